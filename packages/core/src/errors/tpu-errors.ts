@@ -19,12 +19,27 @@ export type TpuErrorCode =
     | 'NO_LEADERS'
     | 'TIMEOUT'
     | 'VALIDATOR_UNREACHABLE'
-    | 'ZERO_RTT_REJECTED';
+    | 'ZERO_RTT_REJECTED'
+    | 'BLOCKHASH_EXPIRED'
+    | 'NONCE_ADVANCED'
+    | 'CANCELLED'
+    | 'INVALID_TRANSACTION'
+    | 'OVERSIZED'
+    | 'DUPLICATE_TRANSACTION'
+    | 'INVALID_ADDRESS'
+    | 'TLS_FAILURE'
+    | 'ENDPOINT_EXHAUSTED';
 
 /**
  * Error codes that are safe to retry.
  */
-export const TPU_RETRYABLE_ERRORS: TpuErrorCode[] = ['CONNECTION_FAILED', 'STREAM_CLOSED', 'RATE_LIMITED', 'TIMEOUT'];
+export const TPU_RETRYABLE_ERRORS: TpuErrorCode[] = [
+    'CONNECTION_FAILED',
+    'STREAM_CLOSED',
+    'RATE_LIMITED',
+    'TIMEOUT',
+    'ENDPOINT_EXHAUSTED',
+];
 
 /**
  * Error thrown when TPU submission fails.
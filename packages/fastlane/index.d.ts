@@ -3,6 +3,33 @@
 
 /* auto-generated by NAPI-RS */
 
+/**
+ * Identity keypair used for QUIC client certificates.
+ *
+ * Exactly one source must be set.
+ */
+export interface IdentityKeypair {
+  /** Path to a Solana CLI keypair file (JSON array of 64 bytes). */
+  path?: string
+  /** Base58-encoded 64-byte secret key. */
+  base58?: string
+  /** Raw 64-byte secret key. */
+  bytes?: Array<number>
+}
+/** A slot source to follow alongside others. */
+export interface SlotSourceConfig {
+  /**
+   * Source kind: "ws" (WebSocket slotsUpdates), "grpc" (Yellowstone) or
+   * "rpc" (getSlot polling).
+   */
+  kind: string
+  /** Endpoint URL. */
+  url: string
+  /** Optional x-token for authenticated gRPC endpoints. */
+  xToken?: string
+  /** Interval between RPC polls in milliseconds (default: 1000). */
+  pollIntervalMs?: number
+}
 /** Configuration for the TPU client. */
 export interface TpuClientConfig {
   /** RPC URL for fetching leader schedule and cluster info. */
@@ -16,10 +43,76 @@ export interface TpuClientConfig {
   grpcUrl?: string
   /** Optional gRPC x-token for authenticated Yellowstone endpoints. */
   grpcXToken?: string
+  /**
+   * Slot sources to follow concurrently, agreeing on the slot across them
+   * so one lagging or failing provider doesn't affect routing.
+   * When set, replaces the single source chosen from `grpcUrl`/`wsUrl`.
+   */
+  slotSources?: Array<SlotSourceConfig>
   /** Number of upcoming leaders to send transactions to (default: 2). */
   fanout?: number
   /** Whether to pre-warm connections to upcoming leaders (default: true). */
   prewarmConnections?: boolean
+  /**
+   * Maximum number of pooled QUIC connections (default: 128).
+   * Least recently used connections are evicted first, keeping upcoming leaders.
+   */
+  maxConnections?: number
+  /**
+   * Per-leader retry policy: "exponential" (default, backoff with jitter),
+   * "fixed" (constant delay) or "none" (single attempt).
+   */
+  retryPolicy?: string
+  /**
+   * Maximum send attempts per leader (default: 3).
+   * Not allowed with retry policy "none".
+   */
+  maxSendAttempts?: number
+  /** Delay before the first retry to a leader in milliseconds (default: 50). */
+  retryDelayMs?: number
+  /**
+   * Cap on a single retry delay in milliseconds (default: 400, scaled
+   * with `retryDelayMs`; the fixed policy always uses the retry delay).
+   */
+  retryMaxDelayMs?: number
+  /**
+   * Timeout for sending to a single leader, connect and retries included,
+   * in milliseconds (default: 1000).
+   */
+  leaderSendTimeoutMs?: number
+  /** How long a send waits for per-leader results in milliseconds (default: 800). */
+  collectTimeoutMs?: number
+  /** QUIC idle timeout in milliseconds (default: 30000). */
+  quicIdleTimeoutMs?: number
+  /** QUIC keep-alive interval in milliseconds, below the idle timeout (default: 4000). */
+  quicKeepAliveMs?: number
+  /** Number of QUIC endpoints connections are spread across (default: 5). */
+  endpointCount?: number
+  /** Number of tokio worker threads driving the client (default: 2). */
+  workerThreads?: number
+  /** Interval between connection pre-warming rounds in milliseconds (default: 400). */
+  prewarmIntervalMs?: number
+  /** Interval between validator TPU socket refreshes in milliseconds (default: 10000). */
+  socketRefreshIntervalMs?: number
+  /**
+   * Validate transactions locally before sending: packet size, message
+   * parsing, signature count and ed25519 signatures, plus duplicates within
+   * a batch (default: false).
+   */
+  preflightChecks?: boolean
+  /**
+   * File to persist leader schedules and validator sockets to. When set
+   * and still current for the cluster, the next start restores from it
+   * instead of fetching schedules, and refreshes in the background.
+   */
+  snapshotPath?: string
+  /** Interval between checks for changes to persist in milliseconds (default: 30000). */
+  snapshotIntervalMs?: number
+  /**
+   * Optional staked validator identity for QUIC client certificates.
+   * Staked identities receive stake-weighted QoS from leaders.
+   */
+  identity?: IdentityKeypair
 }
 /** Result for a single leader send attempt. */
 export interface LeaderSendResult {
@@ -51,6 +144,59 @@ export interface SendResult {
   /** Total retry attempts made across all leaders. */
   retryCount: number
 }
+/** A pooled QUIC connection with its last-use time. */
+export interface PooledConnectionInfo {
+  /** TPU socket address. */
+  address: string
+  /** Whether the connection is established and open. */
+  open: boolean
+  /** Milliseconds since the connection was last used. */
+  idleMs: number
+  /** Unix timestamp of last use in milliseconds. */
+  lastUsedMs: number
+}
+/** Slot and lag of a single slot source. */
+export interface SlotSourceInfo {
+  /** Source label, e.g. "ws-0" (kind and position in `slotSources`). */
+  label: string
+  /** Source kind: "ws", "grpc" or "rpc". */
+  kind: string
+  /** Last slot observed from this source (0 if none yet). */
+  slot: number
+  /** Slots this source is behind the consensus slot (negative if ahead). */
+  lag: number
+  /** Milliseconds since the source last updated (unset if it never did). */
+  lastUpdateMs?: number
+  /** Whether the source stopped updating and is left out of consensus. */
+  stale: boolean
+}
+/** Effective transport, retry and background task settings. */
+export interface TpuClientTuning {
+  /** Maximum send attempts per leader. */
+  maxSendAttempts: number
+  /** Delay before the first retry to a leader in milliseconds. */
+  retryDelayMs: number
+  /** Cap on a single retry delay in milliseconds. */
+  retryMaxDelayMs: number
+  /** Growth factor applied to the retry delay per attempt. */
+  retryMultiplier: number
+  /** Fraction of each retry delay that is randomized. */
+  retryJitter: number
+  /** Timeout for sending to a single leader in milliseconds. */
+  leaderSendTimeoutMs: number
+  /** How long a send waits for per-leader results in milliseconds. */
+  collectTimeoutMs: number
+  /** QUIC idle timeout in milliseconds. */
+  quicIdleTimeoutMs: number
+  /** QUIC keep-alive interval in milliseconds. */
+  quicKeepAliveMs: number
+  /** Number of tokio worker threads driving the client. */
+  workerThreads: number
+  /** Interval between connection pre-warming rounds in milliseconds. */
+  prewarmIntervalMs: number
+  /** Interval between validator TPU socket refreshes in milliseconds. */
+  socketRefreshIntervalMs: number
+}
 /** Client health and statistics. */
 export interface TpuClientStats {
   /** Number of active QUIC connections. */
@@ -65,6 +211,118 @@ export interface TpuClientStats {
   uptimeSecs: number
   /** Number of validators with known sockets. */
   knownValidators: number
+  /** Identity pubkey used for QUIC client certificates (unset if unstaked). */
+  identity?: string
+  /** Maximum number of pooled connections. */
+  maxConnections: number
+  /** Pooled connections with last-use times, most recently used first. */
+  connections: Array<PooledConnectionInfo>
+  /** Effective transport, retry and background task settings. */
+  tuning: TpuClientTuning
+  /** Per-source slot and lag against the consensus slot. */
+  slotSources: Array<SlotSourceInfo>
+  /** Whether schedules and sockets were restored from a snapshot. */
+  warmStart: boolean
+}
+/** An upcoming leader window with its slot range and TPU sockets. */
+export interface UpcomingLeader {
+  /** Validator identity pubkey. */
+  identity: string
+  /** First slot of the leader's window (may be before the current slot). */
+  startSlot: number
+  /** Last slot of the leader's window (inclusive). */
+  endSlot: number
+  /** TPU QUIC socket address, if known. */
+  tpuSocket?: string
+  /** TPU forwards QUIC socket address, if known. */
+  tpuForwardsSocket?: string
+}
+/** A leader selected for routing, with the address transactions are sent to. */
+export interface RoutedLeader {
+  /** Validator identity pubkey. */
+  identity: string
+  /** TPU socket address transactions are sent to. */
+  address: string
+}
+/** Leaders selected by the slot-aware routing strategy. */
+export interface SlotAwareLeaders {
+  /** Current estimated slot. */
+  currentSlot: number
+  /** Position within the current leader's 4-slot window (0-3). */
+  slotPosition: number
+  /** Leaders a transaction sent now would be routed to. */
+  leaders: Array<RoutedLeader>
+}
+/** Current epoch boundaries. */
+export interface EpochInfo {
+  /** Current epoch number. */
+  epoch: number
+  /** First slot of the current epoch. */
+  firstSlot: number
+  /** Number of slots in an epoch. */
+  slotsInEpoch: number
+  /** Current slot index within the epoch. */
+  slotIndex: number
+  /** Current estimated slot. */
+  currentSlot: number
+}
+/** Validator socket changes from a cluster nodes refresh. */
+export interface SocketChanges {
+  /** Validators whose sockets became known. */
+  added: number
+  /** Validators whose sockets changed. */
+  changed: number
+  /** Validators no longer in the cluster. */
+  removed: number
+  /** Validators with known sockets after the refresh. */
+  known: number
+}
+/**
+ * An event delivered to `on` subscribers.
+ *
+ * Only the field matching `kind` is set, along with `slot` where known.
+ */
+export interface TpuClientEvent {
+  /** Event kind: "slot", "leaderChange", "epoch", "sockets" or "send". */
+  kind: string
+  /** Slot the event was observed at ("slot", "leaderChange" and "epoch"). */
+  slot?: number
+  /** New leader identity ("leaderChange"). */
+  leader?: string
+  /** Previous leader identity, if known ("leaderChange"). */
+  previousLeader?: string
+  /** Boundaries of the new epoch ("epoch"). */
+  epoch?: EpochInfo
+  /** Validator socket changes ("sockets"). */
+  sockets?: SocketChanges
+  /** Outcome of a send to a single leader ("send"). */
+  send?: LeaderSendResult
+}
+/** Number of failures with a given error code. */
+export interface ErrorCount {
+  /** Error code (e.g. "TIMEOUT", "RATE_LIMITED"). */
+  code: string
+  /** Number of failures with this code in the window. */
+  count: number
+}
+/** Recent send and connect health for a leader. */
+export interface LeaderHealthInfo {
+  /** Validator identity pubkey. */
+  identity: string
+  /** Last TPU address sent to. */
+  address: string
+  /** Number of send outcomes in the rolling window. */
+  samples: number
+  /** Success rate over the window (unset with too few or stale samples). */
+  successRate?: number
+  /** Moving average connect latency in milliseconds. */
+  connectLatencyMs?: number
+  /** Connect failures to the address since its last success. */
+  consecutiveConnectFailures: number
+  /** Failure counts per error code, most frequent first. */
+  errors: Array<ErrorCount>
+  /** Whether routing treats this leader as healthy. */
+  healthy: boolean
 }
 /** Result from continuous send until confirmed. */
 export interface SendUntilConfirmedResult {
@@ -80,6 +338,8 @@ export interface SendUntilConfirmedResult {
   latencyMs: number
   /** Error message if failed. */
   error?: string
+  /** Error code for programmatic handling (e.g. "BLOCKHASH_EXPIRED", "TIMEOUT"). */
+  errorCode?: string
 }
 /**
  * Native QUIC client for direct Solana TPU transaction submission.
@@ -98,30 +358,157 @@ export declare class TpuClient {
    */
   sendTransaction(transaction: Buffer): Promise<SendResult>
   /**
-   * Sends a transaction continuously until confirmed or timeout.
+   * Sends a batch of serialized transactions to TPU endpoints (single attempt).
+   *
+   * The leader set is resolved once for the whole batch, and each leader
+   * receives every transaction over concurrent streams on a single QUIC
+   * connection. Returns one result per transaction, in input order.
+   */
+  sendTransactions(transactions: Array<Buffer>): Promise<Array<SendResult>>
+  /**
+   * Sends a transaction continuously until confirmed, blockhash expiry or timeout.
+   *
+   * Resubmission stops early with a `BLOCKHASH_EXPIRED` error code once the
+   * transaction's recent blockhash is no longer valid, since it can never land.
+   *
+   * Durable-nonce transactions (first instruction `AdvanceNonceAccount`) don't
+   * expire; resubmission instead stops with a `NONCE_ADVANCED` error code once
+   * the nonce account's nonce changes without this transaction confirming.
+   *
+   * Confirmation is detected via a WebSocket `signatureSubscribe` shared
+   * across all in-flight sends, falling back to RPC polling if the
+   * subscription is unavailable.
    *
    * Uses slot-aware leader selection to minimize tx leakage:
    * - Slots 0-2 of leader window: sends to current leader only
-   * - Slot 3 of leader window: sends to current + next leader (hedge)
+   * - Slot 3 of leader window, or late in slot 2: sends to current + next
+   *   leader (hedge)
    *
    * Falls back to fixed fanout if slot estimation is unreliable.
    *
+   * Passing a token from `create_cancel_token` lets `cancel` stop the send
+   * early; it then resolves with a `CANCELLED` error code and the rounds
+   * attempted so far.
+   *
+   * A transaction that can't be parsed or fails pre-flight checks is never
+   * sent; it resolves with the matching error code (e.g. `OVERSIZED`).
+   *
    * # Arguments
    * * `transaction` - Serialized signed transaction
    * * `timeout_ms` - Maximum time to wait for confirmation (default: 30000ms)
+   * * `cancel_token` - Optional token from `create_cancel_token`
    *
    * # Returns
    * Result indicating whether the transaction was confirmed on-chain.
    */
-  sendUntilConfirmed(transaction: Buffer, timeoutMs?: number | undefined | null): Promise<SendUntilConfirmedResult>
+  sendUntilConfirmed(transaction: Buffer, timeoutMs?: number | undefined | null, cancelToken?: number | undefined | null): Promise<SendUntilConfirmedResult>
+  /**
+   * Creates a token for cancelling a `send_until_confirmed` call.
+   *
+   * Each token is meant for one send and is released when that send
+   * finishes or is cancelled. A token that ends up unused must be freed
+   * with `release_cancel_token`. To cancel from an `AbortSignal`, call
+   * `cancel` from its abort listener.
+   */
+  createCancelToken(): number
+  /**
+   * Releases a token from `create_cancel_token` without cancelling.
+   *
+   * A send already using the token keeps running and can no longer be
+   * cancelled.
+   *
+   * # Returns
+   * Whether the token was known.
+   */
+  releaseCancelToken(cancelToken: number): boolean
+  /**
+   * Cancels the send using a token from `create_cancel_token`.
+   *
+   * The send stops resending immediately, aborts in-flight leader sends and
+   * resolves with a `CANCELLED` error code. Cancelling before the send
+   * starts makes it resolve as cancelled right away.
+   *
+   * The token is released once its send has it, so a token cancelled
+   * before it is passed to a send is released by that send.
+   *
+   * # Returns
+   * Whether the token was known (false once its send has finished).
+   */
+  cancel(cancelToken: number): boolean
   /** Gets the current estimated slot number. */
   getCurrentSlot(): number
+  /**
+   * Gets upcoming leader windows with slot ranges and TPU sockets.
+   *
+   * # Arguments
+   * * `lookahead_slots` - Number of slots to look ahead (default: 16,
+   *   capped at one epoch)
+   */
+  getUpcomingLeaders(lookaheadSlots?: number | undefined | null): Promise<Array<UpcomingLeader>>
+  /**
+   * Gets the leaders the slot-aware strategy would route to right now.
+   *
+   * Returns an empty leader list when the slot is unknown, in which case
+   * sends fall back to fixed fanout.
+   */
+  getSlotAwareLeaders(): Promise<SlotAwareLeaders>
+  /** Gets the current epoch boundaries and slot index. */
+  getEpochInfo(): Promise<EpochInfo>
   /** Gets the number of active QUIC connections. */
   getConnectionCount(): Promise<number>
+  /**
+   * Gets the send and connect health of every leader sent to recently.
+   *
+   * When the current leader is unhealthy, sends widen to additional
+   * upcoming leaders; addresses that keep failing are not prewarmed.
+   */
+  getLeaderHealth(): Array<LeaderHealthInfo>
   /** Gets comprehensive client statistics. */
   getStats(): Promise<TpuClientStats>
+  /**
+   * Renders client metrics in Prometheus text exposition format.
+   *
+   * Includes per-leader send counts, failures per error code, connect and
+   * stream-open latency histograms, 0-RTT acceptance, slot lag versus RPC
+   * and epoch rotations.
+   */
+  metrics(): Promise<string>
   /** Waits for the client to be fully initialized. */
   waitReady(): Promise<void>
-  /** Shuts down the client and closes all connections. */
+  /**
+   * Rotates the identity used for QUIC client certificates.
+   *
+   * Existing connections are closed and re-established with the new
+   * identity on the next send. Pass nothing to revert to an unstaked identity.
+   */
+  setIdentity(identity?: IdentityKeypair | undefined | null): void
+  /**
+   * Subscribes to client events.
+   *
+   * Events cover slot advances, leader window transitions, epoch rotations,
+   * validator socket changes and per-leader send outcomes. The callback
+   * does not keep the process alive, and subscribers that fall behind miss
+   * the oldest events.
+   *
+   * # Arguments
+   * * `kind` - "slot", "leaderChange", "epoch", "sockets", "send", or "*" for all
+   * * `callback` - Called with each matching event
+   *
+   * # Returns
+   * Subscription id to pass to `off`.
+   */
+  on(kind: '*' | 'slot' | 'leaderChange' | 'epoch' | 'sockets' | 'send', callback: (event: TpuClientEvent) => void): number
+  /**
+   * Removes a subscription created by `on`.
+   *
+   * # Returns
+   * Whether the subscription existed.
+   */
+  off(id: number): boolean
+  /**
+   * Shuts down the client and closes all connections.
+   *
+   * Also removes every event subscription.
+   */
   shutdown(): void
 }
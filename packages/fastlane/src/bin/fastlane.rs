@@ -0,0 +1,364 @@
+//! fastlane - command-line tool for sending and inspecting TPU traffic.
+//!
+//! Subcommands:
+//! - `send <FILE>`: send a transaction once and print per-leader results
+//! - `send-until-confirmed <FILE>`: resend until confirmed, expired or timed out
+//! - `leaders`: print upcoming leaders with resolved TPU sockets
+//! - `probe <IP:PORT>`: check QUIC connectivity and 0-RTT to a validator
+//! - `watch-slots`: print slot progress from WebSocket or gRPC
+//!
+//! Transaction files contain a single base64 (default) or base58 string.
+
+use anyhow::{anyhow, bail, Context, Result};
+use base64::Engine;
+use pipeit_fastlane::{
+    DeliveryResult, FastlaneClient, FastlaneConfig, IdentityKeypair, LeaderTracker,
+    StaticLeaderSource, TpuConnectionManager,
+};
+use solana_sdk::signature::Keypair;
+use std::collections::HashMap;
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Default RPC endpoint when `--rpc` is not given.
+const DEFAULT_RPC_URL: &str = "https://api.mainnet-beta.solana.com";
+
+/// How long to wait for leader tracking before giving up.
+const READY_TIMEOUT: Duration = Duration::from_secs(30);
+
+const USAGE: &str = "\
+Usage: fastlane <COMMAND> [ARGS] [OPTIONS]
+
+Commands:
+  send <FILE>                  Send a transaction once
+  send-until-confirmed <FILE>  Resend a transaction until confirmed
+  leaders                      Print upcoming leaders and TPU sockets
+  probe <IP:PORT>              Probe QUIC connectivity and 0-RTT
+  watch-slots                  Print slot updates as they arrive
+
+Options:
+  --rpc <URL>            RPC endpoint (default: mainnet-beta)
+  --ws <URL>             WebSocket endpoint (default: derived from --rpc)
+  --grpc <URL>           Yellowstone gRPC endpoint for slot updates
+  --x-token <TOKEN>      Yellowstone gRPC x-token
+  --identity <PATH>      Staked identity keypair file
+  --fanout <N>           Leaders to send to without slot-aware routing (default: 4)
+  --encoding <ENC>       Transaction file encoding: base64 or base58 (default: base64)
+  --timeout-ms <MS>      send-until-confirmed timeout (default: 30000)
+  --lookahead <SLOTS>    leaders lookahead in slots (default: 16)
+  --duration-secs <S>    watch-slots duration, 0 for forever (default: 0)";
+
+/// Parsed command line.
+struct Args {
+    /// Subcommand name.
+    command: String,
+    /// Positional arguments after the subcommand.
+    positional: Vec<String>,
+    /// `--name value` options.
+    options: HashMap<String, String>,
+}
+
+impl Args {
+    /// Parses arguments (excluding the program name).
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Self> {
+        let mut args = args.into_iter();
+        let command = args.next().ok_or_else(|| anyhow!("Missing command"))?;
+
+        let mut positional = Vec::new();
+        let mut options = HashMap::new();
+        while let Some(arg) = args.next() {
+            match arg.strip_prefix("--") {
+                Some(name) => {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| anyhow!("Missing value for --{}", name))?;
+                    options.insert(name.to_string(), value);
+                }
+                None => positional.push(arg),
+            }
+        }
+
+        Ok(Self {
+            command,
+            positional,
+            options,
+        })
+    }
+
+    /// Returns an option value.
+    fn option(&self, name: &str) -> Option<&str> {
+        self.options.get(name).map(String::as_str)
+    }
+
+    /// Parses a numeric option, falling back to a default.
+    fn number<T: std::str::FromStr>(&self, name: &str, default: T) -> Result<T> {
+        match self.option(name) {
+            Some(value) => value
+                .parse()
+                .map_err(|_| anyhow!("Invalid value for --{}: {}", name, value)),
+            None => Ok(default),
+        }
+    }
+
+    /// Returns the single positional argument for the command.
+    fn positional(&self, what: &str) -> Result<&str> {
+        match self.positional.as_slice() {
+            [value] => Ok(value),
+            [] => bail!("Missing {} for {}", what, self.command),
+            _ => bail!("Too many arguments for {}", self.command),
+        }
+    }
+
+    /// Returns the RPC URL.
+    fn rpc_url(&self) -> String {
+        self.option("rpc").unwrap_or(DEFAULT_RPC_URL).to_string()
+    }
+
+    /// Returns the WebSocket URL, deriving it from the RPC URL if unset.
+    fn ws_url(&self) -> String {
+        match self.option("ws") {
+            Some(url) => url.to_string(),
+            None => {
+                let rpc_url = self.rpc_url();
+                match rpc_url.split_once("://") {
+                    Some(("https", rest)) => format!("wss://{}", rest),
+                    Some(("http", rest)) => format!("ws://{}", rest),
+                    _ => rpc_url,
+                }
+            }
+        }
+    }
+
+    /// Loads the identity keypair, if given.
+    fn identity(&self) -> Result<Option<Keypair>> {
+        self.option("identity")
+            .map(|path| {
+                IdentityKeypair {
+                    path: Some(path.to_string()),
+                    base58: None,
+                    bytes: None,
+                }
+                .load()
+            })
+            .transpose()
+    }
+
+    /// Builds the client config from the common options.
+    fn client_config(&self) -> Result<FastlaneConfig> {
+        let mut config = FastlaneConfig::new(self.rpc_url(), self.ws_url());
+        config.grpc_url = self.option("grpc").map(str::to_string);
+        config.grpc_x_token = self.option("x-token").map(str::to_string);
+        config.fanout = self.number("fanout", 4)?;
+        config.identity = self.identity()?;
+        Ok(config)
+    }
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let result = match args.command.as_str() {
+        "send" => send(&args).await,
+        "send-until-confirmed" => send_until_confirmed(&args).await,
+        "leaders" => leaders(&args).await,
+        "probe" => probe(&args).await,
+        "watch-slots" => watch_slots(&args).await,
+        "help" | "--help" | "-h" => {
+            println!("{}", USAGE);
+            Ok(true)
+        }
+        other => {
+            eprintln!("error: Unknown command {}\n\n{}", other, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {:#}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Reads and decodes a transaction file.
+fn read_transaction(args: &Args) -> Result<Vec<u8>> {
+    let path = args.positional("transaction file")?;
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read transaction file {}", path))?;
+    let encoded = contents.trim();
+
+    match args.option("encoding").unwrap_or("base64") {
+        "base64" => base64::engine::general_purpose::STANDARD
+            .decode(encoded)
+            .context("Transaction file is not valid base64"),
+        "base58" => bs58::decode(encoded)
+            .into_vec()
+            .context("Transaction file is not valid base58"),
+        other => bail!("Unknown encoding {} (expected base64 or base58)", other),
+    }
+}
+
+/// Creates a client and waits for leader tracking.
+async fn ready_client(args: &Args) -> Result<FastlaneClient> {
+    let client = FastlaneClient::new(args.client_config()?).await?;
+    client.wait_ready(READY_TIMEOUT).await?;
+    Ok(client)
+}
+
+/// Prints per-leader results of a delivery.
+fn print_delivery(result: &DeliveryResult) {
+    for leader in &result.leaders {
+        let status = match (&leader.error_code, &leader.error) {
+            (None, _) => "ok".to_string(),
+            (Some(code), Some(error)) => format!("{} ({})", code, error),
+            (Some(code), None) => code.to_string(),
+        };
+        println!(
+            "  {:<44} {:<21} {:>5}ms  attempts={}  {}",
+            leader.identity, leader.address, leader.latency_ms, leader.attempts, status
+        );
+    }
+    println!(
+        "delivered={} leaders={} retries={} latency={}ms",
+        result.delivered, result.leader_count, result.total_retries, result.latency_ms
+    );
+}
+
+/// `send`: sends a transaction once.
+async fn send(args: &Args) -> Result<bool> {
+    let tx_data = read_transaction(args)?;
+    let client = ready_client(args).await?;
+
+    let result = client.send_transaction(&tx_data).await?;
+    print_delivery(&result);
+    Ok(result.delivered)
+}
+
+/// `send-until-confirmed`: resends until confirmed, expired or timed out.
+async fn send_until_confirmed(args: &Args) -> Result<bool> {
+    let tx_data = read_transaction(args)?;
+    let timeout = Duration::from_millis(args.number("timeout-ms", 30_000)?);
+    let client = ready_client(args).await?;
+
+    let outcome = client.send_until_confirmed(&tx_data, timeout).await?;
+    println!("signature={}", outcome.signature);
+    println!(
+        "status={:?} rounds={} leaders_sent={} latency={}ms",
+        outcome.status,
+        outcome.rounds,
+        outcome.total_leaders_sent,
+        outcome.latency.as_millis()
+    );
+    Ok(outcome.confirmed())
+}
+
+/// `leaders`: prints upcoming leader windows with TPU sockets.
+async fn leaders(args: &Args) -> Result<bool> {
+    let lookahead = args.number("lookahead", 16)?;
+    let client = ready_client(args).await?;
+
+    println!("current_slot={}", client.current_slot().await);
+    for window in client.upcoming_leaders(lookahead).await {
+        let (tpu, forwards) = window
+            .sockets
+            .map(|s| (s.tpu_socket, s.tpu_forwards_socket))
+            .unwrap_or_default();
+        println!(
+            "  {}-{}  {:<44}  tpu={}  forwards={}",
+            window.start_slot,
+            window.end_slot,
+            window.identity,
+            tpu.as_deref().unwrap_or("-"),
+            forwards.as_deref().unwrap_or("-")
+        );
+    }
+    Ok(true)
+}
+
+/// `probe`: checks QUIC handshake and 0-RTT against a validator.
+async fn probe(args: &Args) -> Result<bool> {
+    let address = args.positional("validator address")?;
+    let identity = args.identity()?;
+
+    // Probing bypasses routing, so the leader source is never consulted
+    let source = Arc::new(StaticLeaderSource::new(vec![], 0));
+    let manager = TpuConnectionManager::new(source, identity.as_ref())?;
+
+    match manager.probe(address).await {
+        Ok(result) => {
+            println!("handshake={}ms", result.handshake.as_millis());
+            println!(
+                "resumed_handshake={}ms",
+                result.resumed_handshake.as_millis()
+            );
+            println!("zero_rtt_attempted={}", result.zero_rtt_attempted);
+            println!("zero_rtt_accepted={}", result.zero_rtt_accepted);
+            Ok(true)
+        }
+        Err(e) => {
+            println!("error={} ({})", e.code(), e);
+            Ok(false)
+        }
+    }
+}
+
+/// `watch-slots`: prints slot changes from the configured slot source.
+async fn watch_slots(args: &Args) -> Result<bool> {
+    let duration = Duration::from_secs(args.number("duration-secs", 0)?);
+    let tracker = Arc::new(
+        LeaderTracker::new(
+            args.rpc_url(),
+            args.ws_url(),
+            args.option("grpc").map(str::to_string),
+            args.option("x-token").map(str::to_string),
+        )
+        .await?,
+    );
+
+    let source = if args.option("grpc").is_some() {
+        "grpc"
+    } else {
+        "ws"
+    };
+    println!("watching slots via {}", source);
+
+    let listener = tokio::spawn(tracker.clone().run_slot_listener());
+    let start = Instant::now();
+    let mut last_slot = 0;
+    let mut last_change = Instant::now();
+
+    while duration.is_zero() || start.elapsed() < duration {
+        // Observed slot, so feed gaps aren't hidden by extrapolation
+        let slot = tracker.slots_tracker.read().await.observed_slot();
+        if slot != last_slot {
+            println!(
+                "+{:>7.3}s  slot={}  delta={}  interval={}ms",
+                start.elapsed().as_secs_f64(),
+                slot,
+                if last_slot == 0 {
+                    0
+                } else {
+                    slot as i64 - last_slot as i64
+                },
+                last_change.elapsed().as_millis()
+            );
+            last_slot = slot;
+            last_change = Instant::now();
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    listener.abort();
+    Ok(true)
+}
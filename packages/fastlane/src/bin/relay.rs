@@ -0,0 +1,150 @@
+//! fastlane-relay - long-running HTTP relay for TPU submission.
+//!
+//! Owns a single `FastlaneClient` so leader tracking and QUIC connections
+//! stay warm across requests from any number of frontends. See
+//! `pipeit_fastlane::relay` for the routes.
+//!
+//! Configured through environment variables, matching `tpuHandler`:
+//! - `SOLANA_RPC_URL` / `RPC_URL` (required)
+//! - `SOLANA_WS_URL` / `WS_URL` (default: derived from the RPC URL)
+//! - `YELLOWSTONE_GRPC_URL`, `YELLOWSTONE_X_TOKEN` (optional gRPC slot source)
+//! - `FASTLANE_SLOT_SOURCES` (optional comma-separated `kind=url` slot sources
+//!   run together, kind `ws`, `grpc` or `rpc`; gRPC sources use `YELLOWSTONE_X_TOKEN`)
+//! - `FASTLANE_IDENTITY` (optional staked identity keypair file)
+//! - `FASTLANE_FANOUT` (default: 4)
+//! - `FASTLANE_MAX_CONNECTIONS` (default: 128)
+//! - `FASTLANE_PREFLIGHT` (default: false; validate transactions before sending)
+//! - `FASTLANE_SNAPSHOT` (optional file persisting schedules and sockets for warm starts)
+//! - `FASTLANE_LISTEN` (default: 127.0.0.1:8787)
+//! - `FASTLANE_RELAY_TOKEN` (optional bearer token required on the send routes;
+//!   set it before listening on a non-loopback address)
+
+use anyhow::{anyhow, Context, Result};
+use pipeit_fastlane::{relay, FastlaneClient, FastlaneConfig, IdentityKeypair, SlotSource};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Default listen address.
+const DEFAULT_LISTEN_ADDR: &str = "127.0.0.1:8787";
+
+/// How long to wait for leader tracking before serving.
+const READY_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Reads the first set, non-empty variable of `names`.
+fn env(names: &[&str]) -> Option<String> {
+    names
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.is_empty())
+}
+
+/// Derives a WebSocket URL from an HTTP(S) RPC URL.
+fn derive_ws_url(rpc_url: &str) -> String {
+    match rpc_url.split_once("://") {
+        Some(("https", rest)) => format!("wss://{}", rest),
+        Some(("http", rest)) => format!("ws://{}", rest),
+        _ => rpc_url.to_string(),
+    }
+}
+
+/// Builds the client config from the environment.
+fn config_from_env() -> Result<FastlaneConfig> {
+    let rpc_url = env(&["SOLANA_RPC_URL", "RPC_URL"])
+        .ok_or_else(|| anyhow!("RPC URL is required. Set SOLANA_RPC_URL or RPC_URL"))?;
+    let ws_url = env(&["SOLANA_WS_URL", "WS_URL"]).unwrap_or_else(|| derive_ws_url(&rpc_url));
+
+    let mut config = FastlaneConfig::new(rpc_url, ws_url);
+    config.grpc_url = env(&["YELLOWSTONE_GRPC_URL"]);
+    config.grpc_x_token = env(&["YELLOWSTONE_X_TOKEN"]);
+    if let Some(sources) = env(&["FASTLANE_SLOT_SOURCES"]) {
+        for source in sources
+            .split(',')
+            .filter(|source| !source.trim().is_empty())
+        {
+            let mut source: SlotSource = source.parse().context("Invalid FASTLANE_SLOT_SOURCES")?;
+            if let SlotSource::Grpc { x_token, .. } = &mut source {
+                *x_token = config.grpc_x_token.clone();
+            }
+            config.slot_sources.push(source);
+        }
+    }
+    if let Some(fanout) = env(&["FASTLANE_FANOUT"]) {
+        config.fanout = fanout
+            .parse()
+            .with_context(|| format!("Invalid FASTLANE_FANOUT: {}", fanout))?;
+    }
+    if let Some(max) = env(&["FASTLANE_MAX_CONNECTIONS"]) {
+        config.max_connections = max
+            .parse()
+            .with_context(|| format!("Invalid FASTLANE_MAX_CONNECTIONS: {}", max))?;
+    }
+    if let Some(preflight) = env(&["FASTLANE_PREFLIGHT"]) {
+        config.preflight_checks = preflight
+            .parse()
+            .with_context(|| format!("Invalid FASTLANE_PREFLIGHT: {}", preflight))?;
+    }
+    config.snapshot_path = env(&["FASTLANE_SNAPSHOT"]).map(Into::into);
+    if let Some(path) = env(&["FASTLANE_IDENTITY"]) {
+        let identity = IdentityKeypair {
+            path: Some(path),
+            base58: None,
+            bytes: None,
+        };
+        config.identity = Some(identity.load()?);
+    }
+
+    Ok(config)
+}
+
+/// Resolves when the process receives Ctrl-C or SIGTERM.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        let _ = tokio::signal::ctrl_c().await;
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(_) => std::future::pending::<()>().await,
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let config = config_from_env()?;
+    let listen_addr = env(&["FASTLANE_LISTEN"]).unwrap_or_else(|| DEFAULT_LISTEN_ADDR.to_string());
+    let auth_token = env(&["FASTLANE_RELAY_TOKEN"]);
+
+    let client = Arc::new(FastlaneClient::new(config).await?);
+    client.wait_ready(READY_TIMEOUT).await?;
+
+    let listener = tokio::net::TcpListener::bind(&listen_addr)
+        .await
+        .with_context(|| format!("Failed to bind {}", listen_addr))?;
+    let local_addr = listener.local_addr()?;
+    eprintln!("fastlane-relay listening on {}", local_addr);
+    if auth_token.is_none() && !local_addr.ip().is_loopback() {
+        eprintln!(
+            "warning: FASTLANE_RELAY_TOKEN is not set; anyone reaching this address can send"
+        );
+    }
+
+    axum::serve(listener, relay::router(client.clone(), auth_token))
+        .with_graceful_shutdown(shutdown_signal())
+        .await
+        .context("Relay server failed")?;
+
+    client.shutdown();
+    Ok(())
+}
@@ -4,24 +4,94 @@
 //! Solana validator TPU endpoints with per-leader results and retry logic.
 //!
 //! Features continuous resubmission until confirmed for 90%+ landing rates.
+//! This is a thin wrapper over the native `FastlaneClient`, converting
+//! between NAPI types and the Rust API.
 
 use anyhow::Context;
 use napi::bindgen_prelude::*;
+use napi::threadsafe_function::{
+    ErrorStrategy, ThreadSafeCallContext, ThreadsafeFunction, ThreadsafeFunctionCallMode,
+};
+use napi::{Env, JsFunction};
 use napi_derive::napi;
-use solana_client::nonblocking::rpc_client::RpcClient;
-use solana_sdk::signature::Signature;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
-use tokio::sync::oneshot;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
 
-use crate::connection_manager::TpuConnectionManager;
-use crate::tracker::LeaderTracker;
+use crate::connection_manager::{
+    ConnectionTuning, DeliveryResult, LeaderDeliveryResult, PooledConnection,
+    DEFAULT_MAX_CONNECTIONS,
+};
+use crate::errors::FastlaneError;
+use crate::events::ClientEvent;
+use crate::fastlane_client::{ConfirmOutcome, FastlaneClient, FastlaneConfig};
+use crate::identity::IdentityKeypair;
+use crate::leader_health::LeaderHealthSnapshot;
+use crate::retry::RetryPolicy;
+use crate::tracker::{LeaderSource, SlotSource, SlotSourceStats, DEFAULT_RPC_POLL_INTERVAL};
+
+/// Default number of tokio worker threads driving the client.
+const DEFAULT_WORKER_THREADS: usize = 2;
 
 /// Helper to convert anyhow::Error to napi::Error
 fn anyhow_to_napi(err: anyhow::Error) -> napi::Error {
     napi::Error::from_reason(err.to_string())
 }
 
+/// Helper to convert FastlaneError to napi::Error
+///
+/// Errors with a code are prefixed with it (e.g. `OVERSIZED: ...`).
+fn fastlane_to_napi(err: FastlaneError) -> napi::Error {
+    match err.code() {
+        Some(code) => napi::Error::from_reason(format!("{}: {}", code, err)),
+        None => napi::Error::from_reason(err.to_string()),
+    }
+}
+
+/// A slot source to follow alongside others.
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct SlotSourceConfig {
+    /// Source kind: "ws" (WebSocket slotsUpdates), "grpc" (Yellowstone) or
+    /// "rpc" (getSlot polling).
+    pub kind: String,
+    /// Endpoint URL.
+    pub url: String,
+    /// Optional x-token for authenticated gRPC endpoints.
+    pub x_token: Option<String>,
+    /// Interval between RPC polls in milliseconds (default: 1000).
+    pub poll_interval_ms: Option<u32>,
+}
+
+impl TryFrom<SlotSourceConfig> for SlotSource {
+    type Error = anyhow::Error;
+
+    fn try_from(config: SlotSourceConfig) -> anyhow::Result<Self> {
+        match config.kind.as_str() {
+            "ws" => Ok(SlotSource::WebSocket { url: config.url }),
+            "grpc" => Ok(SlotSource::Grpc {
+                url: config.url,
+                x_token: config.x_token,
+            }),
+            "rpc" => Ok(SlotSource::RpcPoll {
+                url: config.url,
+                interval: config
+                    .poll_interval_ms
+                    .map(|ms| Duration::from_millis(ms as u64))
+                    .unwrap_or(DEFAULT_RPC_POLL_INTERVAL),
+            }),
+            other => Err(anyhow::anyhow!(
+                "Unknown slot source kind \"{}\" (expected ws, grpc or rpc)",
+                other
+            )),
+        }
+    }
+}
+
 /// Configuration for the TPU client.
 #[napi(object)]
 #[derive(Debug, Clone)]
@@ -35,10 +105,58 @@ pub struct TpuClientConfig {
     pub grpc_url: Option<String>,
     /// Optional gRPC x-token for authenticated Yellowstone endpoints.
     pub grpc_x_token: Option<String>,
+    /// Slot sources to follow concurrently, agreeing on the slot across them
+    /// so one lagging or failing provider doesn't affect routing.
+    /// When set, replaces the single source chosen from `grpcUrl`/`wsUrl`.
+    pub slot_sources: Option<Vec<SlotSourceConfig>>,
     /// Number of upcoming leaders to send transactions to (default: 2).
     pub fanout: Option<u32>,
     /// Whether to pre-warm connections to upcoming leaders (default: true).
     pub prewarm_connections: Option<bool>,
+    /// Maximum number of pooled QUIC connections (default: 128).
+    /// Least recently used connections are evicted first, keeping upcoming leaders.
+    pub max_connections: Option<u32>,
+    /// Per-leader retry policy: "exponential" (default, backoff with jitter),
+    /// "fixed" (constant delay) or "none" (single attempt).
+    pub retry_policy: Option<String>,
+    /// Maximum send attempts per leader (default: 3).
+    /// Not allowed with retry policy "none".
+    pub max_send_attempts: Option<u32>,
+    /// Delay before the first retry to a leader in milliseconds (default: 50).
+    pub retry_delay_ms: Option<u32>,
+    /// Cap on a single retry delay in milliseconds (default: 400, scaled
+    /// with `retryDelayMs`; the fixed policy always uses the retry delay).
+    pub retry_max_delay_ms: Option<u32>,
+    /// Timeout for sending to a single leader, connect and retries included,
+    /// in milliseconds (default: 1000).
+    pub leader_send_timeout_ms: Option<u32>,
+    /// How long a send waits for per-leader results in milliseconds (default: 800).
+    pub collect_timeout_ms: Option<u32>,
+    /// QUIC idle timeout in milliseconds (default: 30000).
+    pub quic_idle_timeout_ms: Option<u32>,
+    /// QUIC keep-alive interval in milliseconds, below the idle timeout (default: 4000).
+    pub quic_keep_alive_ms: Option<u32>,
+    /// Number of QUIC endpoints connections are spread across (default: 5).
+    pub endpoint_count: Option<u32>,
+    /// Number of tokio worker threads driving the client (default: 2).
+    pub worker_threads: Option<u32>,
+    /// Interval between connection pre-warming rounds in milliseconds (default: 400).
+    pub prewarm_interval_ms: Option<u32>,
+    /// Interval between validator TPU socket refreshes in milliseconds (default: 10000).
+    pub socket_refresh_interval_ms: Option<u32>,
+    /// Validate transactions locally before sending: packet size, message
+    /// parsing, signature count and ed25519 signatures, plus duplicates within
+    /// a batch (default: false).
+    pub preflight_checks: Option<bool>,
+    /// File to persist leader schedules and validator sockets to. When set
+    /// and still current for the cluster, the next start restores from it
+    /// instead of fetching schedules, and refreshes in the background.
+    pub snapshot_path: Option<String>,
+    /// Interval between checks for changes to persist in milliseconds (default: 30000).
+    pub snapshot_interval_ms: Option<u32>,
+    /// Optional staked validator identity for QUIC client certificates.
+    /// Staked identities receive stake-weighted QoS from leaders.
+    pub identity: Option<IdentityKeypair>,
 }
 
 /// Result for a single leader send attempt.
@@ -77,6 +195,223 @@ pub struct SendResult {
     pub retry_count: u32,
 }
 
+impl From<LeaderDeliveryResult> for LeaderSendResult {
+    fn from(lr: LeaderDeliveryResult) -> Self {
+        Self {
+            identity: lr.identity,
+            address: lr.address,
+            success: lr.success,
+            latency_ms: lr.latency_ms as u32,
+            error: lr.error,
+            error_code: lr.error_code.map(|c| c.to_string()),
+            attempts: lr.attempts as u32,
+        }
+    }
+}
+
+impl TpuClientConfig {
+    /// Returns the number of runtime worker threads.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `worker_threads` is zero.
+    fn worker_threads(&self) -> anyhow::Result<usize> {
+        match self.worker_threads {
+            Some(0) => Err(anyhow::anyhow!("workerThreads must be at least 1")),
+            Some(threads) => Ok(threads as usize),
+            None => Ok(DEFAULT_WORKER_THREADS),
+        }
+    }
+
+    /// Converts to the native client config, loading the identity keypair.
+    ///
+    /// Unset tuning fields keep their defaults; the result is validated.
+    fn into_fastlane_config(self) -> anyhow::Result<FastlaneConfig> {
+        let identity = self
+            .identity
+            .as_ref()
+            .map(|identity| identity.load())
+            .transpose()
+            .context("Failed to load identity keypair")?;
+
+        let ms = |value: Option<u32>, default: Duration| {
+            value
+                .map(|ms| Duration::from_millis(ms as u64))
+                .unwrap_or(default)
+        };
+        if self.retry_policy.as_deref() == Some("none") && self.max_send_attempts.is_some() {
+            return Err(anyhow::anyhow!(
+                "maxSendAttempts cannot be combined with retryPolicy \"none\""
+            ));
+        }
+        let mut retry = match self.retry_policy.as_deref() {
+            None | Some("exponential") => RetryPolicy::exponential(),
+            Some("fixed") => {
+                let policy = RetryPolicy::exponential();
+                RetryPolicy::fixed(policy.max_attempts, policy.base_delay)
+            }
+            Some("none") => RetryPolicy::none(),
+            Some(other) => {
+                return Err(anyhow::anyhow!(
+                    "Unknown retryPolicy \"{}\" (expected exponential, fixed or none)",
+                    other
+                ))
+            }
+        };
+        if let Some(attempts) = self.max_send_attempts {
+            retry.max_attempts = attempts as usize;
+        }
+        if let Some(delay) = self.retry_delay_ms {
+            retry = retry.with_base_delay(Duration::from_millis(delay as u64));
+        }
+        retry.max_delay = ms(self.retry_max_delay_ms, retry.max_delay);
+
+        let defaults = ConnectionTuning::default();
+        let tuning = ConnectionTuning {
+            retry,
+            leader_send_timeout: ms(self.leader_send_timeout_ms, defaults.leader_send_timeout),
+            collect_timeout: ms(self.collect_timeout_ms, defaults.collect_timeout),
+            quic_idle_timeout: ms(self.quic_idle_timeout_ms, defaults.quic_idle_timeout),
+            quic_keep_alive: ms(self.quic_keep_alive_ms, defaults.quic_keep_alive),
+            endpoint_count: self
+                .endpoint_count
+                .map(|count| count as usize)
+                .unwrap_or(defaults.endpoint_count),
+        };
+
+        let mut config = FastlaneConfig::new(self.rpc_url, self.ws_url);
+        config.grpc_url = self.grpc_url;
+        config.grpc_x_token = self.grpc_x_token;
+        config.slot_sources = self
+            .slot_sources
+            .unwrap_or_default()
+            .into_iter()
+            .map(SlotSource::try_from)
+            .collect::<anyhow::Result<_>>()?;
+        config.fanout = self.fanout.unwrap_or(config.fanout);
+        config.prewarm_connections = self.prewarm_connections.unwrap_or(true);
+        config.max_connections = self
+            .max_connections
+            .map(|max| max as usize)
+            .unwrap_or(DEFAULT_MAX_CONNECTIONS);
+        config.prewarm_interval = ms(self.prewarm_interval_ms, config.prewarm_interval);
+        config.socket_refresh_interval = ms(
+            self.socket_refresh_interval_ms,
+            config.socket_refresh_interval,
+        );
+        config.tuning = tuning;
+        config.preflight_checks = self.preflight_checks.unwrap_or(false);
+        config.snapshot_path = self.snapshot_path.map(Into::into);
+        config.snapshot_interval = ms(self.snapshot_interval_ms, config.snapshot_interval);
+        config.identity = identity;
+
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+impl From<DeliveryResult> for SendResult {
+    fn from(result: DeliveryResult) -> Self {
+        Self {
+            delivered: result.delivered,
+            latency_ms: result.latency_ms as u32,
+            leader_count: result.leader_count as u32,
+            leaders: result.leaders.into_iter().map(LeaderSendResult::from).collect(),
+            retry_count: result.total_retries as u32,
+        }
+    }
+}
+
+/// A pooled QUIC connection with its last-use time.
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct PooledConnectionInfo {
+    /// TPU socket address.
+    pub address: String,
+    /// Whether the connection is established and open.
+    pub open: bool,
+    /// Milliseconds since the connection was last used.
+    pub idle_ms: u32,
+    /// Unix timestamp of last use in milliseconds.
+    pub last_used_ms: f64,
+}
+
+impl From<PooledConnection> for PooledConnectionInfo {
+    fn from(conn: PooledConnection) -> Self {
+        Self {
+            address: conn.address,
+            open: conn.open,
+            idle_ms: conn.idle.as_millis() as u32,
+            last_used_ms: conn
+                .last_used
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|since| since.as_millis() as f64)
+                .unwrap_or(0.0),
+        }
+    }
+}
+
+/// Slot and lag of a single slot source.
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct SlotSourceInfo {
+    /// Source label, e.g. "ws-0" (kind and position in `slotSources`).
+    pub label: String,
+    /// Source kind: "ws", "grpc" or "rpc".
+    pub kind: String,
+    /// Last slot observed from this source (0 if none yet).
+    pub slot: u32,
+    /// Slots this source is behind the consensus slot (negative if ahead).
+    pub lag: i32,
+    /// Milliseconds since the source last updated (unset if it never did).
+    pub last_update_ms: Option<u32>,
+    /// Whether the source stopped updating and is left out of consensus.
+    pub stale: bool,
+}
+
+impl From<SlotSourceStats> for SlotSourceInfo {
+    fn from(stats: SlotSourceStats) -> Self {
+        Self {
+            label: stats.label,
+            kind: stats.kind.to_string(),
+            slot: stats.slot as u32,
+            lag: stats.lag.clamp(i32::MIN as i64, i32::MAX as i64) as i32,
+            last_update_ms: stats.since_update.map(|since| since.as_millis() as u32),
+            stale: stats.stale,
+        }
+    }
+}
+
+/// Effective transport, retry and background task settings.
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct TpuClientTuning {
+    /// Maximum send attempts per leader.
+    pub max_send_attempts: u32,
+    /// Delay before the first retry to a leader in milliseconds.
+    pub retry_delay_ms: u32,
+    /// Cap on a single retry delay in milliseconds.
+    pub retry_max_delay_ms: u32,
+    /// Growth factor applied to the retry delay per attempt.
+    pub retry_multiplier: f64,
+    /// Fraction of each retry delay that is randomized.
+    pub retry_jitter: f64,
+    /// Timeout for sending to a single leader in milliseconds.
+    pub leader_send_timeout_ms: u32,
+    /// How long a send waits for per-leader results in milliseconds.
+    pub collect_timeout_ms: u32,
+    /// QUIC idle timeout in milliseconds.
+    pub quic_idle_timeout_ms: u32,
+    /// QUIC keep-alive interval in milliseconds.
+    pub quic_keep_alive_ms: u32,
+    /// Number of tokio worker threads driving the client.
+    pub worker_threads: u32,
+    /// Interval between connection pre-warming rounds in milliseconds.
+    pub prewarm_interval_ms: u32,
+    /// Interval between validator TPU socket refreshes in milliseconds.
+    pub socket_refresh_interval_ms: u32,
+}
+
 /// Client health and statistics.
 #[napi(object)]
 #[derive(Debug, Clone)]
@@ -93,6 +428,217 @@ pub struct TpuClientStats {
     pub uptime_secs: u32,
     /// Number of validators with known sockets.
     pub known_validators: u32,
+    /// Identity pubkey used for QUIC client certificates (unset if unstaked).
+    pub identity: Option<String>,
+    /// Maximum number of pooled connections.
+    pub max_connections: u32,
+    /// Pooled connections with last-use times, most recently used first.
+    pub connections: Vec<PooledConnectionInfo>,
+    /// Effective transport, retry and background task settings.
+    pub tuning: TpuClientTuning,
+    /// Per-source slot and lag against the consensus slot.
+    pub slot_sources: Vec<SlotSourceInfo>,
+    /// Whether schedules and sockets were restored from a snapshot.
+    pub warm_start: bool,
+}
+
+/// An upcoming leader window with its slot range and TPU sockets.
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct UpcomingLeader {
+    /// Validator identity pubkey.
+    pub identity: String,
+    /// First slot of the leader's window (may be before the current slot).
+    pub start_slot: u32,
+    /// Last slot of the leader's window (inclusive).
+    pub end_slot: u32,
+    /// TPU QUIC socket address, if known.
+    pub tpu_socket: Option<String>,
+    /// TPU forwards QUIC socket address, if known.
+    pub tpu_forwards_socket: Option<String>,
+}
+
+/// A leader selected for routing, with the address transactions are sent to.
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct RoutedLeader {
+    /// Validator identity pubkey.
+    pub identity: String,
+    /// TPU socket address transactions are sent to.
+    pub address: String,
+}
+
+/// Leaders selected by the slot-aware routing strategy.
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct SlotAwareLeaders {
+    /// Current estimated slot.
+    pub current_slot: u32,
+    /// Position within the current leader's 4-slot window (0-3).
+    pub slot_position: u32,
+    /// Leaders a transaction sent now would be routed to.
+    pub leaders: Vec<RoutedLeader>,
+}
+
+/// Current epoch boundaries.
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct EpochInfo {
+    /// Current epoch number.
+    pub epoch: u32,
+    /// First slot of the current epoch.
+    pub first_slot: u32,
+    /// Number of slots in an epoch.
+    pub slots_in_epoch: u32,
+    /// Current slot index within the epoch.
+    pub slot_index: u32,
+    /// Current estimated slot.
+    pub current_slot: u32,
+}
+
+/// Validator socket changes from a cluster nodes refresh.
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct SocketChanges {
+    /// Validators whose sockets became known.
+    pub added: u32,
+    /// Validators whose sockets changed.
+    pub changed: u32,
+    /// Validators no longer in the cluster.
+    pub removed: u32,
+    /// Validators with known sockets after the refresh.
+    pub known: u32,
+}
+
+/// An event delivered to `on` subscribers.
+///
+/// Only the field matching `kind` is set, along with `slot` where known.
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct TpuClientEvent {
+    /// Event kind: "slot", "leaderChange", "epoch", "sockets" or "send".
+    pub kind: String,
+    /// Slot the event was observed at ("slot", "leaderChange" and "epoch").
+    pub slot: Option<u32>,
+    /// New leader identity ("leaderChange").
+    pub leader: Option<String>,
+    /// Previous leader identity, if known ("leaderChange").
+    pub previous_leader: Option<String>,
+    /// Boundaries of the new epoch ("epoch").
+    pub epoch: Option<EpochInfo>,
+    /// Validator socket changes ("sockets").
+    pub sockets: Option<SocketChanges>,
+    /// Outcome of a send to a single leader ("send").
+    pub send: Option<LeaderSendResult>,
+}
+
+impl From<ClientEvent> for TpuClientEvent {
+    fn from(event: ClientEvent) -> Self {
+        let mut js_event = Self {
+            kind: event.kind().to_string(),
+            slot: None,
+            leader: None,
+            previous_leader: None,
+            epoch: None,
+            sockets: None,
+            send: None,
+        };
+
+        match event {
+            ClientEvent::Slot { slot } => js_event.slot = Some(slot as u32),
+            ClientEvent::LeaderChange {
+                slot,
+                leader,
+                previous,
+            } => {
+                js_event.slot = Some(slot as u32);
+                js_event.leader = Some(leader);
+                js_event.previous_leader = previous;
+            }
+            ClientEvent::EpochRotated { bounds, slot } => {
+                js_event.slot = Some(slot as u32);
+                js_event.epoch = Some(EpochInfo {
+                    epoch: bounds.epoch as u32,
+                    first_slot: bounds.first_slot as u32,
+                    slots_in_epoch: bounds.slots_in_epoch as u32,
+                    slot_index: slot.saturating_sub(bounds.first_slot) as u32,
+                    current_slot: slot as u32,
+                });
+            }
+            ClientEvent::SocketsUpdated {
+                added,
+                changed,
+                removed,
+                known,
+            } => {
+                js_event.sockets = Some(SocketChanges {
+                    added: added as u32,
+                    changed: changed as u32,
+                    removed: removed as u32,
+                    known: known as u32,
+                });
+            }
+            ClientEvent::LeaderSend(result) => js_event.send = Some(result.into()),
+        }
+
+        js_event
+    }
+}
+
+/// Number of failures with a given error code.
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct ErrorCount {
+    /// Error code (e.g. "TIMEOUT", "RATE_LIMITED").
+    pub code: String,
+    /// Number of failures with this code in the window.
+    pub count: u32,
+}
+
+/// Recent send and connect health for a leader.
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct LeaderHealthInfo {
+    /// Validator identity pubkey.
+    pub identity: String,
+    /// Last TPU address sent to.
+    pub address: String,
+    /// Number of send outcomes in the rolling window.
+    pub samples: u32,
+    /// Success rate over the window (unset with too few or stale samples).
+    pub success_rate: Option<f64>,
+    /// Moving average connect latency in milliseconds.
+    pub connect_latency_ms: Option<f64>,
+    /// Connect failures to the address since its last success.
+    pub consecutive_connect_failures: u32,
+    /// Failure counts per error code, most frequent first.
+    pub errors: Vec<ErrorCount>,
+    /// Whether routing treats this leader as healthy.
+    pub healthy: bool,
+}
+
+impl From<LeaderHealthSnapshot> for LeaderHealthInfo {
+    fn from(snapshot: LeaderHealthSnapshot) -> Self {
+        Self {
+            identity: snapshot.identity,
+            address: snapshot.address,
+            samples: snapshot.samples as u32,
+            success_rate: snapshot.success_rate,
+            connect_latency_ms: snapshot
+                .connect_latency
+                .map(|latency| latency.as_secs_f64() * 1000.0),
+            consecutive_connect_failures: snapshot.consecutive_connect_failures,
+            errors: snapshot
+                .errors
+                .into_iter()
+                .map(|(code, count)| ErrorCount {
+                    code: code.to_string(),
+                    count: count as u32,
+                })
+                .collect(),
+            healthy: snapshot.healthy,
+        }
+    }
 }
 
 /// Result from continuous send until confirmed.
@@ -111,6 +657,17 @@ pub struct SendUntilConfirmedResult {
     pub latency_ms: u32,
     /// Error message if failed.
     pub error: Option<String>,
+    /// Error code for programmatic handling (e.g. "BLOCKHASH_EXPIRED", "TIMEOUT").
+    pub error_code: Option<String>,
+}
+
+/// A cancel token handed out to JS.
+#[derive(Debug)]
+struct CancelEntry {
+    /// Token the send using this entry watches.
+    token: CancellationToken,
+    /// Whether a send is currently using the token.
+    in_use: bool,
 }
 
 /// Native QUIC client for direct Solana TPU transaction submission.
@@ -118,20 +675,21 @@ pub struct SendUntilConfirmedResult {
 /// Supports continuous resubmission until confirmed for high landing rates.
 #[napi]
 pub struct TpuClient {
-    /// Leader tracker for routing.
-    leader_tracker: Arc<LeaderTracker>,
-    /// Connection manager for QUIC connections.
-    connection_manager: Arc<TpuConnectionManager>,
-    /// RPC client for confirmation checking.
-    rpc_client: Arc<RpcClient>,
-    /// Tokio runtime for async operations.
+    /// Native client doing the actual work.
+    client: FastlaneClient,
+    /// Number of worker threads in the runtime.
+    worker_threads: usize,
+    /// Event forwarding tasks by subscription id.
+    subscriptions: Mutex<HashMap<u32, JoinHandle<()>>>,
+    /// Id handed to the next `on` subscriber.
+    next_subscription: AtomicU32,
+    /// Cancel tokens by id, until they are used, cancelled or released.
+    cancel_tokens: Mutex<HashMap<u32, CancelEntry>>,
+    /// Id handed out by the next `create_cancel_token` call.
+    next_cancel_token: AtomicU32,
+    /// Tokio runtime driving the client's connections and background tasks.
+    /// Declared last so it outlives the client on drop.
     runtime: tokio::runtime::Runtime,
-    /// Shutdown signal sender.
-    shutdown_tx: Option<oneshot::Sender<()>>,
-    /// Time when client was created.
-    start_time: Instant,
-    /// Number of leaders to fanout to.
-    fanout: u32,
 }
 
 #[napi]
@@ -139,101 +697,22 @@ impl TpuClient {
     /// Creates a new TPU client instance.
     #[napi(constructor)]
     pub fn new(config: TpuClientConfig) -> napi::Result<Self> {
+        let worker_threads = config.worker_threads().map_err(anyhow_to_napi)?;
+        let config = config.into_fastlane_config().map_err(anyhow_to_napi)?;
+        let runtime = Self::build_runtime(worker_threads).map_err(anyhow_to_napi)?;
 
-        // Create tokio runtime
-        let runtime = tokio::runtime::Builder::new_multi_thread()
-            .worker_threads(2)
-            .enable_all()
-            .build()
-            .context("Failed to create tokio runtime")
-            .map_err(anyhow_to_napi)?;
-
-        // Initialize leader tracker
-        let leader_tracker = runtime.block_on(async {
-            LeaderTracker::new(
-                config.rpc_url.clone(),
-                config.ws_url.clone(),
-                config.grpc_url.clone(),
-                config.grpc_x_token.clone(),
-            )
-                .await
-                .context("Failed to create leader tracker")
-        }).map_err(anyhow_to_napi)?;
-
-        let leader_tracker = Arc::new(leader_tracker);
-
-        // Initialize connection manager
-        let connection_manager = TpuConnectionManager::new(leader_tracker.clone())
-            .context("Failed to create connection manager")
-            .map_err(anyhow_to_napi)?;
-        let connection_manager = Arc::new(connection_manager);
-
-        // Create RPC client for confirmation checking
-        let rpc_client = Arc::new(RpcClient::new(config.rpc_url.clone()));
-
-        // Create shutdown channel
-        let (shutdown_tx, shutdown_rx) = oneshot::channel();
-
-        // Spawn background tasks
-        let lt_clone = leader_tracker.clone();
-        let cm_clone = connection_manager.clone();
-        let prewarm = config.prewarm_connections.unwrap_or(true);
-        let fanout = config.fanout.unwrap_or(4);
-        let prewarm_lookahead = (fanout as u64) * 4;
-
-        runtime.spawn(async move {
-            // IMPORTANT: Fetch validator sockets FIRST before starting slot listener
-            // This ensures we have socket data when is_ready() returns true
-            let _ = lt_clone.update_leader_sockets().await;
-
-            // Start slot listener (this will set is_ready = true)
-            let lt_for_slots = lt_clone.clone();
-            let slot_listener = tokio::spawn(async move {
-                let _ = lt_for_slots.run_slot_listener().await;
-            });
-
-            // Start socket updater (every 10 seconds for fresher TPU sockets)
-            let lt_for_sockets = lt_clone.clone();
-            let socket_updater = tokio::spawn(async move {
-                lt_for_sockets
-                    .run_socket_updater(Duration::from_secs(10))
-                    .await;
-            });
-
-            // Start connection pre-warmer (every 400ms = 1 slot time)
-            // pre-warms connections every ~3 slots for optimal landing.
-            // We prewarm more aggressively (every slot) since we're frontend-facing.
-            let prewarm_task = if prewarm {
-                Some(tokio::spawn(async move {
-                    loop {
-                        // Prewarm connections to next fanout * 4 slots (leader lookahead).
-                        cm_clone.prewarm_connections(prewarm_lookahead).await;
-                        tokio::time::sleep(Duration::from_millis(400)).await;
-                    }
-                }))
-            } else {
-                None
-            };
-
-            // Wait for shutdown signal
-            let _ = shutdown_rx.await;
-
-            // Cancel tasks
-            slot_listener.abort();
-            socket_updater.abort();
-            if let Some(task) = prewarm_task {
-                task.abort();
-            }
-        });
+        let client = runtime
+            .block_on(FastlaneClient::new(config))
+            .map_err(fastlane_to_napi)?;
 
         Ok(Self {
-            leader_tracker,
-            connection_manager,
-            rpc_client,
+            client,
+            worker_threads,
+            subscriptions: Mutex::new(HashMap::new()),
+            next_subscription: AtomicU32::new(1),
+            cancel_tokens: Mutex::new(HashMap::new()),
+            next_cancel_token: AtomicU32::new(1),
             runtime,
-            shutdown_tx: Some(shutdown_tx),
-            start_time: Instant::now(),
-            fanout,
         })
     }
 
@@ -244,58 +723,65 @@ impl TpuClient {
     /// For higher landing rates, use `send_until_confirmed` instead.
     #[napi]
     pub async fn send_transaction(&self, transaction: Buffer) -> napi::Result<SendResult> {
-        let tx_data = transaction.as_ref();
-        let cm = self.connection_manager.clone();
-        let fanout = self.fanout;
-
-        let (leaders, _slot_position) = self.leader_tracker.get_slot_aware_leaders().await;
-        let result = if leaders.is_empty() {
-            cm.send_transaction_with_fanout(tx_data, fanout)
-                .await
-                .context("Failed to send transaction")
-                .map_err(anyhow_to_napi)?
-        } else {
-            cm.send_to_leaders(tx_data, &leaders)
-                .await
-                .context("Failed to send transaction")
-                .map_err(anyhow_to_napi)?
-        };
+        self.client
+            .send_transaction(transaction.as_ref())
+            .await
+            .map(SendResult::from)
+            .map_err(fastlane_to_napi)
+    }
 
-        // Convert internal LeaderDeliveryResult to NAPI LeaderSendResult
-        let leaders: Vec<LeaderSendResult> = result
-            .leaders
-            .into_iter()
-            .map(|lr| LeaderSendResult {
-                identity: lr.identity,
-                address: lr.address,
-                success: lr.success,
-                latency_ms: lr.latency_ms as u32,
-                error: lr.error,
-                error_code: lr.error_code.map(|c| c.to_string()),
-                attempts: lr.attempts as u32,
-            })
-            .collect();
+    /// Sends a batch of serialized transactions to TPU endpoints (single attempt).
+    ///
+    /// The leader set is resolved once for the whole batch, and each leader
+    /// receives every transaction over concurrent streams on a single QUIC
+    /// connection. Returns one result per transaction, in input order.
+    #[napi]
+    pub async fn send_transactions(
+        &self,
+        transactions: Vec<Buffer>,
+    ) -> napi::Result<Vec<SendResult>> {
+        let transactions: Vec<Vec<u8>> = transactions.iter().map(|tx| tx.to_vec()).collect();
 
-        Ok(SendResult {
-            delivered: result.delivered,
-            latency_ms: result.latency_ms as u32,
-            leader_count: result.leader_count as u32,
-            leaders,
-            retry_count: result.total_retries as u32,
-        })
+        let results = self
+            .client
+            .send_transactions(&transactions)
+            .await
+            .map_err(fastlane_to_napi)?;
+
+        Ok(results.into_iter().map(SendResult::from).collect())
     }
 
-    /// Sends a transaction continuously until confirmed or timeout.
+    /// Sends a transaction continuously until confirmed, blockhash expiry or timeout.
+    ///
+    /// Resubmission stops early with a `BLOCKHASH_EXPIRED` error code once the
+    /// transaction's recent blockhash is no longer valid, since it can never land.
+    ///
+    /// Durable-nonce transactions (first instruction `AdvanceNonceAccount`) don't
+    /// expire; resubmission instead stops with a `NONCE_ADVANCED` error code once
+    /// the nonce account's nonce changes without this transaction confirming.
+    ///
+    /// Confirmation is detected via a WebSocket `signatureSubscribe` shared
+    /// across all in-flight sends, falling back to RPC polling if the
+    /// subscription is unavailable.
     ///
     /// Uses slot-aware leader selection to minimize tx leakage:
     /// - Slots 0-2 of leader window: sends to current leader only
-    /// - Slot 3 of leader window: sends to current + next leader (hedge)
+    /// - Slot 3 of leader window, or late in slot 2: sends to current + next
+    ///   leader (hedge)
     ///
     /// Falls back to fixed fanout if slot estimation is unreliable.
     ///
+    /// Passing a token from `create_cancel_token` lets `cancel` stop the send
+    /// early; it then resolves with a `CANCELLED` error code and the rounds
+    /// attempted so far.
+    ///
+    /// A transaction that can't be parsed or fails pre-flight checks is never
+    /// sent; it resolves with the matching error code (e.g. `OVERSIZED`).
+    ///
     /// # Arguments
     /// * `transaction` - Serialized signed transaction
     /// * `timeout_ms` - Maximum time to wait for confirmation (default: 30000ms)
+    /// * `cancel_token` - Optional token from `create_cancel_token`
     ///
     /// # Returns
     /// Result indicating whether the transaction was confirmed on-chain.
@@ -304,243 +790,439 @@ impl TpuClient {
         &self,
         transaction: Buffer,
         timeout_ms: Option<u32>,
+        cancel_token: Option<u32>,
     ) -> napi::Result<SendUntilConfirmedResult> {
-        let timeout = Duration::from_millis(timeout_ms.unwrap_or(30_000) as u64);
         let start = Instant::now();
-        let tx_data = transaction.as_ref().to_vec();
-        
-        // Extract signature from transaction
-        let signature = match Self::extract_signature(&tx_data) {
-            Ok(sig) => sig,
-            Err(e) => {
-                return Ok(SendUntilConfirmedResult {
-                    confirmed: false,
-                    signature: String::new(),
-                    rounds: 0,
-                    total_leaders_sent: 0,
-                    latency_ms: start.elapsed().as_millis() as u32,
-                    error: Some(format!("Failed to extract signature: {}", e)),
-                });
-            }
-        };
-        
-        let signature_str = signature.to_string();
-        
-        let mut rounds = 0u32;
-        let mut total_leaders_sent = 0u32;
-        let slot_duration = Duration::from_millis(400);
-        
-        // Staleness detection - track if slot hasn't changed between rounds
-        let mut last_slot: u64 = 0;
-        let mut stale_rounds: u32 = 0;
-        
-        // Send loop - continues until confirmed or timeout
-        while start.elapsed() < timeout {
-            rounds += 1;
-            
-            // Check for stale slot (same slot for multiple rounds)
-            let current_slot = self.leader_tracker.current_slot().await;
-            if current_slot == last_slot && current_slot != 0 {
-                stale_rounds += 1;
-                if stale_rounds >= 2 {
-                    let _ = self.leader_tracker.refresh_slot_from_rpc().await;
+        let timeout = Duration::from_millis(timeout_ms.unwrap_or(30_000) as u64);
+
+        let cancel = match cancel_token {
+            Some(id) => match self.cancel_tokens.lock().unwrap().get_mut(&id) {
+                Some(entry) if !entry.in_use => {
+                    entry.in_use = true;
+                    entry.token.clone()
                 }
-            } else {
-                stale_rounds = 0;
-                last_slot = current_slot;
-            }
-            
-            // 1. Get slot-aware leaders (1 or 2 based on slot position)
-            let (leaders, _slot_position) = self.leader_tracker.get_slot_aware_leaders().await;
-            
-            // Fallback to fixed fanout if slot estimation is unreliable
-            let send_result = if leaders.is_empty() {
-                self.connection_manager
-                    .send_transaction_with_fanout(&tx_data, self.fanout)
-                    .await
-            } else {
-                self.connection_manager
-                    .send_to_leaders(&tx_data, &leaders)
-                    .await
-            };
-            
-            if let Ok(result) = &send_result {
-                total_leaders_sent += result.leader_count as u32;
-            }
-            
-            // 2. Check if confirmed
-            if let Ok(true) = self.check_confirmed(&signature).await {
-                let latency = start.elapsed().as_millis() as u32;
-                return Ok(SendUntilConfirmedResult {
-                    confirmed: true,
-                    signature: signature_str,
-                    rounds,
-                    total_leaders_sent,
-                    latency_ms: latency,
-                    error: None,
-                });
-            }
-            
-            // 3. Wait one slot before next round
-            // Use a shorter sleep if we're close to timeout
-            let remaining = timeout.saturating_sub(start.elapsed());
-            if remaining < slot_duration {
-                tokio::time::sleep(remaining).await;
-            } else {
-                tokio::time::sleep(slot_duration).await;
-            }
+                _ => {
+                    return Err(napi::Error::from_reason(format!(
+                        "Unknown or already used cancel token {}",
+                        id
+                    )))
+                }
+            },
+            None => CancellationToken::new(),
+        };
+
+        let result = self
+            .client
+            .send_until_confirmed_with_cancel(transaction.as_ref(), timeout, &cancel)
+            .await;
+        if let Some(id) = cancel_token {
+            self.cancel_tokens.lock().unwrap().remove(&id);
         }
-        
-        // Timeout - do one final confirmation check
-        let final_confirmed = self.check_confirmed(&signature).await.unwrap_or(false);
-        let latency = start.elapsed().as_millis() as u32;
-        
-        if final_confirmed {
-            Ok(SendUntilConfirmedResult {
-                confirmed: true,
-                signature: signature_str,
-                rounds,
-                total_leaders_sent,
-                latency_ms: latency,
-                error: None,
-            })
-        } else {
-            Ok(SendUntilConfirmedResult {
-                confirmed: false,
-                signature: signature_str,
-                rounds,
-                total_leaders_sent,
-                latency_ms: latency,
-                error: Some(format!(
-                    "Transaction not confirmed within {}ms ({} rounds, {} leaders sent)",
-                    timeout.as_millis(),
-                    rounds,
-                    total_leaders_sent
-                )),
-            })
+
+        match result {
+            Ok(outcome) => Ok(SendUntilConfirmedResult::from_outcome(outcome, timeout)),
+            Err(err @ (FastlaneError::InvalidTransaction(_) | FastlaneError::Preflight(_))) => {
+                Ok(SendUntilConfirmedResult::from_error(&err, start.elapsed()))
+            }
+            Err(e) => Err(fastlane_to_napi(e)),
         }
     }
-    
-    /// Extract signature from a serialized transaction.
-    /// 
-    /// Solana transaction format: [num_signatures, ...signatures (64 bytes each), ...]
-    fn extract_signature(tx_data: &[u8]) -> anyhow::Result<Signature> {
-        if tx_data.is_empty() {
-            anyhow::bail!("Empty transaction data");
-        }
-        
-        let num_signatures = tx_data[0] as usize;
-        if num_signatures == 0 {
-            anyhow::bail!("Transaction has no signatures");
-        }
-        
-        if tx_data.len() < 1 + 64 {
-            anyhow::bail!("Transaction too short to contain signature");
-        }
-        
-        // First signature is at offset 1, 64 bytes
-        let sig_bytes: [u8; 64] = tx_data[1..65]
-            .try_into()
-            .context("Failed to extract signature bytes")?;
-        
-        Ok(Signature::from(sig_bytes))
-    }
-    
-    /// Check if a transaction is confirmed on-chain.
-    async fn check_confirmed(&self, signature: &Signature) -> anyhow::Result<bool> {
-        let response = self.rpc_client
-            .get_signature_statuses(&[*signature])
-            .await
-            .context("Failed to get signature status")?;
-        
-        if let Some(Some(status)) = response.value.first() {
-            // Check if confirmed or finalized
-            // The confirmation_status field indicates the commitment level achieved
-            if let Some(ref conf_status) = status.confirmation_status {
-                use solana_client::rpc_response::TransactionConfirmationStatus;
-                return Ok(matches!(
-                    conf_status,
-                    TransactionConfirmationStatus::Confirmed | TransactionConfirmationStatus::Finalized
-                ));
-            }
-            // If confirmations is Some, it's at least confirmed
-            if status.confirmations.is_some() {
-                return Ok(true);
-            }
-            // If err is None and we have a status, the transaction was processed
-            if status.err.is_none() {
-                return Ok(true);
-            }
+
+    /// Creates a token for cancelling a `send_until_confirmed` call.
+    ///
+    /// Each token is meant for one send and is released when that send
+    /// finishes or is cancelled. A token that ends up unused must be freed
+    /// with `release_cancel_token`. To cancel from an `AbortSignal`, call
+    /// `cancel` from its abort listener.
+    #[napi]
+    pub fn create_cancel_token(&self) -> u32 {
+        let id = self.next_cancel_token.fetch_add(1, Ordering::Relaxed);
+        self.cancel_tokens.lock().unwrap().insert(
+            id,
+            CancelEntry {
+                token: CancellationToken::new(),
+                in_use: false,
+            },
+        );
+        id
+    }
+
+    /// Releases a token from `create_cancel_token` without cancelling.
+    ///
+    /// A send already using the token keeps running and can no longer be
+    /// cancelled.
+    ///
+    /// # Returns
+    /// Whether the token was known.
+    #[napi]
+    pub fn release_cancel_token(&self, cancel_token: u32) -> bool {
+        self.cancel_tokens
+            .lock()
+            .unwrap()
+            .remove(&cancel_token)
+            .is_some()
+    }
+
+    /// Cancels the send using a token from `create_cancel_token`.
+    ///
+    /// The send stops resending immediately, aborts in-flight leader sends and
+    /// resolves with a `CANCELLED` error code. Cancelling before the send
+    /// starts makes it resolve as cancelled right away.
+    ///
+    /// The token is released once its send has it, so a token cancelled
+    /// before it is passed to a send is released by that send.
+    ///
+    /// # Returns
+    /// Whether the token was known (false once its send has finished).
+    #[napi]
+    pub fn cancel(&self, cancel_token: u32) -> bool {
+        let mut cancel_tokens = self.cancel_tokens.lock().unwrap();
+        let Some(entry) = cancel_tokens.get(&cancel_token) else {
+            return false;
+        };
+        entry.token.cancel();
+        if entry.in_use {
+            cancel_tokens.remove(&cancel_token);
         }
-        
-        Ok(false)
+        true
     }
 
     /// Gets the current estimated slot number.
     #[napi]
     pub fn get_current_slot(&self) -> u32 {
-        self.runtime
-            .block_on(self.leader_tracker.current_slot()) as u32
+        self.runtime.block_on(self.client.current_slot()) as u32
+    }
+
+    /// Gets upcoming leader windows with slot ranges and TPU sockets.
+    ///
+    /// Empty when the client routes through a custom leader source.
+    ///
+    /// # Arguments
+    /// * `lookahead_slots` - Number of slots to look ahead (default: 16,
+    ///   capped at one epoch)
+    #[napi]
+    pub async fn get_upcoming_leaders(&self, lookahead_slots: Option<u32>) -> Vec<UpcomingLeader> {
+        let lookahead = lookahead_slots.unwrap_or(16) as u64;
+
+        self.client
+            .upcoming_leaders(lookahead)
+            .await
+            .into_iter()
+            .map(|window| {
+                let (tpu_socket, tpu_forwards_socket) = window
+                    .sockets
+                    .map(|s| (s.tpu_socket, s.tpu_forwards_socket))
+                    .unwrap_or_default();
+
+                UpcomingLeader {
+                    identity: window.identity,
+                    start_slot: window.start_slot as u32,
+                    end_slot: window.end_slot as u32,
+                    tpu_socket,
+                    tpu_forwards_socket,
+                }
+            })
+            .collect()
+    }
+
+    /// Gets the leaders the slot-aware strategy would route to right now.
+    ///
+    /// Returns an empty leader list when the slot is unknown, in which case
+    /// sends fall back to fixed fanout.
+    #[napi]
+    pub async fn get_slot_aware_leaders(&self) -> SlotAwareLeaders {
+        let route = self.client.slot_aware_leaders().await;
+
+        SlotAwareLeaders {
+            current_slot: route.current_slot as u32,
+            slot_position: route.slot_position as u32,
+            leaders: route
+                .leaders
+                .into_iter()
+                .map(|leader| RoutedLeader {
+                    identity: leader.identity,
+                    address: leader.tpu_socket,
+                })
+                .collect(),
+        }
+    }
+
+    /// Gets the current epoch boundaries and slot index.
+    ///
+    /// Epoch fields are zero when the client routes through a custom leader source.
+    #[napi]
+    pub async fn get_epoch_info(&self) -> EpochInfo {
+        let bounds = self.client.epoch_bounds().await;
+        let current_slot = self.client.current_slot().await;
+
+        EpochInfo {
+            epoch: bounds.epoch as u32,
+            first_slot: bounds.first_slot as u32,
+            slots_in_epoch: bounds.slots_in_epoch as u32,
+            slot_index: current_slot.saturating_sub(bounds.first_slot) as u32,
+            current_slot: current_slot as u32,
+        }
     }
 
     /// Gets the number of active QUIC connections.
     #[napi]
     pub async fn get_connection_count(&self) -> u32 {
-        self.connection_manager.connection_count() as u32
+        self.client.connection_count() as u32
+    }
+
+    /// Gets the send and connect health of every leader sent to recently.
+    ///
+    /// When the current leader is unhealthy, sends widen to additional
+    /// upcoming leaders; addresses that keep failing are not prewarmed.
+    #[napi]
+    pub fn get_leader_health(&self) -> Vec<LeaderHealthInfo> {
+        self.client
+            .leader_health()
+            .into_iter()
+            .map(Into::into)
+            .collect()
     }
 
     /// Gets comprehensive client statistics.
     #[napi]
     pub async fn get_stats(&self) -> TpuClientStats {
-        let is_ready = self.leader_tracker.is_ready().await;
-        let current_slot = self.leader_tracker.current_slot().await;
-        let validator_count = self.leader_tracker.validator_count().await;
+        let stats = self.client.stats().await;
 
         TpuClientStats {
-            connection_count: self.connection_manager.connection_count() as u32,
-            current_slot: current_slot as u32,
-            endpoint_count: 5, // NUM_ENDPOINTS from connection_manager
-            ready_state: if is_ready {
+            connection_count: stats.connection_count as u32,
+            current_slot: stats.current_slot as u32,
+            endpoint_count: stats.endpoint_count as u32,
+            ready_state: if stats.ready {
                 "ready".to_string()
             } else {
                 "initializing".to_string()
             },
-            uptime_secs: self.start_time.elapsed().as_secs() as u32,
-            known_validators: validator_count as u32,
+            uptime_secs: stats.uptime.as_secs() as u32,
+            known_validators: stats.known_validators as u32,
+            identity: stats.identity,
+            max_connections: stats.max_connections as u32,
+            connections: stats.connections.into_iter().map(Into::into).collect(),
+            tuning: TpuClientTuning {
+                max_send_attempts: stats.tuning.retry.max_attempts as u32,
+                retry_delay_ms: stats.tuning.retry.base_delay.as_millis() as u32,
+                retry_max_delay_ms: stats.tuning.retry.max_delay.as_millis() as u32,
+                retry_multiplier: stats.tuning.retry.multiplier,
+                retry_jitter: stats.tuning.retry.jitter,
+                leader_send_timeout_ms: stats.tuning.leader_send_timeout.as_millis() as u32,
+                collect_timeout_ms: stats.tuning.collect_timeout.as_millis() as u32,
+                quic_idle_timeout_ms: stats.tuning.quic_idle_timeout.as_millis() as u32,
+                quic_keep_alive_ms: stats.tuning.quic_keep_alive.as_millis() as u32,
+                worker_threads: self.worker_threads as u32,
+                prewarm_interval_ms: stats.prewarm_interval.as_millis() as u32,
+                socket_refresh_interval_ms: stats.socket_refresh_interval.as_millis() as u32,
+            },
+            slot_sources: stats.slot_sources.into_iter().map(Into::into).collect(),
+            warm_start: stats.warm_start,
         }
     }
 
+    /// Renders client metrics in Prometheus text exposition format.
+    ///
+    /// Includes per-leader send counts, failures per error code, connect and
+    /// stream-open latency histograms, 0-RTT acceptance, slot lag versus RPC
+    /// and epoch rotations.
+    #[napi]
+    pub async fn metrics(&self) -> String {
+        self.client.metrics().await
+    }
+
+    /// Rotates the identity used for QUIC client certificates.
+    ///
+    /// Existing connections are closed and re-established with the new
+    /// identity on the next send. Pass nothing to revert to an unstaked identity.
+    #[napi]
+    pub fn set_identity(&self, identity: Option<IdentityKeypair>) -> napi::Result<()> {
+        let keypair = identity
+            .as_ref()
+            .map(|identity| identity.load())
+            .transpose()
+            .context("Failed to load identity keypair")
+            .map_err(anyhow_to_napi)?;
+
+        self.client
+            .set_identity(keypair.as_ref())
+            .map_err(fastlane_to_napi)
+    }
+
     /// Waits for the client to be fully initialized.
     #[napi]
     pub async fn wait_ready(&self) -> napi::Result<()> {
-        let lt = self.leader_tracker.clone();
-
         // Wait for up to 30 seconds for the client to be ready
-        for _ in 0..60 {
-            if lt.is_ready().await {
-                return Ok(());
-            }
-            tokio::time::sleep(Duration::from_millis(500)).await;
+        self.client
+            .wait_ready(Duration::from_secs(30))
+            .await
+            .map_err(fastlane_to_napi)
+    }
+
+    /// Subscribes to client events.
+    ///
+    /// Events cover slot advances, leader window transitions, epoch rotations,
+    /// validator socket changes and per-leader send outcomes. The callback
+    /// does not keep the process alive, and subscribers that fall behind miss
+    /// the oldest events.
+    ///
+    /// # Arguments
+    /// * `kind` - "slot", "leaderChange", "epoch", "sockets", "send", or "*" for all
+    /// * `callback` - Called with each matching event
+    ///
+    /// # Returns
+    /// Subscription id to pass to `off`.
+    #[napi(
+        ts_args_type = "kind: '*' | 'slot' | 'leaderChange' | 'epoch' | 'sockets' | 'send', callback: (event: TpuClientEvent) => void"
+    )]
+    pub fn on(&self, env: Env, kind: String, callback: JsFunction) -> napi::Result<u32> {
+        if kind != "*" && !ClientEvent::KINDS.contains(&kind.as_str()) {
+            return Err(napi::Error::from_reason(format!(
+                "Unknown event kind \"{}\" (expected *, {})",
+                kind,
+                ClientEvent::KINDS.join(", ")
+            )));
         }
 
-        Err(napi::Error::from_reason("Timeout waiting for client to be ready"))
+        let mut listener: ThreadsafeFunction<TpuClientEvent, ErrorStrategy::Fatal> = callback
+            .create_threadsafe_function(0, |ctx: ThreadSafeCallContext<TpuClientEvent>| {
+                Ok(vec![ctx.value])
+            })?;
+        listener.unref(&env)?;
+
+        let mut events = self.client.subscribe();
+        let task = self.runtime.spawn(async move {
+            loop {
+                match events.recv().await {
+                    Ok(event) if kind == "*" || kind == event.kind() => {
+                        let status =
+                            listener.call(event.into(), ThreadsafeFunctionCallMode::NonBlocking);
+                        if status == Status::Closing {
+                            break;
+                        }
+                    }
+                    Ok(_) | Err(RecvError::Lagged(_)) => {}
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+
+        let id = self.next_subscription.fetch_add(1, Ordering::Relaxed);
+        self.subscriptions.lock().unwrap().insert(id, task);
+        Ok(id)
+    }
+
+    /// Removes a subscription created by `on`.
+    ///
+    /// # Returns
+    /// Whether the subscription existed.
+    #[napi]
+    pub fn off(&self, id: u32) -> bool {
+        let task = self.subscriptions.lock().unwrap().remove(&id);
+
+        task.map(|task| task.abort()).is_some()
     }
 
     /// Shuts down the client and closes all connections.
+    ///
+    /// Also removes every event subscription.
     #[napi]
     pub fn shutdown(&mut self) {
-        // Send shutdown signal
-        if let Some(tx) = self.shutdown_tx.take() {
-            let _ = tx.send(());
+        for (_, task) in self.subscriptions.lock().unwrap().drain() {
+            task.abort();
         }
+        self.client.shutdown();
+    }
+}
+
+impl TpuClient {
+    /// Creates a client that routes through a custom leader source.
+    ///
+    /// See [`FastlaneClient::with_leader_source`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the runtime, identity or connection manager
+    /// cannot be initialized.
+    pub fn with_leader_source(
+        config: TpuClientConfig,
+        leader_source: Arc<dyn LeaderSource>,
+    ) -> anyhow::Result<Self> {
+        let worker_threads = config.worker_threads()?;
+        let config = config.into_fastlane_config()?;
+        let runtime = Self::build_runtime(worker_threads)?;
+
+        let client = runtime.block_on(FastlaneClient::with_leader_source(config, leader_source))?;
 
-        // Close all connections
-        self.connection_manager.close_all();
+        Ok(Self {
+            client,
+            worker_threads,
+            subscriptions: Mutex::new(HashMap::new()),
+            next_subscription: AtomicU32::new(1),
+            cancel_tokens: Mutex::new(HashMap::new()),
+            next_cancel_token: AtomicU32::new(1),
+            runtime,
+        })
+    }
+
+    /// Creates the tokio runtime that drives the client.
+    fn build_runtime(worker_threads: usize) -> anyhow::Result<tokio::runtime::Runtime> {
+        tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(worker_threads)
+            .enable_all()
+            .build()
+            .context("Failed to create tokio runtime")
+    }
+}
+
+impl SendUntilConfirmedResult {
+    /// Converts a native outcome, formatting errors for unconfirmed sends.
+    fn from_outcome(outcome: ConfirmOutcome, timeout: Duration) -> Self {
+        Self {
+            confirmed: outcome.confirmed(),
+            signature: outcome.signature.to_string(),
+            rounds: outcome.rounds,
+            total_leaders_sent: outcome.total_leaders_sent,
+            latency_ms: outcome.latency.as_millis() as u32,
+            error: outcome.error_message(timeout),
+            error_code: outcome.error_code().map(|code| code.to_string()),
+        }
+    }
+
+    /// Converts an error that stopped the send before its first round.
+    fn from_error(err: &FastlaneError, latency: Duration) -> Self {
+        Self {
+            confirmed: false,
+            signature: String::new(),
+            rounds: 0,
+            total_leaders_sent: 0,
+            latency_ms: latency.as_millis() as u32,
+            error: Some(err.to_string()),
+            error_code: err.code().map(|code| code.to_string()),
+        }
     }
 }
 
-impl Drop for TpuClient {
-    fn drop(&mut self) {
-        self.shutdown();
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::PreflightError;
+
+    #[test]
+    fn test_rejected_transactions_resolve_with_error_code() {
+        let err = FastlaneError::InvalidTransaction("too short".to_string());
+        let result = SendUntilConfirmedResult::from_error(&err, Duration::from_millis(3));
+        assert!(!result.confirmed);
+        assert_eq!(result.rounds, 0);
+        assert_eq!(result.latency_ms, 3);
+        assert_eq!(result.error_code.as_deref(), Some("INVALID_TRANSACTION"));
+
+        let err = FastlaneError::Preflight(PreflightError::Oversized {
+            size: 1300,
+            limit: 1232,
+        });
+        let result = SendUntilConfirmedResult::from_error(&err, Duration::ZERO);
+        assert_eq!(result.error_code.as_deref(), Some("OVERSIZED"));
+        assert_eq!(result.error, Some(err.to_string()));
     }
 }
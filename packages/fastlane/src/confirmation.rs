@@ -0,0 +1,192 @@
+//! Transaction confirmation via WebSocket signature subscriptions.
+//!
+//! Maintains a single shared PubSub connection and multiplexes one
+//! `signatureSubscribe` per in-flight transaction over it, so concurrent
+//! sends wait on push notifications instead of polling RPC every slot.
+
+use anyhow::{Context, Result};
+use futures_util::StreamExt;
+use solana_client::nonblocking::pubsub_client::PubsubClient;
+use solana_client::rpc_config::RpcSignatureSubscribeConfig;
+use solana_commitment_config::CommitmentConfig;
+use solana_sdk::signature::Signature;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{oneshot, Mutex};
+
+/// Outcome of waiting on a signature subscription.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfirmationStatus {
+    /// Transaction reached confirmed commitment.
+    Confirmed,
+    /// No notification yet; keep waiting.
+    Pending,
+    /// Subscription ended without a notification (connection dropped).
+    /// Callers should fall back to RPC polling.
+    Lost,
+}
+
+/// Shares one WebSocket connection across all signature subscriptions.
+///
+/// The connection is established lazily on first use and re-established
+/// after it drops.
+pub struct ConfirmationTracker {
+    /// WebSocket URL for subscriptions.
+    ws_url: String,
+    /// Shared PubSub client (None until first use or after a disconnect).
+    client: Mutex<Option<Arc<PubsubClient>>>,
+}
+
+/// Pending confirmation for a single signature.
+///
+/// Dropping the waiter unsubscribes from the signature.
+pub struct ConfirmationWaiter {
+    /// Fires once when the signature is confirmed.
+    confirmed_rx: oneshot::Receiver<()>,
+}
+
+impl ConfirmationTracker {
+    /// Creates a new confirmation tracker.
+    ///
+    /// # Arguments
+    ///
+    /// * `ws_url` - WebSocket endpoint URL
+    pub fn new(ws_url: String) -> Self {
+        Self {
+            ws_url,
+            client: Mutex::new(None),
+        }
+    }
+
+    /// Returns the shared PubSub client, connecting if necessary.
+    async fn client(&self) -> Result<Arc<PubsubClient>> {
+        let mut client = self.client.lock().await;
+
+        if let Some(existing) = client.as_ref() {
+            return Ok(existing.clone());
+        }
+
+        let connected = Arc::new(
+            PubsubClient::new(&self.ws_url)
+                .await
+                .context("Failed to connect to WebSocket")?,
+        );
+        *client = Some(connected.clone());
+        Ok(connected)
+    }
+
+    /// Drops the shared client if it is still the given (failed) instance.
+    async fn reset(&self, failed: &Arc<PubsubClient>) {
+        let mut client = self.client.lock().await;
+        if client.as_ref().is_some_and(|c| Arc::ptr_eq(c, failed)) {
+            *client = None;
+        }
+    }
+
+    /// Subscribes to confirmation notifications for a signature.
+    ///
+    /// Returns once the subscription is acknowledged, so a transaction sent
+    /// afterwards cannot confirm before we are listening.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the WebSocket connection or subscription fails.
+    pub async fn subscribe(self: &Arc<Self>, signature: Signature) -> Result<ConfirmationWaiter> {
+        let client = self.client().await?;
+        let tracker = self.clone();
+
+        let (subscribed_tx, subscribed_rx) = oneshot::channel::<Result<()>>();
+        let (mut confirmed_tx, confirmed_rx) = oneshot::channel::<()>();
+
+        // The subscription stream borrows the client, so it lives in its own task
+        tokio::spawn(async move {
+            let config = RpcSignatureSubscribeConfig {
+                commitment: Some(CommitmentConfig::confirmed()),
+                enable_received_notification: Some(false),
+            };
+
+            let (mut notifications, unsubscribe) =
+                match client.signature_subscribe(&signature, Some(config)).await {
+                    Ok(subscription) => subscription,
+                    Err(e) => {
+                        tracker.reset(&client).await;
+                        let _ =
+                            subscribed_tx.send(Err(e).context("Failed to subscribe to signature"));
+                        return;
+                    }
+                };
+            let _ = subscribed_tx.send(Ok(()));
+
+            tokio::select! {
+                notification = notifications.next() => {
+                    match notification {
+                        // Any processed notification at confirmed commitment means confirmed
+                        Some(_) => {
+                            let _ = confirmed_tx.send(());
+                        }
+                        // Stream ended - connection dropped, reconnect on next subscribe
+                        None => tracker.reset(&client).await,
+                    }
+                }
+                // Waiter dropped - stop listening
+                _ = confirmed_tx.closed() => {}
+            }
+
+            drop(notifications);
+            unsubscribe().await;
+        });
+
+        subscribed_rx
+            .await
+            .context("Signature subscription task ended unexpectedly")??;
+
+        Ok(ConfirmationWaiter { confirmed_rx })
+    }
+}
+
+impl ConfirmationWaiter {
+    /// Waits up to `timeout` for the confirmation notification.
+    pub async fn wait(&mut self, timeout: Duration) -> ConfirmationStatus {
+        match tokio::time::timeout(timeout, &mut self.confirmed_rx).await {
+            Ok(Ok(())) => ConfirmationStatus::Confirmed,
+            Ok(Err(_)) => ConfirmationStatus::Lost,
+            Err(_) => ConfirmationStatus::Pending,
+        }
+    }
+}
+
+impl std::fmt::Debug for ConfirmationTracker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConfirmationTracker")
+            .field("ws_url", &self.ws_url)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_waiter_reports_confirmed_pending_and_lost() {
+        let (tx, rx) = oneshot::channel();
+        let mut waiter = ConfirmationWaiter { confirmed_rx: rx };
+        assert_eq!(
+            waiter.wait(Duration::from_millis(1)).await,
+            ConfirmationStatus::Pending
+        );
+        tx.send(()).unwrap();
+        assert_eq!(
+            waiter.wait(Duration::from_millis(1)).await,
+            ConfirmationStatus::Confirmed
+        );
+
+        let (tx, rx) = oneshot::channel::<()>();
+        let mut waiter = ConfirmationWaiter { confirmed_rx: rx };
+        drop(tx);
+        assert_eq!(
+            waiter.wait(Duration::from_millis(1)).await,
+            ConfirmationStatus::Lost
+        );
+    }
+}
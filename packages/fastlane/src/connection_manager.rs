@@ -1,51 +1,146 @@
 //! QUIC connection management for TPU endpoints.
 //!
-//! Maintains a pool of QUIC connections to validator TPU endpoints
-//! with support for connection reuse and 0-RTT reconnection.
+//! Maintains a bounded pool of QUIC connections to validator TPU endpoints
+//! with support for connection reuse and 0-RTT reconnection. When the pool
+//! is full, least recently used connections are evicted, keeping upcoming
+//! leaders where possible; `reap` clears closed and idle entries.
 //!
 //! Features multi-endpoint architecture to avoid Quinn's mutex contention
 //! under high load, distributing connections across multiple QUIC endpoints.
 
 use anyhow::{anyhow, Context, Result};
+use dashmap::mapref::entry::Entry;
 use dashmap::DashMap;
+use futures_util::future::{BoxFuture, Shared};
+use futures_util::{stream, FutureExt, StreamExt};
 use quinn::{
     crypto::rustls::QuicClientConfig, ClientConfig, Connection as QuinnConnection, Endpoint,
     IdleTimeout, TransportConfig,
 };
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use std::collections::HashSet;
 use std::net::SocketAddr;
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant, SystemTime};
+use tokio::task::AbortHandle;
 
-use crate::errors::{classify_error, is_retryable_error, TpuErrorCode};
-use crate::tracker::{LeaderInfo, LeaderTracker};
+use crate::errors::{TpuError, TpuErrorCode};
+use crate::events::{ClientEvent, EventBus};
+use crate::leader_health::LeaderHealth;
+use crate::metrics::ConnectionMetrics;
+use crate::retry::RetryPolicy;
+use crate::tracker::{LeaderInfo, LeaderSource};
 
 /// ALPN protocol identifier for Solana TPU.
 const ALPN_TPU_PROTOCOL_ID: &[u8] = b"solana-tpu";
 
-/// Maximum idle timeout for QUIC connections.
+/// Default maximum idle timeout for QUIC connections.
 /// uses 30s - longer timeout keeps connections warm longer.
 const QUIC_MAX_TIMEOUT: Duration = Duration::from_secs(30);
 
-/// Keep-alive interval for QUIC connections.
+/// Default keep-alive interval for QUIC connections.
 const QUIC_KEEP_ALIVE: Duration = Duration::from_secs(4);
 
-/// Number of QUIC endpoints to distribute connections across.
+/// Default number of QUIC endpoints to distribute connections across.
 /// Multiple endpoints avoid Quinn's internal mutex contention under high load.
 /// Each endpoint has its own event loop for better parallelism.
 const NUM_ENDPOINTS: usize = 5;
 
-/// Maximum retry attempts per leader.
-const MAX_SEND_ATTEMPTS: usize = 3;
-
-/// Delay between retries in milliseconds.
-const RETRY_DELAY_MS: u64 = 50;
-
-/// Timeout for sending to a single leader (connect + all retries).
+/// Default timeout for sending to a single leader (connect + all retries).
 /// Keeps this short - slow leaders won't help landing anyway.
 /// 1 second is enough for connect + send on a healthy validator.
 const LEADER_SEND_TIMEOUT: Duration = Duration::from_secs(1);
 
+/// Default time to collect per-leader results of a send.
+/// Leaders that haven't responded by then probably won't help with landing
+/// (the blockhash might get stale).
+const COLLECT_TIMEOUT: Duration = Duration::from_millis(800);
+
+/// Maximum number of in-flight streams per connection during batch sends.
+/// Quinn additionally blocks `open_uni` on the stream limit advertised by
+/// the validator, so this only bounds how many sends are queued locally.
+const MAX_CONCURRENT_STREAMS_PER_CONNECTION: usize = 64;
+
+/// Timeout for each handshake when probing a validator.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Extra leaders added to the fanout when the current leader is unhealthy.
+const UNHEALTHY_EXTRA_FANOUT: u32 = 2;
+
+/// Default cap on pooled connections.
+pub const DEFAULT_MAX_CONNECTIONS: usize = 128;
+
+/// Slots ahead whose leaders are protected from eviction and idle reaping.
+const PROTECTED_LOOKAHEAD_SLOTS: u64 = 16;
+
+/// Transport and retry tuning for the connection manager.
+///
+/// Defaults suit a frontend-facing sender; low-latency bots may want
+/// tighter timeouts and batch senders more endpoints.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConnectionTuning {
+    /// Per-leader retry policy.
+    pub retry: RetryPolicy,
+    /// Timeout for sending to a single leader (connect + all retries).
+    pub leader_send_timeout: Duration,
+    /// How long a send waits for per-leader results.
+    pub collect_timeout: Duration,
+    /// QUIC idle timeout; connections silent for this long are closed.
+    pub quic_idle_timeout: Duration,
+    /// QUIC keep-alive interval (must be below the idle timeout).
+    pub quic_keep_alive: Duration,
+    /// Number of QUIC endpoints connections are spread across.
+    pub endpoint_count: usize,
+}
+
+impl Default for ConnectionTuning {
+    fn default() -> Self {
+        Self {
+            retry: RetryPolicy::default(),
+            leader_send_timeout: LEADER_SEND_TIMEOUT,
+            collect_timeout: COLLECT_TIMEOUT,
+            quic_idle_timeout: QUIC_MAX_TIMEOUT,
+            quic_keep_alive: QUIC_KEEP_ALIVE,
+            endpoint_count: NUM_ENDPOINTS,
+        }
+    }
+}
+
+impl ConnectionTuning {
+    /// Checks that every value is usable.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the first invalid field.
+    pub fn validate(&self) -> Result<()> {
+        self.retry.validate().context("Invalid retry policy")?;
+        if self.endpoint_count == 0 {
+            return Err(anyhow!("endpoint_count must be at least 1"));
+        }
+        if self.leader_send_timeout.is_zero() {
+            return Err(anyhow!("leader_send_timeout must be positive"));
+        }
+        if self.collect_timeout.is_zero() {
+            return Err(anyhow!("collect_timeout must be positive"));
+        }
+        if self.quic_keep_alive.is_zero() {
+            return Err(anyhow!("quic_keep_alive must be positive"));
+        }
+        if self.quic_keep_alive >= self.quic_idle_timeout {
+            return Err(anyhow!(
+                "quic_keep_alive ({}ms) must be below quic_idle_timeout ({}ms)",
+                self.quic_keep_alive.as_millis(),
+                self.quic_idle_timeout.as_millis()
+            ));
+        }
+        IdleTimeout::try_from(self.quic_idle_timeout)
+            .map_err(|_| anyhow!("quic_idle_timeout is too large"))?;
+        Ok(())
+    }
+}
+
 /// Generates proper QUIC server name (SNI) from socket address.
 /// 
 /// This format is required for validators to properly route QUIC connections.
@@ -56,6 +151,47 @@ fn socket_addr_to_quic_server_name(addr: &SocketAddr) -> String {
     format!("{}.{}.sol", addr.ip(), addr.port())
 }
 
+/// Builds the QUIC client config with a certificate for the given identity.
+///
+/// Validators derive the peer's stake from the certificate pubkey, so a
+/// staked identity receives a larger stream quota than a throwaway one.
+fn build_client_config(
+    identity: Option<&Keypair>,
+    tuning: &ConnectionTuning,
+) -> Result<ClientConfig> {
+    // Generate client certificate for QUIC authentication
+    let client_certificate = solana_tls_utils::QuicClientCertificate::new(identity);
+
+    let mut crypto = solana_tls_utils::tls_client_config_builder()
+        .with_client_auth_cert(
+            vec![client_certificate.certificate.clone()],
+            client_certificate.key.clone_key(),
+        )
+        .context("Failed to set QUIC client certificates")?;
+
+    // Enable 0-RTT for faster reconnection
+    crypto.enable_early_data = true;
+    crypto.alpn_protocols = vec![ALPN_TPU_PROTOCOL_ID.to_vec()];
+
+    // Configure transport settings
+    let transport_config = {
+        let mut config = TransportConfig::default();
+        let timeout = IdleTimeout::try_from(tuning.quic_idle_timeout)
+            .context("QUIC idle timeout out of range")?;
+        config.max_idle_timeout(Some(timeout));
+        config.keep_alive_interval(Some(tuning.quic_keep_alive));
+        config.send_fairness(false);
+        config
+    };
+
+    let mut client_config = ClientConfig::new(Arc::new(
+        QuicClientConfig::try_from(crypto).context("Failed to build QUIC client config")?,
+    ));
+    client_config.transport_config(Arc::new(transport_config));
+
+    Ok(client_config)
+}
+
 /// Result of sending to a single leader.
 #[derive(Debug, Clone)]
 pub struct LeaderDeliveryResult {
@@ -75,6 +211,19 @@ pub struct LeaderDeliveryResult {
     pub attempts: usize,
 }
 
+/// Result of probing QUIC connectivity to a validator.
+#[derive(Debug, Clone)]
+pub struct ProbeResult {
+    /// Time to complete a full handshake.
+    pub handshake: Duration,
+    /// Time to establish the resumed connection.
+    pub resumed_handshake: Duration,
+    /// Whether the resumed connection attempted 0-RTT (a session ticket was received).
+    pub zero_rtt_attempted: bool,
+    /// Whether the validator accepted 0-RTT data.
+    pub zero_rtt_accepted: bool,
+}
+
 /// Result of a transaction delivery attempt.
 #[derive(Debug, Clone)]
 pub struct DeliveryResult {
@@ -90,10 +239,73 @@ pub struct DeliveryResult {
     pub total_retries: usize,
 }
 
+/// State of a pooled connection, for stats.
+#[derive(Debug, Clone)]
+pub struct PooledConnection {
+    /// TPU socket address.
+    pub address: String,
+    /// Whether the connection is established and open.
+    pub open: bool,
+    /// Time since the connection was last used for a send or prewarm.
+    pub idle: Duration,
+    /// Wall-clock time of last use.
+    pub last_used: SystemTime,
+}
+
+/// In-flight connect shared by every caller waiting on the same address.
+type ConnectFuture = Shared<BoxFuture<'static, std::result::Result<QuinnConnection, TpuError>>>;
+
+/// Connection state of a pool entry.
+enum ConnectionState {
+    /// Handshake in progress; concurrent callers await the same future.
+    Connecting(ConnectFuture),
+    /// Established connection (may since have been closed by the peer).
+    Ready(QuinnConnection),
+}
+
 /// Wrapper for a cached QUIC connection.
-#[derive(Default)]
 struct CachedConnection {
-    conn: Option<QuinnConnection>,
+    /// Established connection or in-flight connect.
+    state: ConnectionState,
+    /// When the entry was created or last handed out.
+    last_used: Instant,
+}
+
+impl CachedConnection {
+    /// Creates an entry for a connection being established.
+    fn connecting(connect: ConnectFuture) -> Self {
+        Self {
+            state: ConnectionState::Connecting(connect),
+            last_used: Instant::now(),
+        }
+    }
+
+    /// Returns the established connection, if any.
+    fn connection(&self) -> Option<&QuinnConnection> {
+        match &self.state {
+            ConnectionState::Ready(conn) => Some(conn),
+            ConnectionState::Connecting(_) => None,
+        }
+    }
+}
+
+/// Spawned per-leader send tasks, aborted if the send is dropped first
+/// (e.g. when the caller cancels it).
+struct LeaderTasks(Vec<AbortHandle>);
+
+impl LeaderTasks {
+    /// Lets the tasks run to completion even after the send returns.
+    fn detach(mut self) {
+        self.0.clear();
+    }
+}
+
+impl Drop for LeaderTasks {
+    fn drop(&mut self) {
+        for task in &self.0 {
+            task.abort();
+        }
+    }
 }
 
 /// Manages QUIC connections to Solana TPU endpoints.
@@ -103,16 +315,34 @@ struct CachedConnection {
 /// - Connection pooling with automatic reconnection
 /// - 0-RTT support for faster reconnection
 /// - Pre-warming connections to upcoming leaders
+/// - Staked identity certificates with runtime rotation
+/// - Per-leader health tracking for adaptive routing
+/// - Bounded pool with LRU eviction and idle reaping
 pub struct TpuConnectionManager {
     /// Multiple QUIC endpoints to distribute load across.
     /// Each endpoint has its own event loop for better parallelism.
     endpoints: Vec<Endpoint>,
+    /// QUIC client config carrying the identity certificate.
+    /// Shared across clones so identity rotation applies everywhere.
+    client_config: Arc<RwLock<ClientConfig>>,
+    /// Identity pubkey used for the client certificate (None if unstaked).
+    identity: Arc<RwLock<Option<String>>>,
     /// Cached connections by address.
     connections: Arc<DashMap<String, CachedConnection>>,
-    /// Leader tracker for routing.
-    leader_tracker: Arc<LeaderTracker>,
+    /// Leader source for routing.
+    leader_source: Arc<dyn LeaderSource>,
     /// Round-robin counter for endpoint selection.
     next_endpoint: Arc<AtomicUsize>,
+    /// Send and connection metrics, shared across clones.
+    metrics: Arc<ConnectionMetrics>,
+    /// Per-leader send and connect health, shared across clones.
+    health: Arc<LeaderHealth>,
+    /// Maximum number of pooled connections.
+    max_connections: usize,
+    /// Transport and retry tuning.
+    tuning: ConnectionTuning,
+    /// Publishes per-leader send outcomes.
+    events: EventBus,
 }
 
 impl TpuConnectionManager {
@@ -120,61 +350,124 @@ impl TpuConnectionManager {
     ///
     /// # Arguments
     ///
-    /// * `leader_tracker` - Leader tracker for determining where to send transactions
+    /// * `leader_source` - Leader source for determining where to send transactions
+    /// * `identity` - Optional staked identity keypair for the QUIC client certificate.
+    ///   When `None`, a throwaway unstaked identity is generated.
     ///
     /// # Errors
     ///
     /// Returns an error if any QUIC endpoint cannot be initialized.
-    pub fn new(leader_tracker: Arc<LeaderTracker>) -> Result<Self> {
-        // Generate client certificate for QUIC authentication
-        let client_certificate = solana_tls_utils::QuicClientCertificate::new(None);
-
-        let mut crypto = solana_tls_utils::tls_client_config_builder()
-            .with_client_auth_cert(
-                vec![client_certificate.certificate.clone()],
-                client_certificate.key.clone_key(),
-            )
-            .expect("Failed to set QUIC client certificates");
-
-        // Enable 0-RTT for faster reconnection
-        crypto.enable_early_data = true;
-        crypto.alpn_protocols = vec![ALPN_TPU_PROTOCOL_ID.to_vec()];
-
-        // Configure transport settings
-        let transport_config = {
-            let mut config = TransportConfig::default();
-            let timeout = IdleTimeout::try_from(QUIC_MAX_TIMEOUT).unwrap();
-            config.max_idle_timeout(Some(timeout));
-            config.keep_alive_interval(Some(QUIC_KEEP_ALIVE));
-            config.send_fairness(false);
-            config
-        };
+    pub fn new(leader_source: Arc<dyn LeaderSource>, identity: Option<&Keypair>) -> Result<Self> {
+        Self::with_tuning(leader_source, identity, ConnectionTuning::default())
+    }
 
-        let client_config =
-            ClientConfig::new(Arc::new(QuicClientConfig::try_from(crypto).unwrap()));
-        let client_config = {
-            let mut cfg = client_config;
-            cfg.transport_config(Arc::new(transport_config));
-            cfg
-        };
+    /// Creates a TPU connection manager with custom transport and retry tuning.
+    ///
+    /// # Arguments
+    ///
+    /// * `leader_source` - Leader source for determining where to send transactions
+    /// * `identity` - Optional staked identity keypair for the QUIC client certificate
+    /// * `tuning` - Transport and retry tuning
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tuning is invalid or any QUIC endpoint cannot
+    /// be initialized.
+    pub fn with_tuning(
+        leader_source: Arc<dyn LeaderSource>,
+        identity: Option<&Keypair>,
+        tuning: ConnectionTuning,
+    ) -> Result<Self> {
+        tuning.validate()?;
+        let client_config = build_client_config(identity, &tuning)?;
 
         // Create multiple QUIC endpoints to distribute load
-        let mut endpoints = Vec::with_capacity(NUM_ENDPOINTS);
-        for i in 0..NUM_ENDPOINTS {
-            let mut endpoint = Endpoint::client("0.0.0.0:0".parse()?)
+        let mut endpoints = Vec::with_capacity(tuning.endpoint_count);
+        for i in 0..tuning.endpoint_count {
+            let endpoint = Endpoint::client("0.0.0.0:0".parse()?)
                 .context(format!("Failed to create QUIC endpoint {}", i))?;
-            endpoint.set_default_client_config(client_config.clone());
             endpoints.push(endpoint);
         }
 
         Ok(Self {
             endpoints,
+            client_config: Arc::new(RwLock::new(client_config)),
+            identity: Arc::new(RwLock::new(identity.map(|k| k.pubkey().to_string()))),
             connections: Arc::new(DashMap::new()),
-            leader_tracker,
+            leader_source,
             next_endpoint: Arc::new(AtomicUsize::new(0)),
+            metrics: Arc::new(ConnectionMetrics::default()),
+            health: Arc::new(LeaderHealth::default()),
+            max_connections: DEFAULT_MAX_CONNECTIONS,
+            tuning,
+            events: EventBus::default(),
         })
     }
 
+    /// Sets the maximum number of pooled connections (at least 1).
+    ///
+    /// When a new connection would exceed the cap, the least recently used
+    /// connections are evicted, preferring to keep upcoming leaders.
+    pub fn with_max_connections(mut self, max_connections: usize) -> Self {
+        self.max_connections = max_connections.max(1);
+        self
+    }
+
+    /// Publishes per-leader send outcomes on the given bus.
+    pub fn with_events(mut self, events: EventBus) -> Self {
+        self.events = events;
+        self
+    }
+
+    /// Returns the maximum number of pooled connections.
+    pub fn max_connections(&self) -> usize {
+        self.max_connections
+    }
+
+    /// Returns the transport and retry tuning in effect.
+    pub fn tuning(&self) -> ConnectionTuning {
+        self.tuning
+    }
+
+    /// Replaces the identity used for QUIC client certificates.
+    ///
+    /// Existing connections were authenticated with the previous identity,
+    /// so they are closed and re-established on the next send or prewarm.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity` - New staked identity keypair, or `None` for an unstaked identity
+    pub fn set_identity(&self, identity: Option<&Keypair>) -> Result<()> {
+        let client_config = build_client_config(identity, &self.tuning)?;
+
+        *self.client_config.write().unwrap() = client_config;
+        *self.identity.write().unwrap() = identity.map(|k| k.pubkey().to_string());
+
+        self.close_all();
+        Ok(())
+    }
+
+    /// Returns the identity pubkey used for QUIC client certificates,
+    /// or `None` when connecting with an unstaked identity.
+    pub fn identity(&self) -> Option<String> {
+        self.identity.read().unwrap().clone()
+    }
+
+    /// Returns the number of QUIC endpoints connections are spread across.
+    pub fn endpoint_count(&self) -> usize {
+        self.endpoints.len()
+    }
+
+    /// Returns the send and connection metrics.
+    pub fn metrics(&self) -> &ConnectionMetrics {
+        &self.metrics
+    }
+
+    /// Returns per-leader health used for routing and prewarming.
+    pub fn health(&self) -> &LeaderHealth {
+        &self.health
+    }
+
     /// Selects the next endpoint using round-robin distribution.
     fn select_endpoint(&self) -> &Endpoint {
         let idx = self.next_endpoint.fetch_add(1, Ordering::Relaxed) % self.endpoints.len();
@@ -205,7 +498,15 @@ impl TpuConnectionManager {
     /// Delivery result with per-leader breakdown and retry statistics.
     pub async fn send_transaction_with_fanout(&self, tx_data: &[u8], fanout: u32) -> Result<DeliveryResult> {
         let start = Instant::now();
-        let leaders = self.leader_tracker.get_leaders_with_fanout(fanout).await;
+        let mut leaders = self.leader_source.get_leaders_with_fanout(fanout).await;
+
+        // Hedge with extra leaders when the current one keeps failing
+        if self.health.needs_extended_fanout(&leaders) {
+            leaders = self
+                .leader_source
+                .get_leaders_with_fanout(fanout + UNHEALTHY_EXTRA_FANOUT)
+                .await;
+        }
 
         if leaders.is_empty() {
             return Err(anyhow!("No leaders available"));
@@ -241,9 +542,9 @@ impl TpuConnectionManager {
         let mut total_retries = 0;
         let mut first_success_latency: Option<u64> = None;
 
-        // Wait for results with a short timeout - if leaders haven't responded by 800ms,
-        // they probably won't help with landing (blockhash might get stale)
-        let collect_timeout = Duration::from_millis(800);
+        // Wait for results with a short timeout - leaders that haven't responded
+        // by then probably won't help with landing (blockhash might get stale)
+        let collect_timeout = self.tuning.collect_timeout;
         let collect_start = std::time::Instant::now();
 
         while let Ok(Some(result)) = tokio::time::timeout(
@@ -314,6 +615,7 @@ impl TpuConnectionManager {
         let (tx, mut rx) = tokio::sync::mpsc::channel::<LeaderDeliveryResult>(leaders.len());
 
         // Spawn all send tasks
+        let mut tasks = LeaderTasks(Vec::with_capacity(leaders.len()));
         for leader in leaders {
             let tx_data = tx_data.to_vec();
             let tpu_socket = leader.tpu_socket.clone();
@@ -321,12 +623,13 @@ impl TpuConnectionManager {
             let manager = self.clone();
             let result_tx = tx.clone();
 
-            tokio::spawn(async move {
+            let task = tokio::spawn(async move {
                 let result = manager
                     .send_to_leader_with_retry(&tx_data, &tpu_socket, &identity)
                     .await;
                 let _ = result_tx.send(result).await;
             });
+            tasks.0.push(task.abort_handle());
         }
 
         drop(tx);
@@ -336,7 +639,7 @@ impl TpuConnectionManager {
         let mut success_count = 0;
         let mut total_retries = 0;
 
-        let collect_timeout = Duration::from_millis(800);
+        let collect_timeout = self.tuning.collect_timeout;
         let collect_start = std::time::Instant::now();
 
         while let Ok(Some(result)) = tokio::time::timeout(
@@ -358,6 +661,9 @@ impl TpuConnectionManager {
             }
         }
 
+        // Leaders slower than the collect timeout still finish in the background
+        tasks.detach();
+
         let delivered = success_count > 0;
 
         if !delivered {
@@ -376,6 +682,111 @@ impl TpuConnectionManager {
         })
     }
 
+    /// Sends a batch of transactions to specific leaders.
+    ///
+    /// Each leader's connection is established once, then all transactions
+    /// are sent concurrently over separate unidirectional streams on that
+    /// connection. Concurrency per connection is bounded and `open_uni`
+    /// waits for stream credit, so the validator's advertised stream limit
+    /// is respected.
+    ///
+    /// # Arguments
+    ///
+    /// * `transactions` - Serialized transaction bytes
+    /// * `leaders` - Explicit list of leaders to send to
+    ///
+    /// # Returns
+    ///
+    /// One delivery result per transaction, in input order.
+    pub async fn send_batch_to_leaders(
+        &self,
+        transactions: &[Vec<u8>],
+        leaders: &[LeaderInfo],
+    ) -> Result<Vec<DeliveryResult>> {
+        if leaders.is_empty() {
+            return Err(anyhow!("No leaders provided"));
+        }
+
+        let start = Instant::now();
+        let transactions = Arc::new(transactions.to_vec());
+
+        // Spawn one task per leader; each task sends the whole batch
+        let handles: Vec<_> = leaders
+            .iter()
+            .map(|leader| {
+                let manager = self.clone();
+                let transactions = transactions.clone();
+                let tpu_socket = leader.tpu_socket.clone();
+                let identity = leader.identity.clone();
+
+                tokio::spawn(async move {
+                    manager
+                        .send_batch_to_leader(&transactions, &tpu_socket, &identity)
+                        .await
+                })
+            })
+            .collect();
+
+        // Per-transaction results across all leaders
+        let mut per_tx: Vec<Vec<LeaderDeliveryResult>> = (0..transactions.len())
+            .map(|_| Vec::with_capacity(leaders.len()))
+            .collect();
+
+        let _tasks = LeaderTasks(handles.iter().map(|task| task.abort_handle()).collect());
+        for task in handles {
+            if let Ok(results) = task.await {
+                for (idx, result) in results.into_iter().enumerate() {
+                    per_tx[idx].push(result);
+                }
+            }
+        }
+
+        let latency_ms = start.elapsed().as_millis() as u64;
+
+        Ok(per_tx
+            .into_iter()
+            .map(|leader_results| {
+                let leader_count = leader_results.iter().filter(|r| r.success).count();
+                let total_retries = leader_results
+                    .iter()
+                    .map(|r| r.attempts.saturating_sub(1))
+                    .sum();
+
+                DeliveryResult {
+                    delivered: leader_count > 0,
+                    latency_ms,
+                    leader_count,
+                    leaders: leader_results,
+                    total_retries,
+                }
+            })
+            .collect())
+    }
+
+    /// Sends a batch of transactions to a single leader over one connection.
+    ///
+    /// Returns one result per transaction, in input order.
+    async fn send_batch_to_leader(
+        &self,
+        transactions: &[Vec<u8>],
+        tpu_address: &str,
+        identity: &str,
+    ) -> Vec<LeaderDeliveryResult> {
+        // Establish the connection once up front so concurrent streams share it.
+        // Failures are retried (and classified) by the per-transaction sends below.
+        let _ = self.get_or_create_connection(tpu_address).await;
+
+        let sends: Vec<_> = transactions
+            .iter()
+            .map(|tx_data| self.send_to_leader_with_retry(tx_data, tpu_address, identity))
+            .collect();
+
+        stream::iter(sends)
+            .buffered(MAX_CONCURRENT_STREAMS_PER_CONNECTION)
+            .collect()
+            .await
+    }
+
     /// Sends transaction data to a specific leader with retry logic and timeout.
     ///
     /// Wraps the inner retry logic in a timeout to prevent slow leaders
@@ -386,8 +797,9 @@ impl TpuConnectionManager {
         tpu_address: &str,
         identity: &str,
     ) -> LeaderDeliveryResult {
-        match tokio::time::timeout(
-            LEADER_SEND_TIMEOUT,
+        let timeout = self.tuning.leader_send_timeout;
+        let result = match tokio::time::timeout(
+            timeout,
             self.send_to_leader_with_retry_inner(tx_data, tpu_address, identity),
         )
         .await
@@ -395,23 +807,35 @@ impl TpuConnectionManager {
             Ok(result) => result,
             Err(_) => {
                 // Timeout elapsed - leader is too slow or unreachable
+                let error = TpuError::Timeout(timeout);
                 LeaderDeliveryResult {
                     identity: identity.to_string(),
                     address: tpu_address.to_string(),
                     success: false,
-                    latency_ms: LEADER_SEND_TIMEOUT.as_millis() as u64,
-                    error: Some(format!("Timeout after {:?}", LEADER_SEND_TIMEOUT)),
-                    error_code: Some(TpuErrorCode::Timeout),
-                    attempts: MAX_SEND_ATTEMPTS,
+                    latency_ms: timeout.as_millis() as u64,
+                    error: Some(error.to_string()),
+                    error_code: Some(error.code()),
+                    attempts: self.tuning.retry.max_attempts,
                 }
             }
+        };
+
+        self.metrics.leader_sends.inc(identity);
+        if let Some(code) = result.error_code {
+            self.metrics.send_failures.inc(&code.to_string());
+        }
+        self.health.record_send(identity, tpu_address, result.error_code);
+        if self.events.has_subscribers() {
+            self.events.emit(ClientEvent::LeaderSend(result.clone()));
         }
+
+        result
     }
 
     /// Inner retry logic for sending to a leader.
     ///
-    /// Retries on retryable errors (connection, stream, timeout) with
-    /// a small delay between attempts.
+    /// Retries on retryable errors (connection, stream, timeout), waiting
+    /// between attempts as the retry policy decides.
     async fn send_to_leader_with_retry_inner(
         &self,
         tx_data: &[u8],
@@ -422,7 +846,10 @@ impl TpuConnectionManager {
         let mut last_error: Option<String> = None;
         let mut error_code: Option<TpuErrorCode> = None;
 
-        for attempt in 0..MAX_SEND_ATTEMPTS {
+        let policy = self.tuning.retry;
+        let mut attempts = 0;
+        for attempt in 0..policy.max_attempts {
+            attempts = attempt + 1;
             match self.send_to_leader_once(tx_data, tpu_address).await {
                 Ok(_) => {
                     return LeaderDeliveryResult {
@@ -432,17 +859,17 @@ impl TpuConnectionManager {
                         latency_ms: start.elapsed().as_millis() as u64,
                         error: None,
                         error_code: None,
-                        attempts: attempt + 1,
+                        attempts,
                     };
                 }
                 Err(e) => {
-                    let code = classify_error(&e);
                     last_error = Some(e.to_string());
-                    error_code = Some(code);
+                    error_code = Some(e.code());
 
                     // Only retry on retryable errors and if we have attempts left
-                    if attempt < MAX_SEND_ATTEMPTS - 1 && is_retryable_error(&e) {
-                        tokio::time::sleep(Duration::from_millis(RETRY_DELAY_MS)).await;
+                    match policy.next_delay(e.code(), attempt) {
+                        Some(delay) => tokio::time::sleep(delay).await,
+                        None => break,
                     }
                 }
             }
@@ -455,94 +882,376 @@ impl TpuConnectionManager {
             latency_ms: start.elapsed().as_millis() as u64,
             error: last_error,
             error_code,
-            attempts: MAX_SEND_ATTEMPTS,
+            attempts,
         }
     }
 
     /// Sends transaction data to a specific leader (single attempt).
-    async fn send_to_leader_once(&self, tx_data: &[u8], tpu_address: &str) -> Result<()> {
+    async fn send_to_leader_once(
+        &self,
+        tx_data: &[u8],
+        tpu_address: &str,
+    ) -> std::result::Result<(), TpuError> {
         let conn = self.get_or_create_connection(tpu_address).await?;
 
+        let result = self.write_transaction(&conn, tx_data).await;
+
+        // Errors that leave the connection unusable force a fresh one next time
+        if let Err(e) = &result {
+            if e.code().requires_reconnect() {
+                self.discard_connection(tpu_address, &conn);
+            }
+        }
+
+        result
+    }
+
+    /// Writes transaction data on its own unidirectional stream.
+    async fn write_transaction(
+        &self,
+        conn: &QuinnConnection,
+        tx_data: &[u8],
+    ) -> std::result::Result<(), TpuError> {
         // Open unidirectional stream for transaction
-        let mut send_stream = conn
-            .open_uni()
-            .await
-            .context("Failed to open unidirectional stream")?;
+        let stream_start = Instant::now();
+        let mut send_stream = conn.open_uni().await?;
+        self.metrics
+            .stream_open_latency
+            .observe(stream_start.elapsed());
 
         // Write transaction data
-        send_stream
-            .write_all(tx_data)
-            .await
-            .context("Failed to write transaction data")?;
+        send_stream.write_all(tx_data).await?;
 
         // Finish the stream (no response expected)
-        send_stream.finish().context("Failed to finish stream")?;
+        send_stream.finish()?;
 
         Ok(())
     }
 
+    /// Removes a connection from the pool if it is still the pooled one.
+    ///
+    /// The connection is not closed, so streams still in flight on it can
+    /// finish; it closes once the last handle is dropped.
+    fn discard_connection(&self, address: &str, conn: &QuinnConnection) {
+        self.connections.remove_if(address, |_, cached| {
+            cached
+                .connection()
+                .map(|pooled| pooled.stable_id() == conn.stable_id())
+                .unwrap_or(false)
+        });
+    }
+
     /// Gets an existing connection or creates a new one.
     ///
-    /// Uses round-robin endpoint selection for new connections to
-    /// distribute load across all available QUIC endpoints.
-    async fn get_or_create_connection(&self, address: &str) -> Result<QuinnConnection> {
+    /// Connects are single-flight per address: concurrent callers (sends
+    /// and the prewarmer) await the same in-progress handshake instead of
+    /// each opening a connection, since validators count every handshake
+    /// against the client's connection quota.
+    async fn get_or_create_connection(
+        &self,
+        address: &str,
+    ) -> std::result::Result<QuinnConnection, TpuError> {
         // Check for existing active connection
-        if let Some(cached) = self.connections.get(address) {
-            if let Some(ref conn) = cached.conn {
+        if let Some(mut cached) = self.connections.get_mut(address) {
+            if let ConnectionState::Ready(conn) = &cached.state {
                 if conn.close_reason().is_none() {
-                    return Ok(conn.clone());
+                    let conn = conn.clone();
+                    cached.last_used = Instant::now();
+                    return Ok(conn);
                 }
             }
         }
 
-        // Mark as connecting (prevents duplicate connection attempts)
-        self.connections
-            .insert(address.to_string(), CachedConnection::default());
-        let addr: SocketAddr = address.parse().context("Invalid validator address")?;
+        let addr: SocketAddr = address.parse().map_err(|source| TpuError::InvalidAddress {
+            address: address.to_string(),
+            source,
+        })?;
+
+        // Join the in-flight connect, or start one. The entry lock is held
+        // only while deciding, never across the handshake.
+        let connect = match self.connections.entry(address.to_string()) {
+            Entry::Occupied(mut entry) => match &entry.get().state {
+                ConnectionState::Connecting(connect) => connect.clone(),
+                ConnectionState::Ready(conn) if conn.close_reason().is_none() => {
+                    let conn = conn.clone();
+                    entry.get_mut().last_used = Instant::now();
+                    return Ok(conn);
+                }
+                ConnectionState::Ready(_) => {
+                    let connect = self.start_connect(address, addr);
+                    entry.insert(CachedConnection::connecting(connect.clone()));
+                    connect
+                }
+            },
+            Entry::Vacant(entry) => {
+                let connect = self.start_connect(address, addr);
+                entry.insert(CachedConnection::connecting(connect.clone()));
+                connect
+            }
+        };
 
+        let result = connect.clone().await;
+
+        // The first caller to finish settles the entry; later callers find it settled
+        let is_pending = |cached: &CachedConnection| {
+            matches!(&cached.state, ConnectionState::Connecting(pending) if pending.ptr_eq(&connect))
+        };
+        match &result {
+            Ok(conn) => {
+                let settled = match self.connections.get_mut(address) {
+                    Some(mut cached) if is_pending(&cached) => {
+                        cached.state = ConnectionState::Ready(conn.clone());
+                        cached.last_used = Instant::now();
+                        true
+                    }
+                    _ => false,
+                };
+                if settled {
+                    self.enforce_capacity(address).await;
+                }
+            }
+            Err(_) => {
+                self.connections
+                    .remove_if(address, |_, cached| is_pending(cached));
+            }
+        }
+
+        result
+    }
+
+    /// Starts a shared connect to an address.
+    ///
+    /// The returned future performs the handshake (trying 0-RTT first) and
+    /// records metrics and leader health exactly once, however many callers
+    /// await it.
+    fn start_connect(&self, address: &str, addr: SocketAddr) -> ConnectFuture {
         // Select endpoint using round-robin for load distribution
-        let endpoint = self.select_endpoint();
+        let endpoint = self.select_endpoint().clone();
 
         // Generate proper SNI - validators require correct format for routing
         let server_name = socket_addr_to_quic_server_name(&addr);
 
-        // Try 0-RTT connection first for lower latency
-        let connection = match endpoint.connect(addr, &server_name)?.into_0rtt() {
-            Ok((conn, rtt_accepted)) => {
-                let _ = rtt_accepted.await;
-                conn
-            }
-            Err(connecting) => {
-                match connecting.await {
-                    Ok(conn) => conn,
-                    Err(e) => {
-                        // Failed to connect - remove from cache
-                        self.connections.remove(address);
-                        return Err(e.into());
+        // Use the current identity's client config (may be rotated at runtime)
+        let client_config = self.client_config.read().unwrap().clone();
+
+        let metrics = self.metrics.clone();
+        let health = self.health.clone();
+        let address = address.to_string();
+
+        async move {
+            let connect_start = Instant::now();
+            let result = async {
+                let connecting = endpoint.connect_with(client_config, addr, &server_name)?;
+
+                // Try 0-RTT connection first for lower latency
+                match connecting.into_0rtt() {
+                    Ok((conn, rtt_accepted)) => {
+                        metrics.zero_rtt_attempts.inc();
+                        if rtt_accepted.await {
+                            metrics.zero_rtt_accepted.inc();
+                        }
+                        Ok(conn)
                     }
+                    Err(connecting) => Ok(connecting.await?),
                 }
             }
-        };
+            .await;
 
-        // Cache the connection
-        self.connections.insert(
-            address.to_string(),
-            CachedConnection {
-                conn: Some(connection.clone()),
-            },
-        );
+            match &result {
+                Ok(_) => {
+                    let connect_latency = connect_start.elapsed();
+                    metrics.connect_latency.observe(connect_latency);
+                    health.record_connect(&address, connect_latency);
+                }
+                Err(_) => health.record_connect_failure(&address),
+            }
+            result
+        }
+        .boxed()
+        .shared()
+    }
+
+    /// Returns the TPU addresses of leaders in the protected lookahead.
+    async fn upcoming_addresses(&self) -> HashSet<String> {
+        self.leader_source
+            .get_future_leaders(0, PROTECTED_LOOKAHEAD_SLOTS)
+            .await
+            .into_iter()
+            .map(|leader| leader.tpu_socket)
+            .collect()
+    }
+
+    /// Evicts least recently used connections while the pool is over capacity.
+    ///
+    /// Connections to upcoming leaders are evicted only when nothing else
+    /// is left. The connection at `keep` (just established) is never evicted,
+    /// and placeholders for in-flight handshakes do not count. Evicted
+    /// connections are only dropped from the pool, so in-flight sends still
+    /// holding them finish; they close once the last handle is released.
+    async fn enforce_capacity(&self, keep: &str) {
+        let established = self
+            .connections
+            .iter()
+            .filter(|entry| entry.value().connection().is_some())
+            .count();
+        let excess = established.saturating_sub(self.max_connections);
+        if excess == 0 {
+            return;
+        }
+
+        let upcoming = self.upcoming_addresses().await;
+        let mut candidates: Vec<(bool, Instant, String)> = self
+            .connections
+            .iter()
+            .filter(|entry| entry.key() != keep && entry.value().connection().is_some())
+            .map(|entry| {
+                (
+                    upcoming.contains(entry.key()),
+                    entry.value().last_used,
+                    entry.key().clone(),
+                )
+            })
+            .collect();
+
+        // Non-upcoming before upcoming, then least recently used first
+        candidates.sort();
+
+        for (_, _, address) in candidates.into_iter().take(excess) {
+            if self.connections.remove(&address).is_some() {
+                self.metrics.connection_evictions.inc();
+            }
+        }
+    }
+
+    /// Removes closed, abandoned and idle entries from the pool.
+    ///
+    /// Entries whose connection has a close reason are dropped, as are
+    /// connecting placeholders left behind by cancelled sends. Open
+    /// connections unused for longer than `idle_timeout` are dropped unless
+    /// they belong to an upcoming leader, like evicted ones. Stale leader
+    /// health is pruned as well.
+    ///
+    /// # Returns
+    ///
+    /// Number of entries removed.
+    pub async fn reap(&self, idle_timeout: Duration) -> usize {
+        let upcoming = self.upcoming_addresses().await;
+        let mut reaped = 0;
+
+        self.connections.retain(|address, cached| {
+            let keep = match cached.connection() {
+                Some(conn) if conn.close_reason().is_some() => false,
+                Some(_) => {
+                    cached.last_used.elapsed() <= idle_timeout || upcoming.contains(address)
+                }
+                // A handshake cannot outlive the QUIC idle timeout
+                None => cached.last_used.elapsed() < self.tuning.quic_idle_timeout,
+            };
+            if !keep {
+                reaped += 1;
+            }
+            keep
+        });
+
+        self.metrics.connections_reaped.add(reaped as u64);
+        self.health.prune();
+        reaped
+    }
 
-        Ok(connection)
+    /// Returns the state of every pooled entry, most recently used first.
+    pub fn pooled_connections(&self) -> Vec<PooledConnection> {
+        let now = SystemTime::now();
+        let mut pooled: Vec<PooledConnection> = self
+            .connections
+            .iter()
+            .map(|entry| {
+                let cached = entry.value();
+                let idle = cached.last_used.elapsed();
+                PooledConnection {
+                    address: entry.key().clone(),
+                    open: cached
+                        .connection()
+                        .map(|c| c.close_reason().is_none())
+                        .unwrap_or(false),
+                    idle,
+                    last_used: now.checked_sub(idle).unwrap_or(now),
+                }
+            })
+            .collect();
+
+        pooled.sort_by_key(|conn| conn.idle);
+        pooled
+    }
+
+    /// Probes QUIC connectivity and 0-RTT support of a validator.
+    ///
+    /// Performs a full handshake, closes it, then reconnects to check
+    /// whether session resumption with 0-RTT is accepted. Probe connections
+    /// bypass the connection cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the address is invalid or either handshake fails.
+    pub async fn probe(&self, address: &str) -> std::result::Result<ProbeResult, TpuError> {
+        let addr: SocketAddr = address.parse().map_err(|source| TpuError::InvalidAddress {
+            address: address.to_string(),
+            source,
+        })?;
+        let server_name = socket_addr_to_quic_server_name(&addr);
+        let endpoint = self.select_endpoint();
+
+        // Full handshake (also obtains a session ticket for resumption)
+        let start = Instant::now();
+        let client_config = self.client_config.read().unwrap().clone();
+        let connecting = endpoint.connect_with(client_config, addr, &server_name)?;
+        let connection = tokio::time::timeout(PROBE_TIMEOUT, connecting)
+            .await
+            .map_err(|_| TpuError::Timeout(PROBE_TIMEOUT))??;
+        let handshake = start.elapsed();
+        connection.close(0u32.into(), b"probe");
+
+        // Resumed handshake, attempting 0-RTT
+        let start = Instant::now();
+        let client_config = self.client_config.read().unwrap().clone();
+        let connecting = endpoint.connect_with(client_config, addr, &server_name)?;
+        let (connection, zero_rtt_attempted, zero_rtt_accepted) = match connecting.into_0rtt() {
+            Ok((connection, accepted)) => {
+                let accepted = tokio::time::timeout(PROBE_TIMEOUT, accepted)
+                    .await
+                    .map_err(|_| TpuError::Timeout(PROBE_TIMEOUT))?;
+                (connection, true, accepted)
+            }
+            Err(connecting) => {
+                let connection = tokio::time::timeout(PROBE_TIMEOUT, connecting)
+                    .await
+                    .map_err(|_| TpuError::Timeout(PROBE_TIMEOUT))??;
+                (connection, false, false)
+            }
+        };
+        let resumed_handshake = start.elapsed();
+        connection.close(0u32.into(), b"probe");
+
+        Ok(ProbeResult {
+            handshake,
+            resumed_handshake,
+            zero_rtt_attempted,
+            zero_rtt_accepted,
+        })
     }
 
     /// Pre-warms connections to upcoming leaders.
     ///
     /// This should be called periodically to ensure connections are
-    /// ready when needed for transaction submission.
+    /// ready when needed for transaction submission. Addresses that keep
+    /// failing to connect are skipped until their backoff elapses.
     pub async fn prewarm_connections(&self, lookahead: u64) {
-        let leaders = self.leader_tracker.get_future_leaders(0, lookahead).await;
+        let leaders = self.leader_source.get_future_leaders(0, lookahead).await;
 
         for leader in leaders {
+            if !self.health.should_prewarm(&leader.tpu_socket) {
+                continue;
+            }
+
             let manager = self.clone();
             let socket = leader.tpu_socket.clone();
 
@@ -559,8 +1268,7 @@ impl TpuConnectionManager {
             .filter(|entry| {
                 entry
                     .value()
-                    .conn
-                    .as_ref()
+                    .connection()
                     .map(|c| c.close_reason().is_none())
                     .unwrap_or(false)
             })
@@ -572,7 +1280,7 @@ impl TpuConnectionManager {
     /// Note: Endpoints will clean up remaining state when dropped.
     pub fn close_all(&self) {
         for entry in self.connections.iter() {
-            if let Some(ref conn) = entry.value().conn {
+            if let Some(conn) = entry.value().connection() {
                 conn.close(0u32.into(), b"shutdown");
             }
         }
@@ -584,17 +1292,28 @@ impl Clone for TpuConnectionManager {
     fn clone(&self) -> Self {
         Self {
             endpoints: self.endpoints.clone(),
+            client_config: self.client_config.clone(),
+            identity: self.identity.clone(),
             connections: self.connections.clone(),
-            leader_tracker: self.leader_tracker.clone(),
+            leader_source: self.leader_source.clone(),
             // Share round-robin counter across clones for true distribution.
             next_endpoint: self.next_endpoint.clone(),
+            metrics: self.metrics.clone(),
+            health: self.health.clone(),
+            max_connections: self.max_connections,
+            tuning: self.tuning,
+            events: self.events.clone(),
         }
     }
 }
 
 impl Drop for TpuConnectionManager {
     fn drop(&mut self) {
-        self.close_all();
+        // Clones share the connection cache; only the last one closes it,
+        // otherwise each finished send task would tear down live connections.
+        if Arc::strong_count(&self.connections) == 1 {
+            self.close_all();
+        }
     }
 }
 
@@ -609,6 +1328,412 @@ impl std::fmt::Debug for TpuConnectionManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::errors::close_codes;
+    use crate::test_support::{MockBehavior, MockTpuServer};
+    use crate::tracker::StaticLeaderSource;
+
+    fn test_manager() -> TpuConnectionManager {
+        let source = StaticLeaderSource::new(vec![], 0);
+        TpuConnectionManager::new(Arc::new(source), None).unwrap()
+    }
+
+    fn mock_leader(server: &MockTpuServer) -> LeaderInfo {
+        LeaderInfo {
+            identity: "mock-leader".to_string(),
+            tpu_socket: server.address(),
+            slot: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_delivers_to_mock_server() {
+        let server = MockTpuServer::start(MockBehavior::Accept).await;
+        let manager = test_manager();
+
+        let result = manager
+            .send_to_leaders(b"tx-1", &[mock_leader(&server)])
+            .await
+            .unwrap();
+
+        assert!(result.delivered);
+        assert_eq!(result.leader_count, 1);
+        assert_eq!(result.leaders[0].attempts, 1);
+        assert_eq!(
+            server
+                .wait_for_transactions(1, Duration::from_secs(2))
+                .await,
+            vec![b"tx-1".to_vec()]
+        );
+        assert_eq!(manager.connection_count(), 1);
+        assert_eq!(manager.metrics().leader_sends.get("mock-leader"), 1);
+        assert_eq!(manager.metrics().connect_latency.count(), 1);
+        assert_eq!(manager.metrics().stream_open_latency.count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_fanout_routes_through_leader_source() {
+        let server = MockTpuServer::start(MockBehavior::Accept).await;
+        let source = StaticLeaderSource::new(
+            vec![("pinned-leader".to_string(), server.address())],
+            100,
+        );
+        let manager = TpuConnectionManager::new(Arc::new(source), None).unwrap();
+
+        let result = manager
+            .send_transaction_with_fanout(b"tx-pinned", 4)
+            .await
+            .unwrap();
+
+        assert!(result.delivered);
+        assert_eq!(result.leader_count, 1);
+        assert_eq!(result.leaders[0].identity, "pinned-leader");
+        assert_eq!(
+            server
+                .wait_for_transactions(1, Duration::from_secs(2))
+                .await,
+            vec![b"tx-pinned".to_vec()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_probe_reports_handshakes() {
+        let server = MockTpuServer::start(MockBehavior::Accept).await;
+        let manager = test_manager();
+
+        let result = manager.probe(&server.address()).await.unwrap();
+
+        assert!(result.handshake > Duration::ZERO);
+        assert!(result.resumed_handshake > Duration::ZERO);
+        assert!(!result.zero_rtt_accepted || result.zero_rtt_attempted);
+        assert_eq!(manager.connection_count(), 0);
+
+        let refused = MockTpuServer::start(MockBehavior::Refuse).await;
+        let err = manager.probe(&refused.address()).await.unwrap_err();
+        assert_eq!(err.code(), TpuErrorCode::ConnectionFailed);
+    }
+
+    #[tokio::test]
+    async fn test_refused_connection_is_retried() {
+        let server = MockTpuServer::start(MockBehavior::Refuse).await;
+        let manager = test_manager();
+
+        let result = manager
+            .send_to_leader_with_retry(b"tx", &server.address(), "mock-leader")
+            .await;
+
+        assert!(!result.success);
+        assert_eq!(result.error_code, Some(TpuErrorCode::ConnectionFailed));
+        assert_eq!(result.attempts, RetryPolicy::default().max_attempts);
+    }
+
+    #[tokio::test]
+    async fn test_tuning_controls_retries_and_endpoints() {
+        let server = MockTpuServer::start(MockBehavior::Refuse).await;
+        let tuning = ConnectionTuning {
+            retry: RetryPolicy::fixed(5, Duration::from_millis(1)),
+            endpoint_count: 2,
+            ..ConnectionTuning::default()
+        };
+        let source = StaticLeaderSource::new(vec![], 0);
+        let manager = TpuConnectionManager::with_tuning(Arc::new(source), None, tuning).unwrap();
+
+        let result = manager
+            .send_to_leader_with_retry(b"tx", &server.address(), "mock-leader")
+            .await;
+
+        assert_eq!(result.attempts, 5);
+        assert_eq!(manager.endpoint_count(), 2);
+        assert_eq!(manager.tuning(), tuning);
+    }
+
+    #[test]
+    fn test_invalid_tuning_is_rejected() {
+        let invalid = [
+            ConnectionTuning {
+                retry: RetryPolicy::fixed(0, Duration::ZERO),
+                ..ConnectionTuning::default()
+            },
+            ConnectionTuning {
+                endpoint_count: 0,
+                ..ConnectionTuning::default()
+            },
+            ConnectionTuning {
+                collect_timeout: Duration::ZERO,
+                ..ConnectionTuning::default()
+            },
+            ConnectionTuning {
+                quic_keep_alive: Duration::from_secs(30),
+                quic_idle_timeout: Duration::from_secs(10),
+                ..ConnectionTuning::default()
+            },
+        ];
+
+        assert!(ConnectionTuning::default().validate().is_ok());
+        for tuning in invalid {
+            assert!(tuning.validate().is_err(), "{:?}", tuning);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failing_leader_is_not_prewarmed() {
+        let server = MockTpuServer::start(MockBehavior::Refuse).await;
+        let source = Arc::new(StaticLeaderSource::new(
+            vec![("failing-leader".to_string(), server.address())],
+            100,
+        ));
+        let manager = TpuConnectionManager::new(source, None).unwrap();
+
+        for _ in 0..4 {
+            manager.send_to_leaders(b"tx", &[mock_leader(&server)]).await.ok();
+        }
+
+        let health = manager.health();
+        assert!(!health.is_healthy("mock-leader"));
+        assert!(!health.should_prewarm(&server.address()));
+
+        // Prewarming skips the address, so no further connects are attempted
+        let failures = health.snapshot()[0].consecutive_connect_failures;
+        manager.prewarm_connections(4).await;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(health.snapshot()[0].consecutive_connect_failures, failures);
+    }
+
+    #[tokio::test]
+    async fn test_validator_close_code_is_classified() {
+        let server = MockTpuServer::start(MockBehavior::CloseWith(close_codes::TOO_MANY)).await;
+        let manager = test_manager();
+
+        let conn = manager
+            .get_or_create_connection(&server.address())
+            .await
+            .unwrap();
+        conn.closed().await;
+
+        let err = TpuError::from(conn.open_uni().await.unwrap_err());
+        assert_eq!(err.code(), TpuErrorCode::RateLimited);
+        assert!(err.is_retryable());
+        assert_eq!(manager.connection_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_validator_stream_stop_code_is_classified() {
+        let server = MockTpuServer::start(MockBehavior::StopStreamsWith(
+            close_codes::STREAM_STOP_CODE_THROTTLING,
+        ))
+        .await;
+        let manager = test_manager();
+
+        let conn = manager
+            .get_or_create_connection(&server.address())
+            .await
+            .unwrap();
+        let mut stream = conn.open_uni().await.unwrap();
+        stream.write_all(b"tx").await.unwrap();
+        assert_eq!(
+            stream.stopped().await.unwrap(),
+            Some(quinn::VarInt::from_u32(
+                close_codes::STREAM_STOP_CODE_THROTTLING
+            ))
+        );
+
+        let err = TpuError::from(stream.write_all(b"tx").await.unwrap_err());
+        assert_eq!(err.code(), TpuErrorCode::RateLimited);
+        assert!(server.received().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dropping_a_clone_keeps_pooled_connections_open() {
+        let server = MockTpuServer::start(MockBehavior::Accept).await;
+        let manager = test_manager();
+        let conn = manager
+            .get_or_create_connection(&server.address())
+            .await
+            .unwrap();
+
+        // Send tasks run on clones; finishing one must not close the pool
+        drop(manager.clone());
+        assert!(conn.close_reason().is_none());
+        assert_eq!(manager.connection_count(), 1);
+
+        drop(manager);
+        assert!(conn.close_reason().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_pool_evicts_lru_but_keeps_upcoming_leaders() {
+        let servers = [
+            MockTpuServer::start(MockBehavior::Accept).await,
+            MockTpuServer::start(MockBehavior::Accept).await,
+            MockTpuServer::start(MockBehavior::Accept).await,
+        ];
+        let source = Arc::new(StaticLeaderSource::new(
+            vec![("upcoming-leader".to_string(), servers[0].address())],
+            100,
+        ));
+        let manager = TpuConnectionManager::new(source, None)
+            .unwrap()
+            .with_max_connections(2);
+
+        let mut conns = Vec::new();
+        for server in &servers {
+            conns.push(
+                manager
+                    .get_or_create_connection(&server.address())
+                    .await
+                    .unwrap(),
+            );
+        }
+
+        // The oldest connection is an upcoming leader, so the next one goes
+        let pooled: Vec<String> = manager
+            .pooled_connections()
+            .into_iter()
+            .map(|conn| conn.address)
+            .collect();
+        assert_eq!(pooled.len(), 2);
+        assert!(pooled.contains(&servers[0].address()));
+        assert!(pooled.contains(&servers[2].address()));
+        assert_eq!(manager.metrics().connection_evictions.get(), 1);
+
+        // The evicted connection stays usable for sends still holding it
+        assert!(conns[1].close_reason().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_reap_removes_closed_and_idle_entries() {
+        let closing = MockTpuServer::start(MockBehavior::CloseWith(close_codes::DISALLOWED)).await;
+        let idle = MockTpuServer::start(MockBehavior::Accept).await;
+        let manager = test_manager();
+
+        let conn = manager
+            .get_or_create_connection(&closing.address())
+            .await
+            .unwrap();
+        conn.closed().await;
+        let idle_conn = manager
+            .get_or_create_connection(&idle.address())
+            .await
+            .unwrap();
+
+        assert_eq!(manager.reap(Duration::from_secs(60)).await, 1);
+        let pooled = manager.pooled_connections();
+        assert_eq!(pooled.len(), 1);
+        assert!(pooled[0].open);
+        assert!(pooled[0].idle < Duration::from_secs(60));
+
+        assert_eq!(manager.reap(Duration::ZERO).await, 1);
+        assert!(manager.pooled_connections().is_empty());
+        assert_eq!(manager.metrics().connections_reaped.get(), 2);
+        assert!(idle_conn.close_reason().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_callers_share_one_handshake() {
+        let server = MockTpuServer::start(MockBehavior::Accept).await;
+        let manager = test_manager();
+        let address = server.address();
+
+        let connects = (0..8).map(|_| manager.get_or_create_connection(&address));
+        let results = futures_util::future::join_all(connects).await;
+
+        assert!(results.iter().all(|r| r.is_ok()));
+        assert_eq!(manager.connection_count(), 1);
+
+        // Sending settles the server side and reuses the shared connection
+        manager
+            .send_to_leaders(b"tx", &[mock_leader(&server)])
+            .await
+            .unwrap();
+        server
+            .wait_for_transactions(1, Duration::from_secs(2))
+            .await;
+        assert_eq!(server.connection_count(), 1);
+        assert_eq!(manager.metrics().connect_latency.count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_stalled_handshake_times_out() {
+        let server = MockTpuServer::start(MockBehavior::Stall).await;
+        let manager = test_manager();
+
+        let result = manager
+            .send_to_leader_with_retry(b"tx", &server.address(), "mock-leader")
+            .await;
+
+        assert!(!result.success);
+        assert_eq!(result.error_code, Some(TpuErrorCode::Timeout));
+        assert_eq!(result.latency_ms, LEADER_SEND_TIMEOUT.as_millis() as u64);
+        assert_eq!(manager.metrics().send_failures.get("TIMEOUT"), 1);
+    }
+
+    #[tokio::test]
+    async fn test_dropped_send_aborts_leader_tasks() {
+        let server = MockTpuServer::start(MockBehavior::Stall).await;
+        let manager = test_manager();
+
+        let leaders = [mock_leader(&server)];
+        let send = manager.send_to_leaders(b"tx", &leaders);
+        assert!(tokio::time::timeout(Duration::from_millis(100), send)
+            .await
+            .is_err());
+
+        // A task still running would record its timeout once it elapses
+        tokio::time::sleep(LEADER_SEND_TIMEOUT + Duration::from_millis(200)).await;
+        assert_eq!(manager.metrics().leader_sends.get("mock-leader"), 0);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_address_is_not_retried() {
+        let manager = test_manager();
+
+        let result = manager
+            .send_to_leader_with_retry(b"tx", "not-an-address", "mock-leader")
+            .await;
+
+        assert!(!result.success);
+        assert_eq!(result.error_code, Some(TpuErrorCode::InvalidAddress));
+        assert_eq!(result.attempts, 1);
+        assert_eq!(manager.connection_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_discarded_connection_stays_open_for_in_flight_streams() {
+        let server = MockTpuServer::start(MockBehavior::Accept).await;
+        let manager = test_manager();
+        let address = server.address();
+
+        let conn = manager.get_or_create_connection(&address).await.unwrap();
+        manager.discard_connection(&address, &conn);
+
+        assert_eq!(manager.connection_count(), 0);
+        assert!(conn.close_reason().is_none());
+
+        // The next send reconnects
+        let fresh = manager.get_or_create_connection(&address).await.unwrap();
+        assert_ne!(fresh.stable_id(), conn.stable_id());
+    }
+
+    #[tokio::test]
+    async fn test_batch_respects_throttled_stream_limit() {
+        let server = MockTpuServer::start(MockBehavior::Throttle(2)).await;
+        let manager = test_manager();
+        let transactions: Vec<Vec<u8>> = (0..8u8).map(|i| vec![i; 16]).collect();
+
+        let results = manager
+            .send_batch_to_leaders(&transactions, &[mock_leader(&server)])
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), transactions.len());
+        assert!(results.iter().all(|r| r.delivered));
+
+        let mut received = server
+            .wait_for_transactions(transactions.len(), Duration::from_secs(2))
+            .await;
+        received.sort();
+        assert_eq!(received, transactions);
+        assert_eq!(server.connection_count(), 1);
+    }
 
     #[test]
     fn test_socket_addr_to_quic_server_name_ipv4() {
@@ -1,15 +1,40 @@
 //! TPU error types and classification.
 //!
 //! Provides error codes that are exposed to TypeScript for
-//! programmatic error handling and retry logic.
+//! programmatic error handling and retry logic, and a structured
+//! error type that maps QUIC failures directly to those codes.
 
+use quinn::{ClosedStream, ConnectError, ConnectionError, VarInt, WriteError};
 use std::fmt;
+use std::net::AddrParseError;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Application close and stream stop codes used by Solana validators on
+/// TPU QUIC connections (see agave's streamer `nonblocking::quic`).
+pub mod close_codes {
+    /// Connection was evicted from the validator's connection table.
+    pub const DROPPED_ENTRY: u32 = 1;
+    /// Connection is not allowed (e.g. unstaked connections disabled).
+    pub const DISALLOWED: u32 = 2;
+    /// Peer opened more concurrent streams than allowed.
+    pub const EXCEED_MAX_STREAM_COUNT: u32 = 3;
+    /// Too many connections from this peer.
+    pub const TOO_MANY: u32 = 4;
+    /// Peer sent an invalid stream.
+    pub const INVALID_STREAM: u32 = 5;
+    /// Stream stopped because the peer exceeded its stream throttle.
+    pub const STREAM_STOP_CODE_THROTTLING: u32 = 15;
+}
+
+/// First and last QUIC transport error codes reserved for TLS alerts.
+const CRYPTO_ERROR_RANGE: std::ops::RangeInclusive<u64> = 0x100..=0x1ff;
 
 /// TPU error codes exposed to TypeScript.
 ///
 /// These codes allow frontend applications to handle errors
 /// programmatically and implement smart retry logic.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TpuErrorCode {
     /// Failed to establish QUIC connection to validator.
     ConnectionFailed,
@@ -25,6 +50,24 @@ pub enum TpuErrorCode {
     ValidatorUnreachable,
     /// 0-RTT connection was rejected by validator.
     ZeroRttRejected,
+    /// Transaction's recent blockhash expired before it was confirmed.
+    BlockhashExpired,
+    /// Durable nonce was advanced by another transaction before this one confirmed.
+    NonceAdvanced,
+    /// Send was cancelled by the caller before the transaction confirmed.
+    Cancelled,
+    /// Transaction bytes are malformed or carry invalid signatures.
+    InvalidTransaction,
+    /// Transaction exceeds the 1232-byte packet limit.
+    Oversized,
+    /// Same transaction appears more than once in a batch.
+    DuplicateTransaction,
+    /// Validator TPU address could not be parsed or used.
+    InvalidAddress,
+    /// TLS handshake or certificate configuration failed.
+    TlsFailure,
+    /// Local QUIC endpoint is stopping or out of connection IDs.
+    EndpointExhausted,
 }
 
 impl TpuErrorCode {
@@ -38,6 +81,15 @@ impl TpuErrorCode {
             TpuErrorCode::Timeout => "TIMEOUT",
             TpuErrorCode::ValidatorUnreachable => "VALIDATOR_UNREACHABLE",
             TpuErrorCode::ZeroRttRejected => "ZERO_RTT_REJECTED",
+            TpuErrorCode::BlockhashExpired => "BLOCKHASH_EXPIRED",
+            TpuErrorCode::NonceAdvanced => "NONCE_ADVANCED",
+            TpuErrorCode::Cancelled => "CANCELLED",
+            TpuErrorCode::InvalidTransaction => "INVALID_TRANSACTION",
+            TpuErrorCode::Oversized => "OVERSIZED",
+            TpuErrorCode::DuplicateTransaction => "DUPLICATE_TRANSACTION",
+            TpuErrorCode::InvalidAddress => "INVALID_ADDRESS",
+            TpuErrorCode::TlsFailure => "TLS_FAILURE",
+            TpuErrorCode::EndpointExhausted => "ENDPOINT_EXHAUSTED",
         }
     }
 
@@ -49,6 +101,16 @@ impl TpuErrorCode {
                 | TpuErrorCode::StreamClosed
                 | TpuErrorCode::RateLimited
                 | TpuErrorCode::Timeout
+                | TpuErrorCode::EndpointExhausted
+        )
+    }
+
+    /// Returns whether this error leaves the connection unusable, so the
+    /// next attempt must use a fresh connection.
+    pub fn requires_reconnect(&self) -> bool {
+        matches!(
+            self,
+            TpuErrorCode::StreamClosed | TpuErrorCode::ConnectionFailed
         )
     }
 }
@@ -59,30 +121,196 @@ impl fmt::Display for TpuErrorCode {
     }
 }
 
-/// Classify an error into a TpuErrorCode based on the error message.
-pub fn classify_error(error: &anyhow::Error) -> TpuErrorCode {
-    let msg = error.to_string().to_lowercase();
-
-    if msg.contains("connection refused") || msg.contains("connection reset") {
-        TpuErrorCode::ConnectionFailed
-    } else if msg.contains("stream") && (msg.contains("closed") || msg.contains("reset")) {
-        TpuErrorCode::StreamClosed
-    } else if msg.contains("rate") || msg.contains("limit") || msg.contains("too many") {
-        TpuErrorCode::RateLimited
-    } else if msg.contains("timeout") || msg.contains("timed out") {
-        TpuErrorCode::Timeout
-    } else if msg.contains("0-rtt") || msg.contains("early data") {
-        TpuErrorCode::ZeroRttRejected
-    } else if msg.contains("no leader") || msg.contains("no schedule") {
-        TpuErrorCode::NoLeaders
-    } else {
-        TpuErrorCode::ValidatorUnreachable
+/// Errors from sending to a validator TPU endpoint.
+///
+/// Each variant wraps the underlying QUIC error so it can be mapped
+/// directly to a [`TpuErrorCode`] without inspecting messages.
+#[derive(Debug, Clone, Error)]
+pub enum TpuError {
+    /// Validator address could not be parsed.
+    #[error("Invalid validator address {address}: {source}")]
+    InvalidAddress {
+        address: String,
+        #[source]
+        source: AddrParseError,
+    },
+    /// Connection could not be initiated by the local endpoint.
+    #[error("Failed to initiate connection: {0}")]
+    Connect(#[from] ConnectError),
+    /// Connection failed or was closed.
+    #[error("Connection failed: {0}")]
+    Connection(#[from] ConnectionError),
+    /// Writing transaction data to the stream failed.
+    #[error("Failed to write transaction data: {0}")]
+    Write(#[from] WriteError),
+    /// Stream was already closed when finishing it.
+    #[error("Failed to finish stream: {0}")]
+    ClosedStream(#[from] ClosedStream),
+    /// Operation did not complete in time.
+    #[error("Timeout after {0:?}")]
+    Timeout(Duration),
+}
+
+impl TpuError {
+    /// Maps this error to a TypeScript-facing error code.
+    pub fn code(&self) -> TpuErrorCode {
+        match self {
+            TpuError::InvalidAddress { .. } => TpuErrorCode::InvalidAddress,
+            TpuError::Connect(e) => connect_error_code(e),
+            TpuError::Connection(e) => connection_error_code(e),
+            TpuError::Write(e) => write_error_code(e),
+            TpuError::ClosedStream(_) => TpuErrorCode::StreamClosed,
+            TpuError::Timeout(_) => TpuErrorCode::Timeout,
+        }
+    }
+
+    /// Returns whether this error is retryable.
+    pub fn is_retryable(&self) -> bool {
+        self.code().is_retryable()
+    }
+}
+
+/// Pre-flight check failures, caught before a transaction reaches the network.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum PreflightError {
+    /// Serialized transaction exceeds the packet limit.
+    #[error("Transaction is {size} bytes, over the {limit}-byte packet limit")]
+    Oversized { size: usize, limit: usize },
+    /// Bytes don't parse as a legacy or v0 transaction, or fail sanitization.
+    #[error("Malformed transaction: {0}")]
+    Malformed(String),
+    /// Number of signatures doesn't match the message header.
+    #[error("Transaction has {signatures} signatures but its header requires {required}")]
+    SignatureCount { signatures: usize, required: usize },
+    /// A signature doesn't verify against its signer and the message.
+    #[error("Signature {index} does not verify")]
+    InvalidSignature { index: usize },
+    /// Transaction appears earlier in the same batch.
+    #[error("Transaction {signature} duplicates batch entry {first}")]
+    Duplicate { signature: String, first: usize },
+    /// A batch entry failed a check.
+    #[error("Batch transaction {index}: {source}")]
+    InBatch {
+        index: usize,
+        #[source]
+        source: Box<PreflightError>,
+    },
+}
+
+impl PreflightError {
+    /// Maps this error to a TypeScript-facing error code.
+    pub fn code(&self) -> TpuErrorCode {
+        match self {
+            PreflightError::Oversized { .. } => TpuErrorCode::Oversized,
+            PreflightError::Duplicate { .. } => TpuErrorCode::DuplicateTransaction,
+            PreflightError::InBatch { source, .. } => source.code(),
+            PreflightError::Malformed(_)
+            | PreflightError::SignatureCount { .. }
+            | PreflightError::InvalidSignature { .. } => TpuErrorCode::InvalidTransaction,
+        }
+    }
+}
+
+/// Errors returned by the native `FastlaneClient` API.
+#[derive(Debug, Error)]
+pub enum FastlaneError {
+    /// Client could not be initialized (runtime, RPC, endpoints).
+    #[error("Failed to initialize client: {0:#}")]
+    Init(anyhow::Error),
+    /// Configuration has an invalid value.
+    #[error("Invalid configuration: {0:#}")]
+    Config(anyhow::Error),
+    /// Identity keypair could not be loaded or applied.
+    #[error("Failed to apply identity: {0:#}")]
+    Identity(anyhow::Error),
+    /// Transaction bytes could not be parsed.
+    #[error("Invalid transaction: {0}")]
+    InvalidTransaction(String),
+    /// Transaction failed a pre-flight check.
+    #[error("Pre-flight check failed: {0}")]
+    Preflight(#[from] PreflightError),
+    /// No leaders are known for the current slot.
+    #[error("No leaders available")]
+    NoLeaders,
+    /// Leader tracking did not become ready in time.
+    #[error("Timeout waiting for client to be ready")]
+    NotReady,
+    /// Sending to leaders failed.
+    #[error("Failed to send transaction: {0:#}")]
+    Send(anyhow::Error),
+}
+
+impl FastlaneError {
+    /// Maps this error to a TPU error code, if one applies.
+    pub fn code(&self) -> Option<TpuErrorCode> {
+        match self {
+            FastlaneError::NoLeaders => Some(TpuErrorCode::NoLeaders),
+            FastlaneError::InvalidTransaction(_) => Some(TpuErrorCode::InvalidTransaction),
+            FastlaneError::Preflight(e) => Some(e.code()),
+            FastlaneError::NotReady => Some(TpuErrorCode::Timeout),
+            _ => None,
+        }
+    }
+}
+
+/// Maps a local connect error to an error code.
+fn connect_error_code(error: &ConnectError) -> TpuErrorCode {
+    match error {
+        ConnectError::EndpointStopping | ConnectError::CidsExhausted => {
+            TpuErrorCode::EndpointExhausted
+        }
+        ConnectError::InvalidServerName(_) | ConnectError::InvalidRemoteAddress(_) => {
+            TpuErrorCode::InvalidAddress
+        }
+        ConnectError::NoDefaultClientConfig | ConnectError::UnsupportedVersion => {
+            TpuErrorCode::TlsFailure
+        }
+    }
+}
+
+/// Maps a connection error (including validator close codes) to an error code.
+fn connection_error_code(error: &ConnectionError) -> TpuErrorCode {
+    match error {
+        ConnectionError::ApplicationClosed(close) => application_close_code(close.error_code),
+        ConnectionError::TransportError(e) if CRYPTO_ERROR_RANGE.contains(&u64::from(e.code)) => {
+            TpuErrorCode::TlsFailure
+        }
+        ConnectionError::ConnectionClosed(close)
+            if CRYPTO_ERROR_RANGE.contains(&u64::from(close.error_code)) =>
+        {
+            TpuErrorCode::TlsFailure
+        }
+        ConnectionError::TimedOut => TpuErrorCode::Timeout,
+        ConnectionError::CidsExhausted => TpuErrorCode::EndpointExhausted,
+        ConnectionError::VersionMismatch
+        | ConnectionError::TransportError(_)
+        | ConnectionError::ConnectionClosed(_)
+        | ConnectionError::Reset
+        | ConnectionError::LocallyClosed => TpuErrorCode::ConnectionFailed,
+    }
+}
+
+/// Maps a stream write error to an error code.
+fn write_error_code(error: &WriteError) -> TpuErrorCode {
+    match error {
+        WriteError::Stopped(code) => application_close_code(*code),
+        WriteError::ConnectionLost(e) => connection_error_code(e),
+        WriteError::ClosedStream => TpuErrorCode::StreamClosed,
+        WriteError::ZeroRttRejected => TpuErrorCode::ZeroRttRejected,
     }
 }
 
-/// Check if an error is retryable based on its message.
-pub fn is_retryable_error(error: &anyhow::Error) -> bool {
-    classify_error(error).is_retryable()
+/// Maps a validator application close (or stream stop) code to an error code.
+fn application_close_code(code: VarInt) -> TpuErrorCode {
+    match u32::try_from(code.into_inner()) {
+        Ok(
+            close_codes::EXCEED_MAX_STREAM_COUNT
+            | close_codes::TOO_MANY
+            | close_codes::STREAM_STOP_CODE_THROTTLING,
+        ) => TpuErrorCode::RateLimited,
+        // Evicted, disallowed, invalid stream or unknown code
+        _ => TpuErrorCode::StreamClosed,
+    }
 }
 
 #[cfg(test)]
@@ -94,6 +322,10 @@ mod tests {
         assert_eq!(TpuErrorCode::ConnectionFailed.as_str(), "CONNECTION_FAILED");
         assert_eq!(TpuErrorCode::StreamClosed.as_str(), "STREAM_CLOSED");
         assert_eq!(TpuErrorCode::Timeout.as_str(), "TIMEOUT");
+        assert_eq!(TpuErrorCode::BlockhashExpired.as_str(), "BLOCKHASH_EXPIRED");
+        assert_eq!(TpuErrorCode::NonceAdvanced.as_str(), "NONCE_ADVANCED");
+        assert_eq!(TpuErrorCode::Cancelled.as_str(), "CANCELLED");
+        assert_eq!(TpuErrorCode::Oversized.as_str(), "OVERSIZED");
     }
 
     #[test]
@@ -105,5 +337,98 @@ mod tests {
         assert!(!TpuErrorCode::NoLeaders.is_retryable());
         assert!(!TpuErrorCode::ValidatorUnreachable.is_retryable());
         assert!(!TpuErrorCode::ZeroRttRejected.is_retryable());
+        assert!(!TpuErrorCode::BlockhashExpired.is_retryable());
+        assert!(!TpuErrorCode::NonceAdvanced.is_retryable());
+        assert!(!TpuErrorCode::Cancelled.is_retryable());
+        assert!(!TpuErrorCode::InvalidTransaction.is_retryable());
+        assert!(!TpuErrorCode::Oversized.is_retryable());
+        assert!(!TpuErrorCode::InvalidAddress.is_retryable());
+        assert!(!TpuErrorCode::TlsFailure.is_retryable());
+        assert!(TpuErrorCode::EndpointExhausted.is_retryable());
+    }
+
+    fn application_closed(code: u32) -> ConnectionError {
+        ConnectionError::ApplicationClosed(quinn::ApplicationClose {
+            error_code: VarInt::from_u32(code),
+            reason: "closed".into(),
+        })
+    }
+
+    #[test]
+    fn test_validator_close_codes() {
+        assert_eq!(
+            TpuError::from(application_closed(close_codes::TOO_MANY)).code(),
+            TpuErrorCode::RateLimited
+        );
+        assert_eq!(
+            TpuError::from(application_closed(close_codes::EXCEED_MAX_STREAM_COUNT)).code(),
+            TpuErrorCode::RateLimited
+        );
+        assert_eq!(
+            TpuError::from(application_closed(close_codes::DROPPED_ENTRY)).code(),
+            TpuErrorCode::StreamClosed
+        );
+        assert_eq!(
+            TpuError::from(application_closed(close_codes::DISALLOWED)).code(),
+            TpuErrorCode::StreamClosed
+        );
+    }
+
+    #[test]
+    fn test_write_error_codes() {
+        assert_eq!(
+            TpuError::from(WriteError::Stopped(VarInt::from_u32(close_codes::TOO_MANY))).code(),
+            TpuErrorCode::RateLimited
+        );
+        assert_eq!(
+            TpuError::from(WriteError::Stopped(VarInt::from_u32(
+                close_codes::STREAM_STOP_CODE_THROTTLING
+            )))
+            .code(),
+            TpuErrorCode::RateLimited
+        );
+        assert_eq!(
+            TpuError::from(WriteError::ZeroRttRejected).code(),
+            TpuErrorCode::ZeroRttRejected
+        );
+        assert_eq!(
+            TpuError::from(WriteError::ConnectionLost(ConnectionError::TimedOut)).code(),
+            TpuErrorCode::Timeout
+        );
+        assert_eq!(
+            TpuError::from(ClosedStream::default()).code(),
+            TpuErrorCode::StreamClosed
+        );
+    }
+
+    #[test]
+    fn test_connect_and_address_error_codes() {
+        assert_eq!(
+            TpuError::from(ConnectError::EndpointStopping).code(),
+            TpuErrorCode::EndpointExhausted
+        );
+        assert_eq!(
+            TpuError::from(ConnectError::InvalidServerName("bad".to_string())).code(),
+            TpuErrorCode::InvalidAddress
+        );
+
+        let source = "not-an-address".parse::<std::net::SocketAddr>().unwrap_err();
+        let error = TpuError::InvalidAddress {
+            address: "not-an-address".to_string(),
+            source,
+        };
+        assert_eq!(error.code(), TpuErrorCode::InvalidAddress);
+        assert!(!error.is_retryable());
+    }
+
+    #[test]
+    fn test_messages_do_not_affect_classification() {
+        // A reason mentioning "limit" must not be classified as rate limiting
+        let error = TpuError::from(ConnectionError::ApplicationClosed(quinn::ApplicationClose {
+            error_code: VarInt::from_u32(close_codes::DROPPED_ENTRY),
+            reason: "stream limit unlimited".into(),
+        }));
+        assert_eq!(error.code(), TpuErrorCode::StreamClosed);
+        assert_eq!(TpuError::Timeout(Duration::from_secs(1)).code(), TpuErrorCode::Timeout);
     }
 }
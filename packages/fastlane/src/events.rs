@@ -0,0 +1,125 @@
+//! Client event stream.
+//!
+//! The leader tracker and connection manager publish what they observe
+//! (slot advances, leader window transitions, epoch rotations, validator
+//! socket changes and per-leader send outcomes) on a broadcast channel, so
+//! consumers can react live instead of polling.
+
+use tokio::sync::broadcast;
+
+use crate::connection_manager::LeaderDeliveryResult;
+use crate::tracker::{EpochBounds, Slot};
+
+/// Events buffered per subscriber before the oldest are dropped.
+pub const EVENT_CHANNEL_CAPACITY: usize = 1024;
+
+/// An event observed by the client.
+#[derive(Debug, Clone)]
+pub enum ClientEvent {
+    /// The tracked slot advanced.
+    Slot {
+        /// New current slot.
+        slot: Slot,
+    },
+    /// A different leader's window started.
+    LeaderChange {
+        /// Slot at which the change was observed.
+        slot: Slot,
+        /// Identity of the new leader.
+        leader: String,
+        /// Identity of the previous leader, if known.
+        previous: Option<String>,
+    },
+    /// The leader schedule rotated into a new epoch.
+    EpochRotated {
+        /// Boundaries of the new epoch.
+        bounds: EpochBounds,
+        /// Slot that triggered the rotation.
+        slot: Slot,
+    },
+    /// Validator TPU sockets changed after a cluster nodes refresh.
+    SocketsUpdated {
+        /// Validators whose sockets became known.
+        added: usize,
+        /// Validators whose sockets changed.
+        changed: usize,
+        /// Validators no longer in the cluster.
+        removed: usize,
+        /// Validators with known sockets after the refresh.
+        known: usize,
+    },
+    /// A send to a single leader finished, retries included.
+    LeaderSend(LeaderDeliveryResult),
+}
+
+impl ClientEvent {
+    /// Every event kind, as returned by `kind`.
+    pub const KINDS: [&'static str; 5] = ["slot", "leaderChange", "epoch", "sockets", "send"];
+
+    /// Returns the event kind as exposed to JavaScript.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ClientEvent::Slot { .. } => "slot",
+            ClientEvent::LeaderChange { .. } => "leaderChange",
+            ClientEvent::EpochRotated { .. } => "epoch",
+            ClientEvent::SocketsUpdated { .. } => "sockets",
+            ClientEvent::LeaderSend(_) => "send",
+        }
+    }
+}
+
+/// Broadcast channel for client events.
+///
+/// Clones share the channel. Publishing without subscribers is a no-op,
+/// and slow subscribers miss the oldest events rather than blocking.
+#[derive(Debug, Clone)]
+pub struct EventBus {
+    /// Sending half; receivers are created on subscribe.
+    sender: broadcast::Sender<ClientEvent>,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        let (sender, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        Self { sender }
+    }
+}
+
+impl EventBus {
+    /// Publishes an event to every current subscriber.
+    pub fn emit(&self, event: ClientEvent) {
+        let _ = self.sender.send(event);
+    }
+
+    /// Returns whether anyone is subscribed, so callers can skip building
+    /// events nobody will receive.
+    pub fn has_subscribers(&self) -> bool {
+        self.sender.receiver_count() > 0
+    }
+
+    /// Subscribes to events published from now on.
+    pub fn subscribe(&self) -> broadcast::Receiver<ClientEvent> {
+        self.sender.subscribe()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subscribers_receive_events_published_after_subscribing() {
+        let bus = EventBus::default();
+        assert!(!bus.has_subscribers());
+        bus.emit(ClientEvent::Slot { slot: 1 });
+
+        let mut rx = bus.clone().subscribe();
+        assert!(bus.has_subscribers());
+        bus.emit(ClientEvent::Slot { slot: 2 });
+
+        let event = rx.try_recv().unwrap();
+        assert_eq!(event.kind(), "slot");
+        assert!(matches!(event, ClientEvent::Slot { slot: 2 }));
+        assert!(rx.try_recv().is_err());
+    }
+}
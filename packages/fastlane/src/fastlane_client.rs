@@ -0,0 +1,1473 @@
+//! FastlaneClient - native async Rust API.
+//!
+//! Owns leader routing, QUIC connections and confirmation tracking, and
+//! runs its background tasks on the caller's Tokio runtime. The NAPI
+//! `TpuClient` is a thin wrapper over this client.
+
+use anyhow::Context;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_response::TransactionConfirmationStatus;
+use solana_commitment_config::CommitmentConfig;
+use solana_sdk::hash::Hash;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signature};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, oneshot};
+use tokio_util::sync::CancellationToken;
+
+use crate::confirmation::{ConfirmationStatus, ConfirmationTracker};
+use crate::connection_manager::{
+    ConnectionTuning, DeliveryResult, PooledConnection, TpuConnectionManager,
+    DEFAULT_MAX_CONNECTIONS,
+};
+use crate::errors::{FastlaneError, TpuErrorCode};
+use crate::events::{ClientEvent, EventBus};
+use crate::leader_health::LeaderHealthSnapshot;
+use crate::metrics::write_gauge;
+use crate::tracker::{
+    EpochBounds, LeaderInfo, LeaderSource, LeaderTracker, LeaderWindow, Slot, SlotSource,
+    SlotSourceStats, DEFAULT_SNAPSHOT_INTERVAL,
+};
+use crate::transaction::{
+    extract_nonce_account, extract_recent_blockhash, parse_durable_nonce, preflight_check,
+    preflight_check_batch,
+};
+
+/// How often (in send rounds) to check whether the blockhash has expired
+/// (or, for durable-nonce transactions, whether the nonce was advanced).
+/// At ~400ms per round this checks roughly every 2 seconds.
+const BLOCKHASH_CHECK_INTERVAL_ROUNDS: u32 = 5;
+
+/// How many expiry checks may report a never-seen-valid blockhash as
+/// invalid before it is treated as expired. The RPC node can lag behind
+/// the one the blockhash came from, but a blockhash that stays invalid
+/// was already stale when submitted.
+const BLOCKHASH_UNSEEN_GRACE_CHECKS: u32 = 3;
+
+/// Approximate slot duration, used as the resend interval.
+const SLOT_DURATION: Duration = Duration::from_millis(400);
+
+/// Longest the first round waits on the signature subscription before
+/// falling back to polling.
+const SUBSCRIBE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// How often the connection pool is reaped.
+const REAP_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Pooled connections unused for this long are closed (upcoming leaders excepted).
+const CONNECTION_IDLE_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Default interval between connection pre-warming rounds (one slot).
+const PREWARM_INTERVAL: Duration = SLOT_DURATION;
+
+/// Default interval between validator TPU socket refreshes.
+const SOCKET_REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Configuration for the native client.
+#[derive(Debug)]
+pub struct FastlaneConfig {
+    /// RPC URL for fetching leader schedule, cluster info and confirmations.
+    pub rpc_url: String,
+    /// WebSocket URL for slot updates and signature subscriptions.
+    pub ws_url: String,
+    /// Optional Yellowstone gRPC URL for slot subscriptions.
+    pub grpc_url: Option<String>,
+    /// Optional gRPC x-token for authenticated Yellowstone endpoints.
+    pub grpc_x_token: Option<String>,
+    /// Slot sources to follow concurrently, agreeing on the slot across them.
+    /// When empty, `grpc_url` is used if set, otherwise `ws_url`.
+    pub slot_sources: Vec<SlotSource>,
+    /// Number of upcoming leaders to send to when slot-aware routing is unavailable.
+    pub fanout: u32,
+    /// Whether to pre-warm connections to upcoming leaders.
+    pub prewarm_connections: bool,
+    /// Maximum number of pooled QUIC connections.
+    pub max_connections: usize,
+    /// Interval between connection pre-warming rounds.
+    pub prewarm_interval: Duration,
+    /// Interval between validator TPU socket refreshes.
+    pub socket_refresh_interval: Duration,
+    /// Transport and retry tuning for the connection manager.
+    pub tuning: ConnectionTuning,
+    /// Whether to validate transactions locally before sending.
+    pub preflight_checks: bool,
+    /// Optional file to persist leader schedules and validator sockets to,
+    /// restored on the next start while still current.
+    pub snapshot_path: Option<PathBuf>,
+    /// Interval between checks for schedule or socket changes to persist.
+    pub snapshot_interval: Duration,
+    /// Optional staked identity for QUIC client certificates.
+    pub identity: Option<Keypair>,
+}
+
+impl FastlaneConfig {
+    /// Creates a config with default fanout (4), pre-warming on, a pool of
+    /// 128 connections, default tuning, no pre-flight checks, no snapshot
+    /// and no identity.
+    pub fn new(rpc_url: impl Into<String>, ws_url: impl Into<String>) -> Self {
+        Self {
+            rpc_url: rpc_url.into(),
+            ws_url: ws_url.into(),
+            grpc_url: None,
+            grpc_x_token: None,
+            slot_sources: Vec::new(),
+            fanout: 4,
+            prewarm_connections: true,
+            max_connections: DEFAULT_MAX_CONNECTIONS,
+            prewarm_interval: PREWARM_INTERVAL,
+            socket_refresh_interval: SOCKET_REFRESH_INTERVAL,
+            tuning: ConnectionTuning::default(),
+            preflight_checks: false,
+            snapshot_path: None,
+            snapshot_interval: DEFAULT_SNAPSHOT_INTERVAL,
+            identity: None,
+        }
+    }
+
+    /// Checks that every setting is usable.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FastlaneError::Config` naming the first invalid setting.
+    pub fn validate(&self) -> Result<(), FastlaneError> {
+        if self.prewarm_interval.is_zero() {
+            return Err(FastlaneError::Config(anyhow::anyhow!(
+                "prewarm_interval must be positive"
+            )));
+        }
+        if self.socket_refresh_interval.is_zero() {
+            return Err(FastlaneError::Config(anyhow::anyhow!(
+                "socket_refresh_interval must be positive"
+            )));
+        }
+        if self.snapshot_interval.is_zero() {
+            return Err(FastlaneError::Config(anyhow::anyhow!(
+                "snapshot_interval must be positive"
+            )));
+        }
+        for source in &self.slot_sources {
+            if let SlotSource::RpcPoll { interval, .. } = source {
+                if interval.is_zero() {
+                    return Err(FastlaneError::Config(anyhow::anyhow!(
+                        "RPC slot source poll interval must be positive"
+                    )));
+                }
+            }
+        }
+        self.tuning.validate().map_err(FastlaneError::Config)
+    }
+}
+
+/// How a `send_until_confirmed` call ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfirmStatus {
+    /// Transaction reached confirmed commitment.
+    Confirmed,
+    /// Recent blockhash expired before confirmation.
+    BlockhashExpired,
+    /// Durable nonce was advanced without this transaction confirming.
+    NonceAdvanced,
+    /// Caller cancelled the send before confirmation.
+    Cancelled,
+    /// Timeout elapsed before confirmation.
+    TimedOut,
+}
+
+/// Result from continuous send until confirmed.
+#[derive(Debug, Clone)]
+pub struct ConfirmOutcome {
+    /// How the send ended.
+    pub status: ConfirmStatus,
+    /// Transaction signature.
+    pub signature: Signature,
+    /// Number of send rounds attempted.
+    pub rounds: u32,
+    /// Total number of leader sends across all rounds.
+    pub total_leaders_sent: u32,
+    /// Total elapsed time.
+    pub latency: Duration,
+}
+
+impl ConfirmOutcome {
+    /// Returns whether the transaction was confirmed.
+    pub fn confirmed(&self) -> bool {
+        self.status == ConfirmStatus::Confirmed
+    }
+
+    /// Returns the error code for unconfirmed outcomes.
+    pub fn error_code(&self) -> Option<TpuErrorCode> {
+        match self.status {
+            ConfirmStatus::Confirmed => None,
+            ConfirmStatus::BlockhashExpired => Some(TpuErrorCode::BlockhashExpired),
+            ConfirmStatus::NonceAdvanced => Some(TpuErrorCode::NonceAdvanced),
+            ConfirmStatus::Cancelled => Some(TpuErrorCode::Cancelled),
+            ConfirmStatus::TimedOut => Some(TpuErrorCode::Timeout),
+        }
+    }
+
+    /// Returns a human-readable error for unconfirmed outcomes.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - The timeout the send was given, quoted in timeout errors
+    pub fn error_message(&self, timeout: Duration) -> Option<String> {
+        match self.status {
+            ConfirmStatus::Confirmed => None,
+            ConfirmStatus::BlockhashExpired => Some(format!(
+                "Blockhash expired before confirmation ({} rounds, {} leaders sent)",
+                self.rounds, self.total_leaders_sent
+            )),
+            ConfirmStatus::NonceAdvanced => Some(format!(
+                "Durable nonce advanced before confirmation ({} rounds, {} leaders sent)",
+                self.rounds, self.total_leaders_sent
+            )),
+            ConfirmStatus::Cancelled => Some(format!(
+                "Send cancelled before confirmation ({} rounds, {} leaders sent)",
+                self.rounds, self.total_leaders_sent
+            )),
+            ConfirmStatus::TimedOut => Some(format!(
+                "Transaction not confirmed within {}ms ({} rounds, {} leaders sent)",
+                timeout.as_millis(),
+                self.rounds,
+                self.total_leaders_sent
+            )),
+        }
+    }
+}
+
+/// Leaders selected by the slot-aware routing strategy.
+#[derive(Debug, Clone)]
+pub struct SlotAwareRoute {
+    /// Current estimated slot.
+    pub current_slot: Slot,
+    /// Position within the current leader's 4-slot window (0-3).
+    pub slot_position: u8,
+    /// Leaders a transaction sent now would be routed to.
+    pub leaders: Vec<LeaderInfo>,
+}
+
+/// Client health and statistics.
+#[derive(Debug, Clone)]
+pub struct ClientStats {
+    /// Number of active QUIC connections.
+    pub connection_count: usize,
+    /// Current estimated slot.
+    pub current_slot: Slot,
+    /// Number of QUIC endpoints.
+    pub endpoint_count: usize,
+    /// Whether leader tracking is ready.
+    pub ready: bool,
+    /// Time since the client was created.
+    pub uptime: Duration,
+    /// Number of validators with known sockets.
+    pub known_validators: usize,
+    /// Identity pubkey used for QUIC client certificates (None if unstaked).
+    pub identity: Option<String>,
+    /// Maximum number of pooled connections.
+    pub max_connections: usize,
+    /// Pooled connections with last-use times, most recently used first.
+    pub connections: Vec<PooledConnection>,
+    /// Interval between connection pre-warming rounds.
+    pub prewarm_interval: Duration,
+    /// Interval between validator TPU socket refreshes.
+    pub socket_refresh_interval: Duration,
+    /// Transport and retry tuning in effect.
+    pub tuning: ConnectionTuning,
+    /// Per-source slot and lag (empty without a leader tracker).
+    pub slot_sources: Vec<SlotSourceStats>,
+    /// Whether schedules and sockets were restored from a snapshot.
+    pub warm_start: bool,
+}
+
+/// Native QUIC client for direct Solana TPU transaction submission.
+///
+/// Must be created from within a Tokio runtime; background tasks run on
+/// that runtime until `shutdown` is called or the client is dropped.
+pub struct FastlaneClient {
+    /// Leader source for routing.
+    leader_source: Arc<dyn LeaderSource>,
+    /// Live leader tracker (None when a custom leader source is used).
+    leader_tracker: Option<Arc<LeaderTracker>>,
+    /// Connection manager for QUIC connections.
+    connection_manager: Arc<TpuConnectionManager>,
+    /// RPC client for confirmation checking.
+    rpc_client: Arc<RpcClient>,
+    /// Shared WebSocket signature subscriptions for confirmation.
+    confirmation_tracker: Arc<ConfirmationTracker>,
+    /// Shutdown signal sender.
+    shutdown_tx: Mutex<Option<oneshot::Sender<()>>>,
+    /// Time when client was created.
+    start_time: Instant,
+    /// Number of leaders to fanout to.
+    fanout: u32,
+    /// Interval between connection pre-warming rounds.
+    prewarm_interval: Duration,
+    /// Interval between validator TPU socket refreshes.
+    socket_refresh_interval: Duration,
+    /// Whether transactions are validated locally before sending.
+    preflight_checks: bool,
+    /// Slot, leader, epoch, socket and send events.
+    events: EventBus,
+}
+
+impl FastlaneClient {
+    /// Creates a client backed by a live leader tracker.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FastlaneError::Config` if the config is invalid, or
+    /// `FastlaneError::Init` if the leader schedule cannot be fetched or the
+    /// QUIC endpoints cannot be created.
+    pub async fn new(config: FastlaneConfig) -> Result<Self, FastlaneError> {
+        config.validate()?;
+
+        let leader_tracker = LeaderTracker::new_with_snapshot(
+            config.rpc_url.clone(),
+            config.ws_url.clone(),
+            config.grpc_url.clone(),
+            config.grpc_x_token.clone(),
+            config.snapshot_path.clone(),
+        )
+        .await
+        .context("Failed to create leader tracker")
+        .map_err(FastlaneError::Init)?
+        .with_slot_sources(config.slot_sources.clone());
+
+        let leader_tracker = Arc::new(leader_tracker);
+        Self::build(config, leader_tracker.clone(), Some(leader_tracker))
+    }
+
+    /// Creates a client that routes through a custom leader source.
+    ///
+    /// No leader tracker is started, so `rpc_url`/`ws_url` are only used for
+    /// confirmation. Epoch bounds and upcoming leader windows are unavailable.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FastlaneError::Config` if the config is invalid, or
+    /// `FastlaneError::Init` if the QUIC endpoints cannot be created.
+    pub async fn with_leader_source(
+        config: FastlaneConfig,
+        leader_source: Arc<dyn LeaderSource>,
+    ) -> Result<Self, FastlaneError> {
+        config.validate()?;
+        Self::build(config, leader_source, None)
+    }
+
+    /// Wires up the connection manager and background tasks.
+    ///
+    /// Tracker maintenance tasks only run when a live `LeaderTracker` is given.
+    fn build(
+        config: FastlaneConfig,
+        leader_source: Arc<dyn LeaderSource>,
+        leader_tracker: Option<Arc<LeaderTracker>>,
+    ) -> Result<Self, FastlaneError> {
+        // Tracker and connection manager publish on one bus
+        let events = leader_tracker
+            .as_ref()
+            .map(|tracker| tracker.events().clone())
+            .unwrap_or_default();
+
+        // Initialize connection manager
+        let connection_manager = TpuConnectionManager::with_tuning(
+            leader_source.clone(),
+            config.identity.as_ref(),
+            config.tuning,
+        )
+        .context("Failed to create connection manager")
+        .map_err(FastlaneError::Init)?
+        .with_max_connections(config.max_connections)
+        .with_events(events.clone());
+        let connection_manager = Arc::new(connection_manager);
+
+        // Create RPC client for confirmation checking
+        let rpc_client = Arc::new(RpcClient::new(config.rpc_url.clone()));
+
+        // Shared signature subscriptions (connects lazily on first send)
+        let confirmation_tracker = Arc::new(ConfirmationTracker::new(config.ws_url.clone()));
+
+        // Create shutdown channel
+        let (shutdown_tx, shutdown_rx) = oneshot::channel();
+
+        // Spawn background tasks
+        let lt_clone = leader_tracker.clone();
+        let cm_clone = connection_manager.clone();
+        let prewarm = config.prewarm_connections;
+        let fanout = config.fanout;
+        let prewarm_lookahead = (fanout as u64) * 4;
+        let prewarm_interval = config.prewarm_interval;
+        let socket_refresh_interval = config.socket_refresh_interval;
+        let preflight_checks = config.preflight_checks;
+        let snapshot_interval = config.snapshot_interval;
+
+        tokio::spawn(async move {
+            let mut tasks = Vec::new();
+
+            if let Some(lt_clone) = lt_clone {
+                // IMPORTANT: Fetch validator sockets FIRST before starting slot listener
+                // This ensures we have socket data when is_ready() returns true.
+                // Sockets restored from a snapshot are refreshed by the updater instead.
+                if !lt_clone.warm_start() {
+                    let _ = lt_clone.update_leader_sockets().await;
+                }
+
+                // Start slot listener (this will set is_ready = true)
+                let lt_for_slots = lt_clone.clone();
+                tasks.push(tokio::spawn(async move {
+                    let _ = lt_for_slots.run_slot_listener().await;
+                }));
+
+                // Start socket updater (every 10 seconds by default for fresher TPU sockets)
+                let lt_for_sockets = lt_clone.clone();
+                tasks.push(tokio::spawn(async move {
+                    lt_for_sockets
+                        .run_socket_updater(socket_refresh_interval)
+                        .await;
+                }));
+
+                // Retry fetching the next epoch schedule if it wasn't available yet
+                let lt_for_schedule = lt_clone.clone();
+                tasks.push(tokio::spawn(async move {
+                    lt_for_schedule
+                        .run_schedule_refresher(Duration::from_secs(30))
+                        .await;
+                }));
+
+                // Persist schedules and sockets for the next start
+                if lt_clone.snapshot_path().is_some() {
+                    let lt_for_snapshot = lt_clone.clone();
+                    tasks.push(tokio::spawn(async move {
+                        lt_for_snapshot.run_snapshot_writer(snapshot_interval).await;
+                    }));
+                }
+            }
+
+            // Drop closed, abandoned and idle entries from the connection pool
+            let cm_for_reaper = cm_clone.clone();
+            tasks.push(tokio::spawn(async move {
+                loop {
+                    tokio::time::sleep(REAP_INTERVAL).await;
+                    cm_for_reaper.reap(CONNECTION_IDLE_TIMEOUT).await;
+                }
+            }));
+
+            // Start connection pre-warmer (every 400ms = 1 slot time by default)
+            // pre-warms connections every ~3 slots for optimal landing.
+            // We prewarm more aggressively (every slot) since we're frontend-facing.
+            if prewarm {
+                tasks.push(tokio::spawn(async move {
+                    loop {
+                        // Prewarm connections to next fanout * 4 slots (leader lookahead).
+                        cm_clone.prewarm_connections(prewarm_lookahead).await;
+                        tokio::time::sleep(prewarm_interval).await;
+                    }
+                }));
+            }
+
+            // Wait for shutdown signal
+            let _ = shutdown_rx.await;
+
+            // Cancel tasks
+            for task in tasks {
+                task.abort();
+            }
+        });
+
+        Ok(Self {
+            leader_source,
+            leader_tracker,
+            connection_manager,
+            rpc_client,
+            confirmation_tracker,
+            shutdown_tx: Mutex::new(Some(shutdown_tx)),
+            start_time: Instant::now(),
+            fanout,
+            prewarm_interval,
+            socket_refresh_interval,
+            preflight_checks,
+            events,
+        })
+    }
+
+    /// Resolves leaders via slot-aware routing, falling back to fixed fanout.
+    ///
+    /// When the current leader is unhealthy, the configured fanout of
+    /// upcoming leaders is added so the transaction still lands promptly.
+    async fn route(&self) -> Result<Vec<LeaderInfo>, FastlaneError> {
+        let (mut leaders, _slot_position) = self.leader_source.get_slot_aware_leaders().await;
+        if leaders.is_empty() {
+            leaders = self
+                .leader_source
+                .get_leaders_with_fanout(self.fanout)
+                .await;
+        } else if self.connection_manager.health().needs_extended_fanout(&leaders) {
+            for leader in self.leader_source.get_leaders_with_fanout(self.fanout).await {
+                if !leaders.iter().any(|l| l.identity == leader.identity) {
+                    leaders.push(leader);
+                }
+            }
+        }
+
+        if leaders.is_empty() {
+            return Err(FastlaneError::NoLeaders);
+        }
+        Ok(leaders)
+    }
+
+    /// Sends a serialized transaction to TPU endpoints (single attempt).
+    ///
+    /// Uses slot-aware leader selection when available, falling back to fanout.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FastlaneError::Preflight` if pre-flight checks are enabled and
+    /// the transaction fails them, or `FastlaneError::NoLeaders` if no leader
+    /// can be resolved.
+    pub async fn send_transaction(&self, tx_data: &[u8]) -> Result<DeliveryResult, FastlaneError> {
+        if self.preflight_checks {
+            preflight_check(tx_data)?;
+        }
+        let leaders = self.route().await?;
+
+        self.connection_manager
+            .send_to_leaders(tx_data, &leaders)
+            .await
+            .map_err(FastlaneError::Send)
+    }
+
+    /// Sends a batch of serialized transactions to TPU endpoints (single attempt).
+    ///
+    /// The leader set is resolved once for the whole batch. Returns one
+    /// result per transaction, in input order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FastlaneError::Preflight` if pre-flight checks are enabled and
+    /// a transaction fails them or repeats another in the batch, or
+    /// `FastlaneError::NoLeaders` if no leader can be resolved.
+    pub async fn send_transactions(
+        &self,
+        transactions: &[Vec<u8>],
+    ) -> Result<Vec<DeliveryResult>, FastlaneError> {
+        if self.preflight_checks {
+            preflight_check_batch(transactions)?;
+        }
+        let leaders = self.route().await?;
+
+        self.connection_manager
+            .send_batch_to_leaders(transactions, &leaders)
+            .await
+            .map_err(FastlaneError::Send)
+    }
+
+    /// Sends a transaction continuously until confirmed, blockhash expiry or timeout.
+    ///
+    /// Durable-nonce transactions (first instruction `AdvanceNonceAccount`)
+    /// don't expire; instead the nonce account is watched and the send ends
+    /// with `ConfirmStatus::NonceAdvanced` once the nonce changes without
+    /// this transaction confirming.
+    ///
+    /// Confirmation is detected via a WebSocket `signatureSubscribe` shared
+    /// across all in-flight sends, falling back to RPC polling if the
+    /// subscription is unavailable.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FastlaneError::Preflight` if pre-flight checks are enabled and
+    /// the transaction fails them, or `FastlaneError::InvalidTransaction` if
+    /// the signature cannot be read.
+    pub async fn send_until_confirmed(
+        &self,
+        tx_data: &[u8],
+        timeout: Duration,
+    ) -> Result<ConfirmOutcome, FastlaneError> {
+        self.send_until_confirmed_with_cancel(tx_data, timeout, &CancellationToken::new())
+            .await
+    }
+
+    /// Like `send_until_confirmed`, but stops as soon as `cancel` is cancelled.
+    ///
+    /// Cancelling ends the send immediately with `ConfirmStatus::Cancelled`
+    /// and the rounds attempted so far, aborting in-flight leader sends.
+    ///
+    /// # Errors
+    ///
+    /// Same as `send_until_confirmed`.
+    pub async fn send_until_confirmed_with_cancel(
+        &self,
+        tx_data: &[u8],
+        timeout: Duration,
+        cancel: &CancellationToken,
+    ) -> Result<ConfirmOutcome, FastlaneError> {
+        let start = Instant::now();
+
+        let signature = if self.preflight_checks {
+            preflight_check(tx_data)?
+        } else {
+            extract_signature(tx_data)
+                .map_err(|e| FastlaneError::InvalidTransaction(e.to_string()))?
+        };
+
+        // Recent blockhash for expiry tracking (skipped if it can't be parsed).
+        // For durable-nonce transactions this is the nonce itself.
+        let recent_blockhash = extract_recent_blockhash(tx_data).ok();
+        let nonce_account = extract_nonce_account(tx_data).ok().flatten();
+        let mut blockhash_expired = false;
+        let mut last_valid_block_height = None;
+        let mut blockhash_unseen_checks = 0u32;
+        let mut nonce_advanced = false;
+
+        let mut rounds = 0u32;
+        let mut total_leaders_sent = 0u32;
+
+        // Staleness detection - track if slot hasn't changed between rounds
+        let mut last_slot: u64 = 0;
+        let mut stale_rounds: u32 = 0;
+
+        // Subscribed alongside the first send; None falls back to RPC polling.
+        let mut confirmation = None;
+
+        let outcome = |status, rounds, total_leaders_sent| ConfirmOutcome {
+            status,
+            signature,
+            rounds,
+            total_leaders_sent,
+            latency: start.elapsed(),
+        };
+
+        // Send loop - continues until confirmed, timeout or cancellation.
+        // Cancelling drops the loop mid-round, aborting in-flight leader sends
+        // along with a pending subscription or expiry check.
+        let resend = async {
+            while start.elapsed() < timeout {
+                rounds += 1;
+
+                // Check for stale slot (same slot for multiple rounds). The
+                // tracker extrapolates through short feed gaps, so this mainly
+                // catches longer outages.
+                let current_slot = self.leader_source.current_slot().await;
+                if current_slot == last_slot && current_slot != 0 {
+                    stale_rounds += 1;
+                    if stale_rounds >= 2 {
+                        let _ = self.leader_source.refresh_slot().await;
+                    }
+                } else {
+                    stale_rounds = 0;
+                    last_slot = current_slot;
+                }
+
+                // 1. Send to slot-aware leaders. The first round also subscribes
+                // to the signature, bounded by the remaining budget so a slow
+                // WebSocket never delays the send or outlives the timeout
+                if rounds == 1 {
+                    let budget = timeout
+                        .saturating_sub(start.elapsed())
+                        .min(SUBSCRIBE_TIMEOUT);
+                    let (subscribed, sent) = tokio::join!(
+                        tokio::time::timeout(
+                            budget,
+                            self.confirmation_tracker.subscribe(signature)
+                        ),
+                        self.send_round(tx_data)
+                    );
+                    confirmation = subscribed.ok().and_then(Result::ok);
+                    total_leaders_sent += sent;
+                } else {
+                    total_leaders_sent += self.send_round(tx_data).await;
+                }
+
+                // 2. Wait up to one slot for confirmation before the next round
+                // Use a shorter wait if we're close to timeout
+                let remaining = timeout.saturating_sub(start.elapsed());
+                let wait = remaining.min(SLOT_DURATION);
+
+                let confirmed = match confirmation.as_mut() {
+                    Some(waiter) => match waiter.wait(wait).await {
+                        ConfirmationStatus::Confirmed => true,
+                        ConfirmationStatus::Pending => false,
+                        ConfirmationStatus::Lost => {
+                            // Subscription dropped - poll RPC for the rest of this send
+                            confirmation = None;
+                            self.check_confirmed(&signature).await.unwrap_or(false)
+                        }
+                    },
+                    None => {
+                        if let Ok(true) = self.check_confirmed(&signature).await {
+                            true
+                        } else {
+                            tokio::time::sleep(wait).await;
+                            false
+                        }
+                    }
+                };
+
+                // The first send may confirm before the subscription was
+                // acknowledged, so poll once to not miss it
+                if !confirmed
+                    && rounds == 1
+                    && confirmation.is_some()
+                    && self.check_confirmed(&signature).await.unwrap_or(false)
+                {
+                    return true;
+                }
+
+                if confirmed {
+                    return true;
+                }
+
+                // Stop resending once the blockhash has expired or the nonce was
+                // advanced - the transaction can never land. Checked after
+                // sending, so a fresh blockhash never costs an RPC round-trip
+                // up front
+                if let Some(blockhash) = recent_blockhash.as_ref() {
+                    if rounds % BLOCKHASH_CHECK_INTERVAL_ROUNDS == 1 {
+                        match nonce_account.as_ref() {
+                            Some(nonce_account) => {
+                                if self.is_nonce_advanced(nonce_account, blockhash).await {
+                                    nonce_advanced = true;
+                                    break;
+                                }
+                            }
+                            // Once the expiry height is known, only the block
+                            // height has to be watched
+                            None => match last_valid_block_height {
+                                Some(last_valid) => {
+                                    if self
+                                        .block_height()
+                                        .await
+                                        .is_some_and(|height| height > last_valid)
+                                    {
+                                        blockhash_expired = true;
+                                        break;
+                                    }
+                                }
+                                None => match self.is_blockhash_valid(blockhash).await {
+                                    Some(true) => {
+                                        last_valid_block_height =
+                                            self.last_valid_block_height().await;
+                                    }
+                                    // The RPC node may not have seen a fresh
+                                    // blockhash yet, so allow a few checks first
+                                    Some(false) => {
+                                        blockhash_unseen_checks += 1;
+                                        if blockhash_unseen_checks >= BLOCKHASH_UNSEEN_GRACE_CHECKS
+                                        {
+                                            blockhash_expired = true;
+                                            break;
+                                        }
+                                    }
+                                    None => {}
+                                },
+                            },
+                        }
+                    }
+                }
+            }
+            false
+        };
+
+        let resent = tokio::select! {
+            biased;
+            _ = cancel.cancelled() => None,
+            confirmed = resend => Some(confirmed),
+        };
+
+        let status = match resent {
+            None => ConfirmStatus::Cancelled,
+            Some(true) => ConfirmStatus::Confirmed,
+            // Timeout or expiry - do one final confirmation check, which also
+            // catches this transaction being the one that advanced the nonce
+            Some(false) => {
+                if self.check_confirmed(&signature).await.unwrap_or(false) {
+                    ConfirmStatus::Confirmed
+                } else if nonce_advanced {
+                    ConfirmStatus::NonceAdvanced
+                } else if blockhash_expired {
+                    ConfirmStatus::BlockhashExpired
+                } else {
+                    ConfirmStatus::TimedOut
+                }
+            }
+        };
+
+        Ok(outcome(status, rounds, total_leaders_sent))
+    }
+
+    /// Sends to slot-aware leaders (1 or 2 based on slot position), falling
+    /// back to fixed fanout if slot estimation is unreliable.
+    ///
+    /// # Returns
+    ///
+    /// The number of leaders the transaction was delivered to.
+    async fn send_round(&self, tx_data: &[u8]) -> u32 {
+        let leaders = match self.route().await {
+            Ok(leaders) => leaders,
+            Err(_) => return 0,
+        };
+        match self
+            .connection_manager
+            .send_to_leaders(tx_data, &leaders)
+            .await
+        {
+            Ok(result) => result.leader_count as u32,
+            Err(_) => 0,
+        }
+    }
+
+    /// Check whether a blockhash is still valid for new transactions.
+    ///
+    /// # Returns
+    ///
+    /// `None` on RPC errors, so a flaky RPC never stops a send early.
+    async fn is_blockhash_valid(&self, blockhash: &Hash) -> Option<bool> {
+        self.rpc_client
+            .is_blockhash_valid(blockhash, CommitmentConfig::confirmed())
+            .await
+            .ok()
+    }
+
+    /// Resolve the last block height at which a currently valid blockhash
+    /// can still land.
+    ///
+    /// Uses the latest blockhash's `lastValidBlockHeight`, which bounds
+    /// every valid blockhash since older ones expire first.
+    ///
+    /// # Returns
+    ///
+    /// `None` on RPC errors.
+    async fn last_valid_block_height(&self) -> Option<u64> {
+        self.rpc_client
+            .get_latest_blockhash_with_commitment(CommitmentConfig::confirmed())
+            .await
+            .ok()
+            .map(|(_, last_valid_block_height)| last_valid_block_height)
+    }
+
+    /// Current block height, or `None` on RPC errors.
+    async fn block_height(&self) -> Option<u64> {
+        self.rpc_client
+            .get_block_height_with_commitment(CommitmentConfig::confirmed())
+            .await
+            .ok()
+    }
+
+    /// Check whether a durable nonce account no longer holds `nonce`.
+    ///
+    /// RPC errors, missing accounts and unparseable data are treated as
+    /// "not advanced" so a flaky RPC never stops a send early.
+    async fn is_nonce_advanced(&self, nonce_account: &Pubkey, nonce: &Hash) -> bool {
+        let account = self
+            .rpc_client
+            .get_account_with_commitment(nonce_account, CommitmentConfig::confirmed())
+            .await;
+
+        match account {
+            Ok(response) => response
+                .value
+                .and_then(|account| parse_durable_nonce(&account.data))
+                .map(|current| current != *nonce)
+                .unwrap_or(false),
+            Err(_) => false,
+        }
+    }
+
+    /// Check if a transaction is confirmed on-chain.
+    async fn check_confirmed(&self, signature: &Signature) -> anyhow::Result<bool> {
+        let response = self
+            .rpc_client
+            .get_signature_statuses(&[*signature])
+            .await
+            .context("Failed to get signature status")?;
+
+        if let Some(Some(status)) = response.value.first() {
+            // Check if confirmed or finalized
+            // The confirmation_status field indicates the commitment level achieved
+            if let Some(ref conf_status) = status.confirmation_status {
+                return Ok(matches!(
+                    conf_status,
+                    TransactionConfirmationStatus::Confirmed
+                        | TransactionConfirmationStatus::Finalized
+                ));
+            }
+            // If confirmations is Some, it's at least confirmed
+            if status.confirmations.is_some() {
+                return Ok(true);
+            }
+            // If err is None and we have a status, the transaction was processed
+            if status.err.is_none() {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Gets the current estimated slot.
+    pub async fn current_slot(&self) -> Slot {
+        self.leader_source.current_slot().await
+    }
+
+    /// Gets upcoming leader windows with slot ranges and TPU sockets.
+    ///
+    /// Empty when the client routes through a custom leader source.
+    pub async fn upcoming_leaders(&self, lookahead_slots: u64) -> Vec<LeaderWindow> {
+        match self.leader_tracker.as_ref() {
+            Some(leader_tracker) => leader_tracker.get_leader_windows(lookahead_slots).await,
+            None => vec![],
+        }
+    }
+
+    /// Gets the leaders the slot-aware strategy would route to right now.
+    pub async fn slot_aware_leaders(&self) -> SlotAwareRoute {
+        let current_slot = self.leader_source.current_slot().await;
+        let (leaders, slot_position) = self.leader_source.get_slot_aware_leaders().await;
+
+        SlotAwareRoute {
+            current_slot,
+            slot_position,
+            leaders,
+        }
+    }
+
+    /// Gets the current epoch boundaries.
+    ///
+    /// All zero when the client routes through a custom leader source.
+    pub async fn epoch_bounds(&self) -> EpochBounds {
+        match self.leader_tracker.as_ref() {
+            Some(leader_tracker) => leader_tracker.epoch_bounds().await,
+            None => EpochBounds::default(),
+        }
+    }
+
+    /// Subscribes to client events published from now on.
+    ///
+    /// Slot, leader change, epoch and socket events come from the live
+    /// leader tracker, so clients built on a custom leader source only
+    /// publish send outcomes. Subscribers that fall more than
+    /// `EVENT_CHANNEL_CAPACITY` events behind miss the oldest ones.
+    pub fn subscribe(&self) -> broadcast::Receiver<ClientEvent> {
+        self.events.subscribe()
+    }
+
+    /// Gets the number of active QUIC connections.
+    pub fn connection_count(&self) -> usize {
+        self.connection_manager.connection_count()
+    }
+
+    /// Gets the send and connect health of every leader sent to recently.
+    ///
+    /// Unhealthy leaders cause routing to widen fanout, and addresses that
+    /// keep failing to connect are skipped by the prewarmer.
+    pub fn leader_health(&self) -> Vec<LeaderHealthSnapshot> {
+        self.connection_manager.health().snapshot()
+    }
+
+    /// Gets client statistics.
+    pub async fn stats(&self) -> ClientStats {
+        let (known_validators, slot_sources) = match self.leader_tracker.as_ref() {
+            Some(leader_tracker) => (
+                leader_tracker.validator_count().await,
+                leader_tracker.slot_source_stats().await,
+            ),
+            None => (0, Vec::new()),
+        };
+
+        ClientStats {
+            connection_count: self.connection_manager.connection_count(),
+            current_slot: self.leader_source.current_slot().await,
+            endpoint_count: self.connection_manager.endpoint_count(),
+            ready: self.leader_source.is_ready().await,
+            uptime: self.start_time.elapsed(),
+            known_validators,
+            identity: self.connection_manager.identity(),
+            max_connections: self.connection_manager.max_connections(),
+            connections: self.connection_manager.pooled_connections(),
+            prewarm_interval: self.prewarm_interval,
+            socket_refresh_interval: self.socket_refresh_interval,
+            tuning: self.connection_manager.tuning(),
+            slot_sources,
+            warm_start: self
+                .leader_tracker
+                .as_ref()
+                .is_some_and(|leader_tracker| leader_tracker.warm_start()),
+        }
+    }
+
+    /// Renders client metrics in Prometheus text exposition format.
+    pub async fn metrics(&self) -> String {
+        let current_slot = self.leader_source.current_slot().await;
+        let mut out = String::new();
+
+        write_gauge(
+            &mut out,
+            "fastlane_connections",
+            "Number of active QUIC connections.",
+            self.connection_manager.connection_count() as i64,
+        );
+        write_gauge(
+            &mut out,
+            "fastlane_endpoints",
+            "Number of QUIC endpoints.",
+            self.connection_manager.endpoint_count() as i64,
+        );
+        write_gauge(
+            &mut out,
+            "fastlane_current_slot",
+            "Current estimated slot.",
+            current_slot as i64,
+        );
+        write_gauge(
+            &mut out,
+            "fastlane_unhealthy_leaders",
+            "Leaders whose recent send success rate is below threshold.",
+            self.connection_manager.health().unhealthy_count() as i64,
+        );
+        self.connection_manager.metrics().write_prometheus(&mut out);
+
+        if let Some(leader_tracker) = self.leader_tracker.as_ref() {
+            write_gauge(
+                &mut out,
+                "fastlane_known_validators",
+                "Number of validators with known sockets.",
+                leader_tracker.validator_count().await as i64,
+            );
+            leader_tracker.metrics().write_prometheus(&mut out);
+        }
+
+        out
+    }
+
+    /// Rotates the identity used for QUIC client certificates.
+    ///
+    /// Existing connections are closed and re-established with the new
+    /// identity on the next send. Pass `None` to revert to an unstaked identity.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FastlaneError::Identity` if the client certificate cannot be built.
+    pub fn set_identity(&self, identity: Option<&Keypair>) -> Result<(), FastlaneError> {
+        self.connection_manager
+            .set_identity(identity)
+            .context("Failed to rotate identity")
+            .map_err(FastlaneError::Identity)
+    }
+
+    /// Waits for leader tracking to become ready.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FastlaneError::NotReady` if the timeout elapses first.
+    pub async fn wait_ready(&self, timeout: Duration) -> Result<(), FastlaneError> {
+        let start = Instant::now();
+
+        while start.elapsed() < timeout {
+            if self.leader_source.is_ready().await {
+                return Ok(());
+            }
+            tokio::time::sleep(Duration::from_millis(500).min(timeout)).await;
+        }
+
+        Err(FastlaneError::NotReady)
+    }
+
+    /// Stops background tasks and closes all connections.
+    pub fn shutdown(&self) {
+        // Send shutdown signal
+        if let Some(tx) = self.shutdown_tx.lock().unwrap().take() {
+            let _ = tx.send(());
+        }
+
+        // Close all connections
+        self.connection_manager.close_all();
+    }
+}
+
+impl Drop for FastlaneClient {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+impl std::fmt::Debug for FastlaneClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FastlaneClient")
+            .field("leader_source", &self.leader_source)
+            .field("fanout", &self.fanout)
+            .finish()
+    }
+}
+
+/// Extract signature from a serialized transaction.
+///
+/// Solana transaction format: [num_signatures, ...signatures (64 bytes each), ...]
+fn extract_signature(tx_data: &[u8]) -> anyhow::Result<Signature> {
+    if tx_data.is_empty() {
+        anyhow::bail!("Empty transaction data");
+    }
+
+    let num_signatures = tx_data[0] as usize;
+    if num_signatures == 0 {
+        anyhow::bail!("Transaction has no signatures");
+    }
+
+    if tx_data.len() < 1 + 64 {
+        anyhow::bail!("Transaction too short to contain signature");
+    }
+
+    // First signature is at offset 1, 64 bytes
+    let sig_bytes: [u8; 64] = tx_data[1..65]
+        .try_into()
+        .context("Failed to extract signature bytes")?;
+
+    Ok(Signature::from(sig_bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{MockBehavior, MockRpcServer, MockTpuServer};
+    use crate::tracker::StaticLeaderSource;
+    use solana_sdk::message::Message;
+    use solana_sdk::signer::Signer;
+    use solana_sdk::transaction::Transaction;
+
+    async fn pinned_client(server: &MockTpuServer) -> FastlaneClient {
+        pinned_client_with_ws(server, "ws://127.0.0.1:1").await
+    }
+
+    async fn pinned_client_with_ws(server: &MockTpuServer, ws_url: &str) -> FastlaneClient {
+        pinned_client_with_urls(server, "http://127.0.0.1:1", ws_url).await
+    }
+
+    async fn pinned_client_with_urls(
+        server: &MockTpuServer,
+        rpc_url: &str,
+        ws_url: &str,
+    ) -> FastlaneClient {
+        let source =
+            StaticLeaderSource::new(vec![("pinned-leader".to_string(), server.address())], 100);
+        let mut config = FastlaneConfig::new(rpc_url, ws_url);
+        config.prewarm_connections = false;
+
+        FastlaneClient::with_leader_source(config, Arc::new(source))
+            .await
+            .unwrap()
+    }
+
+    /// Returns a WebSocket URL whose server accepts connections but never
+    /// completes the handshake.
+    async fn hung_ws_url() -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut held = Vec::new();
+            while let Ok((stream, _)) = listener.accept().await {
+                held.push(stream);
+            }
+        });
+        format!("ws://{}", address)
+    }
+
+    #[tokio::test]
+    async fn test_send_transaction_through_static_source() {
+        let server = MockTpuServer::start(MockBehavior::Accept).await;
+        let client = pinned_client(&server).await;
+
+        let result = client.send_transaction(b"native-tx").await.unwrap();
+
+        assert!(result.delivered);
+        assert_eq!(result.leaders[0].identity, "pinned-leader");
+        assert_eq!(
+            server
+                .wait_for_transactions(1, Duration::from_secs(2))
+                .await,
+            vec![b"native-tx".to_vec()]
+        );
+
+        let route = client.slot_aware_leaders().await;
+        assert_eq!(route.current_slot, 100);
+        assert_eq!(route.slot_position, 0);
+        assert_eq!(route.leaders.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_unhealthy_leader_widens_route() {
+        let server = MockTpuServer::start(MockBehavior::Accept).await;
+        let source = StaticLeaderSource::new(
+            vec![
+                ("flaky-leader".to_string(), "127.0.0.1:1".to_string()),
+                ("next-leader".to_string(), server.address()),
+            ],
+            96,
+        );
+        let mut config = FastlaneConfig::new("http://127.0.0.1:1", "ws://127.0.0.1:1");
+        config.prewarm_connections = false;
+        config.fanout = 2;
+        let client = FastlaneClient::with_leader_source(config, Arc::new(source))
+            .await
+            .unwrap();
+
+        assert_eq!(client.route().await.unwrap().len(), 1);
+
+        let health = client.connection_manager.health();
+        for _ in 0..4 {
+            health.record_send("flaky-leader", "127.0.0.1:1", Some(TpuErrorCode::Timeout));
+        }
+
+        let route = client.route().await.unwrap();
+        let identities: Vec<&str> = route.iter().map(|l| l.identity.as_str()).collect();
+        assert_eq!(identities, ["flaky-leader", "next-leader"]);
+        assert!(!client.leader_health()[0].healthy);
+    }
+
+    #[tokio::test]
+    async fn test_typed_errors() {
+        let server = MockTpuServer::start(MockBehavior::Accept).await;
+        let client = pinned_client(&server).await;
+
+        let err = client
+            .send_until_confirmed(&[], Duration::from_secs(1))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, FastlaneError::InvalidTransaction(_)));
+
+        let source = StaticLeaderSource::new(vec![], 100);
+        let client = FastlaneClient::with_leader_source(
+            FastlaneConfig::new("http://127.0.0.1:1", "ws://127.0.0.1:1"),
+            Arc::new(source),
+        )
+        .await
+        .unwrap();
+        let err = client.send_transaction(b"tx").await.unwrap_err();
+        assert!(matches!(err, FastlaneError::NoLeaders));
+        assert_eq!(err.code(), Some(TpuErrorCode::NoLeaders));
+    }
+
+    #[tokio::test]
+    async fn test_preflight_checks_reject_before_sending() {
+        let server = MockTpuServer::start(MockBehavior::Accept).await;
+        let source =
+            StaticLeaderSource::new(vec![("pinned-leader".to_string(), server.address())], 100);
+        let mut config = FastlaneConfig::new("http://127.0.0.1:1", "ws://127.0.0.1:1");
+        config.prewarm_connections = false;
+        config.preflight_checks = true;
+        let client = FastlaneClient::with_leader_source(config, Arc::new(source))
+            .await
+            .unwrap();
+
+        let err = client.send_transaction(b"native-tx").await.unwrap_err();
+        assert_eq!(err.code(), Some(TpuErrorCode::InvalidTransaction));
+
+        let payer = Keypair::new();
+        let message = Message::new_with_blockhash(&[], Some(&payer.pubkey()), &Hash::default());
+        let tx = Transaction::new(&[&payer], message, Hash::default());
+        let tx_data = bincode::serialize(&tx).unwrap();
+
+        let err = client
+            .send_transactions(&[tx_data.clone(), tx_data.clone()])
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), Some(TpuErrorCode::DuplicateTransaction));
+
+        let result = client.send_transaction(&tx_data).await.unwrap();
+        assert!(result.delivered);
+        assert_eq!(
+            server
+                .wait_for_transactions(1, Duration::from_secs(2))
+                .await,
+            vec![tx_data]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_subscribers_receive_send_outcomes() {
+        let server = MockTpuServer::start(MockBehavior::Accept).await;
+        let client = pinned_client(&server).await;
+        let mut events = client.subscribe();
+
+        client.send_transaction(b"native-tx").await.unwrap();
+
+        let event = tokio::time::timeout(Duration::from_secs(2), events.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(event.kind(), "send");
+        match event {
+            ClientEvent::LeaderSend(result) => {
+                assert!(result.success);
+                assert_eq!(result.identity, "pinned-leader");
+                assert_eq!(result.address, server.address());
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cancel_stops_resending() {
+        let server = MockTpuServer::start(MockBehavior::Accept).await;
+        let client = pinned_client(&server).await;
+        let tx = vec![1u8; 1 + 64 + 8];
+
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+        let outcome = client
+            .send_until_confirmed_with_cancel(&tx, Duration::from_secs(5), &cancel)
+            .await
+            .unwrap();
+        assert_eq!(outcome.status, ConfirmStatus::Cancelled);
+        assert_eq!(outcome.rounds, 0);
+
+        let cancel = CancellationToken::new();
+        let canceller = cancel.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(300)).await;
+            canceller.cancel();
+        });
+
+        let outcome = client
+            .send_until_confirmed_with_cancel(&tx, Duration::from_secs(5), &cancel)
+            .await
+            .unwrap();
+        assert_eq!(outcome.status, ConfirmStatus::Cancelled);
+        assert_eq!(outcome.error_code(), Some(TpuErrorCode::Cancelled));
+        assert!(outcome.rounds >= 1);
+        assert!(outcome.latency < Duration::from_secs(2));
+        assert!(!server.received().is_empty());
+    }
+
+    /// Builds a signed legacy transaction with a parseable blockhash.
+    fn blockhash_tx(blockhash: Hash) -> Vec<u8> {
+        let payer = Keypair::new();
+        let message = Message::new_with_blockhash(&[], Some(&payer.pubkey()), &blockhash);
+        bincode::serialize(&Transaction::new(&[&payer], message, blockhash)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_blockhash_stale_at_submit_expires() {
+        let server = MockTpuServer::start(MockBehavior::Accept).await;
+        let rpc = MockRpcServer::start(&[
+            (
+                "isBlockhashValid",
+                r#"{"context":{"slot":1},"value":false}"#,
+            ),
+            (
+                "getSignatureStatuses",
+                r#"{"context":{"slot":1},"value":[null]}"#,
+            ),
+        ])
+        .await;
+        let client = pinned_client_with_urls(&server, &rpc.url(), "ws://127.0.0.1:1").await;
+
+        let outcome = client
+            .send_until_confirmed(&blockhash_tx(Hash::new_unique()), Duration::from_secs(15))
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.status, ConfirmStatus::BlockhashExpired);
+        assert_eq!(
+            rpc.calls("isBlockhashValid"),
+            BLOCKHASH_UNSEEN_GRACE_CHECKS as usize
+        );
+        assert!(outcome.latency < Duration::from_secs(10));
+    }
+
+    #[tokio::test]
+    async fn test_blockhash_expires_past_last_valid_block_height() {
+        let server = MockTpuServer::start(MockBehavior::Accept).await;
+        let latest = format!(
+            r#"{{"context":{{"slot":1}},"value":{{"blockhash":"{}","lastValidBlockHeight":100}}}}"#,
+            Hash::default()
+        );
+        let rpc = MockRpcServer::start(&[
+            ("isBlockhashValid", r#"{"context":{"slot":1},"value":true}"#),
+            ("getLatestBlockhash", &latest),
+            ("getBlockHeight", "101"),
+            (
+                "getSignatureStatuses",
+                r#"{"context":{"slot":1},"value":[null]}"#,
+            ),
+        ])
+        .await;
+        let client = pinned_client_with_urls(&server, &rpc.url(), "ws://127.0.0.1:1").await;
+
+        let outcome = client
+            .send_until_confirmed(&blockhash_tx(Hash::new_unique()), Duration::from_secs(15))
+            .await
+            .unwrap();
+
+        // Seen valid on the first check, expired by height on the next
+        assert_eq!(outcome.status, ConfirmStatus::BlockhashExpired);
+        assert_eq!(outcome.rounds, BLOCKHASH_CHECK_INTERVAL_ROUNDS + 1);
+        assert_eq!(rpc.calls("isBlockhashValid"), 1);
+        assert_eq!(rpc.calls("getBlockHeight"), 1);
+    }
+
+    #[tokio::test]
+    async fn test_hung_websocket_does_not_delay_sends() {
+        let server = MockTpuServer::start(MockBehavior::Accept).await;
+        let client = pinned_client_with_ws(&server, &hung_ws_url().await).await;
+        let tx = vec![1u8; 1 + 64 + 8];
+
+        let send = client.send_until_confirmed(&tx, Duration::from_secs(2));
+        let received = server.wait_for_transactions(1, Duration::from_millis(500));
+        let (outcome, received) = tokio::join!(send, received);
+
+        // First send went out while the subscription was still pending
+        assert_eq!(received.len(), 1);
+        let outcome = outcome.unwrap();
+        assert_eq!(outcome.status, ConfirmStatus::TimedOut);
+        assert!(outcome.rounds >= 2);
+        assert!(outcome.latency < Duration::from_secs(3));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_interrupts_pending_subscription() {
+        let server = MockTpuServer::start(MockBehavior::Accept).await;
+        let client = pinned_client_with_ws(&server, &hung_ws_url().await).await;
+        let tx = vec![1u8; 1 + 64 + 8];
+
+        let cancel = CancellationToken::new();
+        let canceller = cancel.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            canceller.cancel();
+        });
+
+        let outcome = client
+            .send_until_confirmed_with_cancel(&tx, Duration::from_secs(5), &cancel)
+            .await
+            .unwrap();
+        assert_eq!(outcome.status, ConfirmStatus::Cancelled);
+        assert_eq!(outcome.rounds, 1);
+        assert!(outcome.latency < SUBSCRIBE_TIMEOUT);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_tuning_is_rejected_and_effective_tuning_reported() {
+        let source: Arc<dyn LeaderSource> = Arc::new(StaticLeaderSource::new(vec![], 100));
+
+        let mut config = FastlaneConfig::new("http://127.0.0.1:1", "ws://127.0.0.1:1");
+        config.tuning.retry.max_attempts = 0;
+        let err = FastlaneClient::with_leader_source(config, source.clone())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, FastlaneError::Config(_)));
+
+        let mut config = FastlaneConfig::new("http://127.0.0.1:1", "ws://127.0.0.1:1");
+        config.prewarm_connections = false;
+        config.prewarm_interval = Duration::from_millis(100);
+        config.tuning.endpoint_count = 2;
+        config.tuning.collect_timeout = Duration::from_millis(300);
+        let client = FastlaneClient::with_leader_source(config, source)
+            .await
+            .unwrap();
+
+        let stats = client.stats().await;
+        assert_eq!(stats.endpoint_count, 2);
+        assert_eq!(stats.tuning.collect_timeout, Duration::from_millis(300));
+        assert_eq!(stats.prewarm_interval, Duration::from_millis(100));
+        assert_eq!(stats.socket_refresh_interval, SOCKET_REFRESH_INTERVAL);
+    }
+}
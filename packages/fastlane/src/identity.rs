@@ -0,0 +1,111 @@
+//! Validator identity loading for staked QUIC connections.
+//!
+//! Validators allocate stream quotas based on the stake of the identity
+//! presented in the QUIC client certificate. Connecting with a staked
+//! identity keypair gives stake-weighted QoS instead of the minimal
+//! quota granted to unstaked (throwaway) identities.
+
+use anyhow::{bail, Context, Result};
+#[cfg(feature = "napi")]
+use napi_derive::napi;
+use solana_sdk::signature::{read_keypair_file, Keypair};
+
+/// Identity keypair used for QUIC client certificates.
+///
+/// Exactly one source must be set.
+#[cfg_attr(feature = "napi", napi(object))]
+#[derive(Clone)]
+pub struct IdentityKeypair {
+    /// Path to a Solana CLI keypair file (JSON array of 64 bytes).
+    pub path: Option<String>,
+    /// Base58-encoded 64-byte secret key.
+    pub base58: Option<String>,
+    /// Raw 64-byte secret key.
+    pub bytes: Option<Vec<u8>>,
+}
+
+impl std::fmt::Debug for IdentityKeypair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print secret key material.
+        f.debug_struct("IdentityKeypair")
+            .field("path", &self.path)
+            .field("base58", &self.base58.as_ref().map(|_| "<redacted>"))
+            .field("bytes", &self.bytes.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl IdentityKeypair {
+    /// Loads the keypair from whichever source is configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no source or more than one source is set,
+    /// or if the key material is invalid.
+    pub fn load(&self) -> Result<Keypair> {
+        match (&self.path, &self.base58, &self.bytes) {
+            (Some(path), None, None) => read_keypair_file(path)
+                .map_err(|e| anyhow::anyhow!("{}", e))
+                .context(format!("Failed to read identity keypair file {}", path)),
+            (None, Some(base58), None) => {
+                let bytes = bs58::decode(base58.trim())
+                    .into_vec()
+                    .context("Identity keypair is not valid base58")?;
+                keypair_from_bytes(&bytes)
+            }
+            (None, None, Some(bytes)) => keypair_from_bytes(bytes),
+            (None, None, None) => bail!("Identity keypair has no path, base58 or bytes set"),
+            _ => bail!("Identity keypair must set exactly one of path, base58 or bytes"),
+        }
+    }
+}
+
+/// Builds a keypair from a 64-byte secret key.
+fn keypair_from_bytes(bytes: &[u8]) -> Result<Keypair> {
+    Keypair::try_from(bytes)
+        .map_err(|e| anyhow::anyhow!("Invalid identity keypair bytes: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::signer::Signer;
+
+    fn identity(path: Option<String>, base58: Option<String>, bytes: Option<Vec<u8>>) -> IdentityKeypair {
+        IdentityKeypair { path, base58, bytes }
+    }
+
+    #[test]
+    fn test_load_from_bytes_and_base58() {
+        let keypair = Keypair::new();
+
+        let from_bytes = identity(None, None, Some(keypair.to_bytes().to_vec()))
+            .load()
+            .unwrap();
+        assert_eq!(from_bytes.pubkey(), keypair.pubkey());
+
+        let from_base58 = identity(None, Some(keypair.to_base58_string()), None)
+            .load()
+            .unwrap();
+        assert_eq!(from_base58.pubkey(), keypair.pubkey());
+    }
+
+    #[test]
+    fn test_load_rejects_invalid_sources() {
+        assert!(identity(None, None, None).load().is_err());
+        assert!(identity(None, None, Some(vec![1, 2, 3])).load().is_err());
+        assert!(identity(None, Some("not-base58-0OIl".to_string()), None).load().is_err());
+        assert!(identity(Some("/tmp/id.json".to_string()), None, Some(vec![0; 64]))
+            .load()
+            .is_err());
+    }
+
+    #[test]
+    fn test_debug_redacts_secrets() {
+        let keypair = Keypair::new();
+        let secret = keypair.to_base58_string();
+        let debug = format!("{:?}", identity(None, Some(secret.clone()), None));
+        assert!(!debug.contains(&secret));
+        assert!(debug.contains("<redacted>"));
+    }
+}
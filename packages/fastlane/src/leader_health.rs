@@ -0,0 +1,394 @@
+//! Per-leader health scoring for adaptive routing.
+//!
+//! Remembers how each leader identity has handled recent sends (rolling
+//! success rate and error codes) and how each TPU address has handled
+//! recent connects (latency and consecutive failures). Routing uses this
+//! to widen fanout when the current leader looks unhealthy, and the
+//! prewarmer skips addresses that keep failing to connect.
+
+use dashmap::DashMap;
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use crate::errors::TpuErrorCode;
+use crate::tracker::LeaderInfo;
+
+/// Number of recent send outcomes kept per leader.
+const SEND_WINDOW: usize = 32;
+
+/// Minimum outcomes before a leader can be judged unhealthy.
+const MIN_SAMPLES: usize = 4;
+
+/// Success rate below which a leader is considered unhealthy.
+const UNHEALTHY_SUCCESS_RATE: f64 = 0.5;
+
+/// Outcomes older than this no longer count; leaders are only sent to
+/// while scheduled, so stale history says little about their next window.
+const STALE_AFTER: Duration = Duration::from_secs(600);
+
+/// Consecutive connect failures after which an address is not prewarmed.
+const PREWARM_FAILURE_THRESHOLD: u32 = 3;
+
+/// How long a failing address is skipped by the prewarmer before retrying.
+const PREWARM_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Smoothing factor for the connect latency moving average.
+const LATENCY_EWMA_ALPHA: f64 = 0.2;
+
+/// Recent send history for a leader identity.
+#[derive(Debug)]
+struct LeaderStats {
+    /// Last TPU address sent to.
+    address: String,
+    /// Recent outcomes, newest last (`None` = success, else the error code).
+    outcomes: VecDeque<Option<TpuErrorCode>>,
+    /// When the last outcome was recorded.
+    updated: Instant,
+}
+
+impl LeaderStats {
+    fn new(address: &str) -> Self {
+        Self {
+            address: address.to_string(),
+            outcomes: VecDeque::with_capacity(SEND_WINDOW),
+            updated: Instant::now(),
+        }
+    }
+
+    /// Returns failure counts per error code over the window.
+    fn errors(&self) -> HashMap<TpuErrorCode, u64> {
+        let mut errors = HashMap::new();
+        for code in self.outcomes.iter().flatten() {
+            *errors.entry(*code).or_insert(0) += 1;
+        }
+        errors
+    }
+
+    /// Returns the success rate over the window, or `None` with too few samples.
+    fn success_rate(&self) -> Option<f64> {
+        if self.outcomes.len() < MIN_SAMPLES || self.updated.elapsed() > STALE_AFTER {
+            return None;
+        }
+        let successes = self.outcomes.iter().filter(|code| code.is_none()).count();
+        Some(successes as f64 / self.outcomes.len() as f64)
+    }
+}
+
+/// Recent connect history for a TPU address.
+#[derive(Debug)]
+struct AddressStats {
+    /// Moving average of successful connect latency in milliseconds.
+    connect_latency_ms: Option<f64>,
+    /// Connect failures since the last success.
+    consecutive_failures: u32,
+    /// When the last connect failed.
+    last_failure: Option<Instant>,
+    /// When the last connect was recorded.
+    updated: Instant,
+}
+
+impl AddressStats {
+    fn new() -> Self {
+        Self {
+            connect_latency_ms: None,
+            consecutive_failures: 0,
+            last_failure: None,
+            updated: Instant::now(),
+        }
+    }
+}
+
+/// Health snapshot for a single leader.
+#[derive(Debug, Clone)]
+pub struct LeaderHealthSnapshot {
+    /// Validator identity pubkey.
+    pub identity: String,
+    /// Last TPU address sent to.
+    pub address: String,
+    /// Number of send outcomes in the rolling window.
+    pub samples: usize,
+    /// Success rate over the window (None with too few or stale samples).
+    pub success_rate: Option<f64>,
+    /// Moving average connect latency to the address.
+    pub connect_latency: Option<Duration>,
+    /// Connect failures to the address since its last success.
+    pub consecutive_connect_failures: u32,
+    /// Failure counts per error code, most frequent first.
+    pub errors: Vec<(TpuErrorCode, u64)>,
+    /// Whether routing treats this leader as healthy.
+    pub healthy: bool,
+}
+
+/// Tracks send and connect health per leader identity and address.
+#[derive(Debug, Default)]
+pub struct LeaderHealth {
+    /// Send history by leader identity.
+    leaders: DashMap<String, LeaderStats>,
+    /// Connect history by TPU address.
+    addresses: DashMap<String, AddressStats>,
+}
+
+impl LeaderHealth {
+    /// Records the outcome of a send to a leader.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity` - Validator identity pubkey
+    /// * `address` - TPU address sent to
+    /// * `error_code` - `None` on success, otherwise the failure code
+    pub fn record_send(&self, identity: &str, address: &str, error_code: Option<TpuErrorCode>) {
+        let mut stats = self
+            .leaders
+            .entry(identity.to_string())
+            .or_insert_with(|| LeaderStats::new(address));
+
+        // Drop history that is too old to describe the leader's current state
+        if stats.updated.elapsed() > STALE_AFTER {
+            stats.outcomes.clear();
+        }
+
+        if stats.outcomes.len() == SEND_WINDOW {
+            stats.outcomes.pop_front();
+        }
+        stats.outcomes.push_back(error_code);
+        if stats.address != address {
+            stats.address = address.to_string();
+        }
+        stats.updated = Instant::now();
+    }
+
+    /// Records a successful connect to an address.
+    pub fn record_connect(&self, address: &str, latency: Duration) {
+        let mut stats = self
+            .addresses
+            .entry(address.to_string())
+            .or_insert_with(AddressStats::new);
+        let latency_ms = latency.as_secs_f64() * 1000.0;
+
+        stats.connect_latency_ms = Some(match stats.connect_latency_ms {
+            Some(avg) => avg + LATENCY_EWMA_ALPHA * (latency_ms - avg),
+            None => latency_ms,
+        });
+        stats.consecutive_failures = 0;
+        stats.last_failure = None;
+        stats.updated = Instant::now();
+    }
+
+    /// Records a failed connect to an address.
+    pub fn record_connect_failure(&self, address: &str) {
+        let mut stats = self
+            .addresses
+            .entry(address.to_string())
+            .or_insert_with(AddressStats::new);
+        stats.consecutive_failures += 1;
+        stats.last_failure = Some(Instant::now());
+        stats.updated = Instant::now();
+    }
+
+    /// Returns whether a leader is healthy enough to rely on alone.
+    ///
+    /// Leaders without enough recent history are assumed healthy.
+    pub fn is_healthy(&self, identity: &str) -> bool {
+        self.leaders
+            .get(identity)
+            .and_then(|stats| stats.success_rate())
+            .map(|rate| rate >= UNHEALTHY_SUCCESS_RATE)
+            .unwrap_or(true)
+    }
+
+    /// Returns whether routing should add leaders beyond `leaders`.
+    ///
+    /// True when the first (current) leader is unhealthy.
+    pub fn needs_extended_fanout(&self, leaders: &[LeaderInfo]) -> bool {
+        leaders
+            .first()
+            .map(|leader| !self.is_healthy(&leader.identity))
+            .unwrap_or(false)
+    }
+
+    /// Returns whether an address should be prewarmed.
+    ///
+    /// Addresses that keep failing to connect are skipped for a backoff
+    /// period, after which a single attempt is allowed again.
+    pub fn should_prewarm(&self, address: &str) -> bool {
+        match self.addresses.get(address) {
+            Some(stats) if stats.consecutive_failures >= PREWARM_FAILURE_THRESHOLD => stats
+                .last_failure
+                .map(|at| at.elapsed() >= PREWARM_BACKOFF)
+                .unwrap_or(true),
+            _ => true,
+        }
+    }
+
+    /// Forgets leaders and addresses without activity within the staleness
+    /// window, so identities rotating out of the schedule don't accumulate.
+    pub fn prune(&self) {
+        self.prune_older_than(STALE_AFTER);
+    }
+
+    /// Forgets leaders and addresses without activity within `max_age`.
+    fn prune_older_than(&self, max_age: Duration) {
+        self.leaders
+            .retain(|_, stats| stats.updated.elapsed() <= max_age);
+        self.addresses
+            .retain(|_, stats| stats.updated.elapsed() <= max_age);
+    }
+
+    /// Returns the number of leaders currently considered unhealthy.
+    pub fn unhealthy_count(&self) -> usize {
+        self.leaders
+            .iter()
+            .filter(|entry| {
+                entry
+                    .value()
+                    .success_rate()
+                    .map(|rate| rate < UNHEALTHY_SUCCESS_RATE)
+                    .unwrap_or(false)
+            })
+            .count()
+    }
+
+    /// Returns a health snapshot of every leader with send history.
+    pub fn snapshot(&self) -> Vec<LeaderHealthSnapshot> {
+        let mut snapshots: Vec<LeaderHealthSnapshot> = self
+            .leaders
+            .iter()
+            .map(|entry| {
+                let stats = entry.value();
+                let success_rate = stats.success_rate();
+                let address = self.addresses.get(&stats.address);
+
+                let mut errors: Vec<(TpuErrorCode, u64)> = stats.errors().into_iter().collect();
+                errors.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+
+                LeaderHealthSnapshot {
+                    identity: entry.key().clone(),
+                    address: stats.address.clone(),
+                    samples: stats.outcomes.len(),
+                    success_rate,
+                    connect_latency: address
+                        .as_ref()
+                        .and_then(|a| a.connect_latency_ms)
+                        .map(|ms| Duration::from_secs_f64(ms / 1000.0)),
+                    consecutive_connect_failures: address
+                        .as_ref()
+                        .map(|a| a.consecutive_failures)
+                        .unwrap_or(0),
+                    errors,
+                    healthy: success_rate
+                        .map(|rate| rate >= UNHEALTHY_SUCCESS_RATE)
+                        .unwrap_or(true),
+                }
+            })
+            .collect();
+
+        snapshots.sort_by(|a, b| a.identity.cmp(&b.identity));
+        snapshots
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leader(identity: &str) -> LeaderInfo {
+        LeaderInfo {
+            identity: identity.to_string(),
+            tpu_socket: "127.0.0.1:1000".to_string(),
+            slot: 0,
+        }
+    }
+
+    #[test]
+    fn test_leader_becomes_unhealthy_after_failures() {
+        let health = LeaderHealth::default();
+        for _ in 0..MIN_SAMPLES - 1 {
+            health.record_send("A", "127.0.0.1:1000", Some(TpuErrorCode::Timeout));
+        }
+        // Too few samples to judge
+        assert!(health.is_healthy("A"));
+
+        health.record_send("A", "127.0.0.1:1000", Some(TpuErrorCode::Timeout));
+        assert!(!health.is_healthy("A"));
+        assert!(health.needs_extended_fanout(&[leader("A"), leader("B")]));
+        assert!(!health.needs_extended_fanout(&[leader("B"), leader("A")]));
+        assert_eq!(health.unhealthy_count(), 1);
+
+        // Recovers once successes dominate the window
+        for _ in 0..MIN_SAMPLES {
+            health.record_send("A", "127.0.0.1:1000", None);
+        }
+        assert!(health.is_healthy("A"));
+
+        let snapshot = &health.snapshot()[0];
+        assert_eq!(snapshot.samples, MIN_SAMPLES * 2);
+        assert_eq!(snapshot.success_rate, Some(0.5));
+        assert_eq!(snapshot.errors, vec![(TpuErrorCode::Timeout, 4)]);
+    }
+
+    #[test]
+    fn test_error_counts_follow_the_window() {
+        let health = LeaderHealth::default();
+        for _ in 0..SEND_WINDOW {
+            health.record_send("A", "127.0.0.1:1000", Some(TpuErrorCode::Timeout));
+        }
+        for _ in 0..SEND_WINDOW / 2 {
+            health.record_send("A", "127.0.0.1:1000", Some(TpuErrorCode::RateLimited));
+        }
+        health.record_send("A", "127.0.0.1:1000", None);
+
+        // Outcomes leaving the window no longer count
+        let snapshot = &health.snapshot()[0];
+        assert_eq!(snapshot.samples, SEND_WINDOW);
+        assert_eq!(
+            snapshot.errors,
+            vec![
+                (TpuErrorCode::RateLimited, SEND_WINDOW as u64 / 2),
+                (TpuErrorCode::Timeout, SEND_WINDOW as u64 / 2 - 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stale_entries_are_pruned() {
+        let health = LeaderHealth::default();
+        health.record_send("A", "127.0.0.1:1000", None);
+        health.record_connect_failure("127.0.0.1:1000");
+
+        health.prune();
+        assert_eq!(health.snapshot().len(), 1);
+
+        std::thread::sleep(Duration::from_millis(5));
+        health.prune_older_than(Duration::from_millis(1));
+        assert!(health.snapshot().is_empty());
+        assert!(health.addresses.is_empty());
+    }
+
+    #[test]
+    fn test_failing_address_is_not_prewarmed() {
+        let health = LeaderHealth::default();
+        let address = "127.0.0.1:1000";
+
+        for _ in 0..PREWARM_FAILURE_THRESHOLD - 1 {
+            health.record_connect_failure(address);
+        }
+        assert!(health.should_prewarm(address));
+
+        health.record_connect_failure(address);
+        assert!(!health.should_prewarm(address));
+
+        health.record_connect(address, Duration::from_millis(20));
+        assert!(health.should_prewarm(address));
+    }
+
+    #[test]
+    fn test_connect_latency_is_smoothed() {
+        let health = LeaderHealth::default();
+        health.record_connect("127.0.0.1:1000", Duration::from_millis(100));
+        health.record_connect("127.0.0.1:1000", Duration::from_millis(200));
+        health.record_send("A", "127.0.0.1:1000", None);
+
+        let latency = health.snapshot()[0].connect_latency.unwrap();
+        assert!((latency.as_secs_f64() - 0.12).abs() < 1e-6);
+    }
+}
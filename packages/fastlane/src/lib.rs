@@ -6,11 +6,20 @@
 //! ## Features
 //!
 //! - Direct QUIC connections to validator TPU endpoints
-//! - Real-time leader schedule tracking
-//! - Connection pooling with 0-RTT support
+//! - Real-time leader schedule tracking, with an optional on-disk snapshot for warm starts
+//! - Slot consensus across WebSocket, gRPC and RPC sources, tolerating a lagging provider
+//! - Bounded connection pooling with 0-RTT support, LRU eviction and idle reaping
 //! - Pre-warming connections to upcoming leaders
+//! - Push-based confirmation via WebSocket signature subscriptions
+//! - Durable nonce transactions, ending when the nonce is advanced elsewhere
 //! - Per-leader send results with error classification
-//! - Internal retry with exponential backoff
+//! - Optional pre-flight validation of transaction bytes before sending
+//! - Internal retry with exponential backoff, jitter and per-error-code behavior
+//! - Configurable transport, retry and background task tuning
+//! - Per-leader health scoring with adaptive fanout and prewarming
+//! - Staked identity support for stake-weighted QoS
+//! - Prometheus metrics for sends, connections and slot tracking
+//! - Event subscriptions for slots, leader changes, epochs, sockets and sends
 //!
 //! ## Usage
 //!
@@ -26,17 +35,89 @@
 //! const result = await client.sendTransaction(serializedTxBuffer);
 //! // result.leaders contains per-validator send status
 //! ```
+//!
+//! ## Rust
+//!
+//! The crate also builds as an `rlib`. Depend on it with
+//! `default-features = false` to drop the NAPI bindings and use
+//! [`FastlaneClient`] directly:
+//!
+//! ```rust,no_run
+//! use pipeit_fastlane::{FastlaneClient, FastlaneConfig};
+//! use std::time::Duration;
+//!
+//! # async fn run(tx: Vec<u8>) -> Result<(), pipeit_fastlane::FastlaneError> {
+//! let client = FastlaneClient::new(FastlaneConfig::new(
+//!     "https://api.mainnet-beta.solana.com",
+//!     "wss://api.mainnet-beta.solana.com",
+//! ))
+//! .await?;
+//!
+//! client.wait_ready(Duration::from_secs(30)).await?;
+//! let outcome = client.send_until_confirmed(&tx, Duration::from_secs(30)).await?;
+//! println!("confirmed: {}", outcome.confirmed());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## CLI
+//!
+//! The `fastlane` binary sends transactions, lists upcoming leaders,
+//! probes validators and watches slots from the command line:
+//!
+//! ```text
+//! cargo run --no-default-features --features cli --bin fastlane -- help
+//! ```
+//!
+//! ## Relay
+//!
+//! The `fastlane-relay` binary serves `POST /send` and
+//! `POST /send-until-confirmed` over HTTP from one shared client, so many
+//! frontends reuse warm connections. See the `relay` module for routes and the
+//! binary for environment configuration:
+//!
+//! ```text
+//! SOLANA_RPC_URL=https://api.mainnet-beta.solana.com \
+//!     cargo run --release --no-default-features --features relay --bin fastlane-relay
+//! ```
 
 #![deny(clippy::all)]
 
+#[cfg(feature = "napi")]
 mod client;
+mod confirmation;
 mod connection_manager;
 mod errors;
+mod events;
+mod fastlane_client;
+mod identity;
+mod leader_health;
+mod metrics;
+#[cfg(feature = "relay")]
+pub mod relay;
+mod retry;
+#[cfg(test)]
+mod test_support;
 pub mod tracker;
+mod transaction;
 
 // Re-export main types
+#[cfg(feature = "napi")]
 pub use client::{LeaderSendResult, SendResult, TpuClient, TpuClientConfig, TpuClientStats};
-pub use connection_manager::{DeliveryResult, LeaderDeliveryResult, TpuConnectionManager};
-pub use errors::TpuErrorCode;
-pub use tracker::{LeaderInfo, LeaderTracker, ScheduleTracker, SlotEvent, SlotsTracker};
+pub use connection_manager::{
+    ConnectionTuning, DeliveryResult, LeaderDeliveryResult, PooledConnection, ProbeResult,
+    TpuConnectionManager, DEFAULT_MAX_CONNECTIONS,
+};
+pub use errors::{close_codes, FastlaneError, PreflightError, TpuError, TpuErrorCode};
+pub use events::{ClientEvent, EventBus, EVENT_CHANNEL_CAPACITY};
+pub use fastlane_client::{
+    ClientStats, ConfirmOutcome, ConfirmStatus, FastlaneClient, FastlaneConfig, SlotAwareRoute,
+};
+pub use identity::IdentityKeypair;
+pub use leader_health::{LeaderHealth, LeaderHealthSnapshot};
+pub use retry::RetryPolicy;
+pub use tracker::{
+    LeaderInfo, LeaderSource, LeaderTracker, ScheduleTracker, SlotConsensus, SlotEvent,
+    SlotSource, SlotSourceStats, SlotsTracker, StaticLeaderSource, TrackerSnapshot,
+};
 
@@ -0,0 +1,337 @@
+//! Client metrics with Prometheus text exposition.
+//!
+//! Metrics are plain atomics updated on the hot path and rendered on demand,
+//! so recording never blocks a send. The connection manager and leader
+//! tracker each own their metric set; `TpuClient::metrics()` renders both.
+
+use dashmap::DashMap;
+use std::fmt::Write;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Histogram bucket upper bounds in seconds, for network latencies.
+const LATENCY_BUCKETS: &[f64] = &[
+    0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5,
+];
+
+/// Monotonically increasing counter.
+#[derive(Debug, Default)]
+pub struct Counter(AtomicU64);
+
+impl Counter {
+    /// Increments the counter by one.
+    pub fn inc(&self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Increments the counter by `n`.
+    pub fn add(&self, n: u64) {
+        self.0.fetch_add(n, Ordering::Relaxed);
+    }
+
+    /// Returns the current value.
+    pub fn get(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Value that can go up and down.
+#[derive(Debug, Default)]
+pub struct Gauge(AtomicI64);
+
+impl Gauge {
+    /// Sets the gauge value.
+    pub fn set(&self, value: i64) {
+        self.0.store(value, Ordering::Relaxed);
+    }
+
+    /// Returns the current value.
+    pub fn get(&self) -> i64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Counters keyed by a single label value.
+#[derive(Debug, Default)]
+pub struct LabeledCounter(DashMap<String, u64>);
+
+impl LabeledCounter {
+    /// Increments the counter for a label value by one.
+    pub fn inc(&self, label: &str) {
+        // Avoid allocating the key when the label already exists
+        if let Some(mut count) = self.0.get_mut(label) {
+            *count += 1;
+            return;
+        }
+        *self.0.entry(label.to_string()).or_insert(0) += 1;
+    }
+
+    /// Returns the count for a label value.
+    pub fn get(&self, label: &str) -> u64 {
+        self.0.get(label).map(|count| *count).unwrap_or(0)
+    }
+
+    /// Returns all label values and counts, sorted by label.
+    fn snapshot(&self) -> Vec<(String, u64)> {
+        let mut entries: Vec<(String, u64)> = self
+            .0
+            .iter()
+            .map(|entry| (entry.key().clone(), *entry.value()))
+            .collect();
+        entries.sort();
+        entries
+    }
+}
+
+/// Latency histogram with fixed buckets.
+#[derive(Debug)]
+pub struct Histogram {
+    /// Non-cumulative count per bucket in `LATENCY_BUCKETS`, plus `+Inf`.
+    buckets: Vec<AtomicU64>,
+    /// Sum of observations in microseconds.
+    sum_micros: AtomicU64,
+    /// Number of observations.
+    count: AtomicU64,
+}
+
+impl Default for Histogram {
+    fn default() -> Self {
+        Self {
+            buckets: (0..=LATENCY_BUCKETS.len())
+                .map(|_| AtomicU64::new(0))
+                .collect(),
+            sum_micros: AtomicU64::new(0),
+            count: AtomicU64::new(0),
+        }
+    }
+}
+
+impl Histogram {
+    /// Records a duration.
+    pub fn observe(&self, duration: Duration) {
+        let secs = duration.as_secs_f64();
+        let bucket = LATENCY_BUCKETS
+            .iter()
+            .position(|bound| secs <= *bound)
+            .unwrap_or(LATENCY_BUCKETS.len());
+
+        self.buckets[bucket].fetch_add(1, Ordering::Relaxed);
+        self.sum_micros
+            .fetch_add(duration.as_micros() as u64, Ordering::Relaxed);
+        self.count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns the number of observations.
+    pub fn count(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+}
+
+/// Metrics recorded by the connection manager.
+#[derive(Debug, Default)]
+pub struct ConnectionMetrics {
+    /// Send attempts per leader identity (one per leader per send).
+    pub leader_sends: LabeledCounter,
+    /// Failed leader sends per `TpuErrorCode`.
+    pub send_failures: LabeledCounter,
+    /// Time to establish a new QUIC connection.
+    pub connect_latency: Histogram,
+    /// New connections that attempted 0-RTT.
+    pub zero_rtt_attempts: Counter,
+    /// 0-RTT attempts whose early data was accepted.
+    pub zero_rtt_accepted: Counter,
+    /// Time to open a unidirectional stream.
+    pub stream_open_latency: Histogram,
+    /// Connections evicted because the pool was full.
+    pub connection_evictions: Counter,
+    /// Closed, abandoned or idle pool entries removed by the reaper.
+    pub connections_reaped: Counter,
+}
+
+impl ConnectionMetrics {
+    /// Appends these metrics in Prometheus text format.
+    pub fn write_prometheus(&self, out: &mut String) {
+        write_labeled_counter(
+            out,
+            "fastlane_leader_sends_total",
+            "Transaction sends per leader.",
+            "leader",
+            &self.leader_sends,
+        );
+        write_labeled_counter(
+            out,
+            "fastlane_send_failures_total",
+            "Failed leader sends per error code.",
+            "code",
+            &self.send_failures,
+        );
+        write_histogram(
+            out,
+            "fastlane_connect_duration_seconds",
+            "Time to establish a new QUIC connection.",
+            &self.connect_latency,
+        );
+        write_counter(
+            out,
+            "fastlane_zero_rtt_attempts_total",
+            "New connections that attempted 0-RTT.",
+            &self.zero_rtt_attempts,
+        );
+        write_counter(
+            out,
+            "fastlane_zero_rtt_accepted_total",
+            "0-RTT attempts accepted by the validator.",
+            &self.zero_rtt_accepted,
+        );
+        write_histogram(
+            out,
+            "fastlane_stream_open_duration_seconds",
+            "Time to open a unidirectional stream.",
+            &self.stream_open_latency,
+        );
+        write_counter(
+            out,
+            "fastlane_connection_evictions_total",
+            "Connections evicted because the pool was full.",
+            &self.connection_evictions,
+        );
+        write_counter(
+            out,
+            "fastlane_connections_reaped_total",
+            "Closed, abandoned or idle pool entries removed.",
+            &self.connections_reaped,
+        );
+    }
+}
+
+/// Metrics recorded by the leader tracker.
+#[derive(Debug, Default)]
+pub struct TrackerMetrics {
+    /// RPC slot minus tracked slot at the last comparison.
+    pub slot_lag: Gauge,
+    /// Number of epoch schedule rotations.
+    pub epoch_rotations: Counter,
+}
+
+impl TrackerMetrics {
+    /// Appends these metrics in Prometheus text format.
+    pub fn write_prometheus(&self, out: &mut String) {
+        write_gauge(
+            out,
+            "fastlane_slot_lag_slots",
+            "RPC slot minus tracked slot at the last comparison.",
+            self.slot_lag.get(),
+        );
+        write_counter(
+            out,
+            "fastlane_epoch_rotations_total",
+            "Number of epoch schedule rotations.",
+            &self.epoch_rotations,
+        );
+    }
+}
+
+/// Appends a gauge with a single value.
+pub fn write_gauge(out: &mut String, name: &str, help: &str, value: i64) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} gauge");
+    let _ = writeln!(out, "{name} {value}");
+}
+
+/// Appends a counter with a single value.
+fn write_counter(out: &mut String, name: &str, help: &str, counter: &Counter) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} counter");
+    let _ = writeln!(out, "{name} {}", counter.get());
+}
+
+/// Appends a counter with one series per label value.
+fn write_labeled_counter(
+    out: &mut String,
+    name: &str,
+    help: &str,
+    label: &str,
+    counter: &LabeledCounter,
+) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} counter");
+    for (value, count) in counter.snapshot() {
+        let _ = writeln!(
+            out,
+            "{name}{{{label}=\"{}\"}} {count}",
+            escape_label(&value)
+        );
+    }
+}
+
+/// Appends a histogram with cumulative buckets.
+fn write_histogram(out: &mut String, name: &str, help: &str, histogram: &Histogram) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} histogram");
+
+    let mut cumulative = 0;
+    for (i, bucket) in histogram.buckets.iter().enumerate() {
+        cumulative += bucket.load(Ordering::Relaxed);
+        match LATENCY_BUCKETS.get(i) {
+            Some(bound) => {
+                let _ = writeln!(out, "{name}_bucket{{le=\"{bound}\"}} {cumulative}");
+            }
+            None => {
+                let _ = writeln!(out, "{name}_bucket{{le=\"+Inf\"}} {cumulative}");
+            }
+        }
+    }
+
+    let sum = histogram.sum_micros.load(Ordering::Relaxed) as f64 / 1_000_000.0;
+    let _ = writeln!(out, "{name}_sum {sum}");
+    let _ = writeln!(out, "{name}_count {}", histogram.count());
+}
+
+/// Escapes a label value per the Prometheus text format.
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_histogram_buckets_are_cumulative() {
+        let histogram = Histogram::default();
+        histogram.observe(Duration::from_micros(500));
+        histogram.observe(Duration::from_millis(20));
+        histogram.observe(Duration::from_secs(5));
+
+        let mut out = String::new();
+        write_histogram(&mut out, "h", "Test.", &histogram);
+
+        assert!(out.contains("h_bucket{le=\"0.001\"} 1\n"));
+        assert!(out.contains("h_bucket{le=\"0.025\"} 2\n"));
+        assert!(out.contains("h_bucket{le=\"2.5\"} 2\n"));
+        assert!(out.contains("h_bucket{le=\"+Inf\"} 3\n"));
+        assert!(out.contains("h_sum 5.0205\n"));
+        assert!(out.contains("h_count 3\n"));
+    }
+
+    #[test]
+    fn test_labeled_counter_output() {
+        let metrics = ConnectionMetrics::default();
+        metrics.send_failures.inc("TIMEOUT");
+        metrics.send_failures.inc("TIMEOUT");
+        metrics.send_failures.inc("RATE_LIMITED");
+        metrics.leader_sends.inc("we\"ird");
+
+        let mut out = String::new();
+        metrics.write_prometheus(&mut out);
+
+        assert!(out.contains("# TYPE fastlane_send_failures_total counter\n"));
+        assert!(out.contains("fastlane_send_failures_total{code=\"RATE_LIMITED\"} 1\n"));
+        assert!(out.contains("fastlane_send_failures_total{code=\"TIMEOUT\"} 2\n"));
+        assert!(out.contains("fastlane_leader_sends_total{leader=\"we\\\"ird\"} 1\n"));
+        assert_eq!(metrics.send_failures.get("TIMEOUT"), 2);
+    }
+}
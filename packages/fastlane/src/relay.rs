@@ -0,0 +1,553 @@
+//! HTTP/JSON relay in front of a shared `FastlaneClient`.
+//!
+//! Lets many frontends submit through one long-running process that owns
+//! the leader tracker and warm QUIC connections, instead of each serverless
+//! function loading the NAPI module on a cold start. Response bodies use the
+//! same camelCase shapes as the NAPI `SendResult`, `SendUntilConfirmedResult`
+//! and `TpuClientStats`.
+//!
+//! Routes:
+//! - `POST /send` - send once, returns `SendResult`
+//! - `POST /send-until-confirmed` - resend until confirmed, returns `SendUntilConfirmedResult`
+//! - `GET /health` - 200 when leader tracking is ready, 503 otherwise
+//! - `GET /stats` - returns `TpuClientStats`
+//! - `GET /metrics` - Prometheus text exposition
+//!
+//! When built with an auth token, the send routes require an
+//! `Authorization: Bearer <token>` header.
+
+use axum::extract::rejection::JsonRejection;
+use axum::extract::{Request, State};
+use axum::http::{header, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::connection_manager::{DeliveryResult, LeaderDeliveryResult};
+use crate::errors::FastlaneError;
+use crate::fastlane_client::{ConfirmOutcome, FastlaneClient};
+
+/// Default timeout for `/send-until-confirmed`, matching `tpuHandler`.
+const DEFAULT_CONFIRM_TIMEOUT_MS: u64 = 30_000;
+
+/// Upper bound on a caller-supplied confirmation timeout.
+const MAX_CONFIRM_TIMEOUT_MS: u64 = 120_000;
+
+/// Request body for the send routes.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelaySendRequest {
+    /// Base64-encoded signed transaction.
+    pub transaction: String,
+    /// Confirmation timeout for `/send-until-confirmed` (default: 30000,
+    /// must be positive, capped at 120000).
+    pub timeout_ms: Option<u64>,
+}
+
+/// Per-leader result, shaped like the NAPI `LeaderSendResult`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelayLeaderResult {
+    /// Validator identity pubkey.
+    pub identity: String,
+    /// TPU socket address.
+    pub address: String,
+    /// Whether send succeeded.
+    pub success: bool,
+    /// Latency for this leader in milliseconds.
+    pub latency_ms: u64,
+    /// Error message if failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Error code for programmatic handling.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
+    /// Number of attempts made for this leader.
+    pub attempts: usize,
+}
+
+/// Response for `/send`, shaped like the NAPI `SendResult`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelaySendResponse {
+    /// Whether the transaction was delivered to at least one leader.
+    pub delivered: bool,
+    /// Total latency in milliseconds.
+    pub latency_ms: u64,
+    /// Number of leaders the transaction was successfully sent to.
+    pub leader_count: usize,
+    /// Per-leader breakdown of send results.
+    pub leaders: Vec<RelayLeaderResult>,
+    /// Total retry attempts made across all leaders.
+    pub retry_count: usize,
+}
+
+/// Response for `/send-until-confirmed`, shaped like the NAPI
+/// `SendUntilConfirmedResult`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelayConfirmResponse {
+    /// Whether the transaction was confirmed on-chain.
+    pub confirmed: bool,
+    /// Transaction signature (base58).
+    pub signature: String,
+    /// Number of send rounds attempted.
+    pub rounds: u32,
+    /// Total number of leader sends across all rounds.
+    pub total_leaders_sent: u32,
+    /// Time taken in milliseconds.
+    pub latency_ms: u64,
+    /// Error message if not confirmed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Error code if not confirmed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
+}
+
+/// Response for `/stats`, shaped like the NAPI `TpuClientStats`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelayStatsResponse {
+    /// Number of active QUIC connections.
+    pub connection_count: usize,
+    /// Current estimated slot.
+    pub current_slot: u64,
+    /// Number of QUIC endpoints.
+    pub endpoint_count: usize,
+    /// "ready" or "initializing".
+    pub ready_state: String,
+    /// Seconds since the relay started.
+    pub uptime_secs: u64,
+    /// Number of validators with known sockets.
+    pub known_validators: usize,
+    /// Identity pubkey used for QUIC client certificates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity: Option<String>,
+    /// Maximum number of pooled connections.
+    pub max_connections: usize,
+    /// Pooled connections with last-use times, most recently used first.
+    pub connections: Vec<RelayPooledConnection>,
+    /// Per-source slot and lag against the consensus slot.
+    pub slot_sources: Vec<RelaySlotSource>,
+    /// Whether schedules and sockets were restored from a snapshot.
+    pub warm_start: bool,
+}
+
+/// Slot source health, shaped like the NAPI `SlotSourceInfo`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelaySlotSource {
+    /// Source label, e.g. "ws-0".
+    pub label: String,
+    /// Source kind: "ws", "grpc" or "rpc".
+    pub kind: String,
+    /// Last slot observed from this source (0 if none yet).
+    pub slot: u64,
+    /// Slots this source is behind the consensus slot (negative if ahead).
+    pub lag: i64,
+    /// Milliseconds since the source last updated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_update_ms: Option<u64>,
+    /// Whether the source stopped updating and is left out of consensus.
+    pub stale: bool,
+}
+
+/// Pooled connection, shaped like the NAPI `PooledConnectionInfo`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelayPooledConnection {
+    /// TPU socket address.
+    pub address: String,
+    /// Whether the connection is established and open.
+    pub open: bool,
+    /// Milliseconds since the connection was last used.
+    pub idle_ms: u64,
+    /// Unix timestamp of last use in milliseconds.
+    pub last_used_ms: u64,
+}
+
+/// Error body returned for failed requests.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RelayErrorBody {
+    error: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error_code: Option<String>,
+}
+
+/// Request failure mapped to an HTTP status.
+#[derive(Debug)]
+enum RelayError {
+    /// Malformed request body.
+    BadRequest(String),
+    /// Missing or wrong bearer token.
+    Unauthorized,
+    /// Client error from the send path.
+    Client(FastlaneError),
+}
+
+impl From<FastlaneError> for RelayError {
+    fn from(err: FastlaneError) -> Self {
+        RelayError::Client(err)
+    }
+}
+
+impl From<JsonRejection> for RelayError {
+    fn from(rejection: JsonRejection) -> Self {
+        RelayError::BadRequest(rejection.body_text())
+    }
+}
+
+impl IntoResponse for RelayError {
+    fn into_response(self) -> Response {
+        let (status, error, error_code) = match self {
+            RelayError::BadRequest(message) => (StatusCode::BAD_REQUEST, message, None),
+            RelayError::Unauthorized => (
+                StatusCode::UNAUTHORIZED,
+                "Missing or invalid bearer token".to_string(),
+                None,
+            ),
+            RelayError::Client(err) => {
+                let status = match err {
+                    FastlaneError::InvalidTransaction(_) | FastlaneError::Preflight(_) => {
+                        StatusCode::BAD_REQUEST
+                    }
+                    FastlaneError::NoLeaders | FastlaneError::NotReady => {
+                        StatusCode::SERVICE_UNAVAILABLE
+                    }
+                    _ => StatusCode::INTERNAL_SERVER_ERROR,
+                };
+                (status, err.to_string(), err.code())
+            }
+        };
+
+        let body = RelayErrorBody {
+            error,
+            error_code: error_code.map(|code| code.to_string()),
+        };
+        (status, Json(body)).into_response()
+    }
+}
+
+impl From<LeaderDeliveryResult> for RelayLeaderResult {
+    fn from(lr: LeaderDeliveryResult) -> Self {
+        Self {
+            identity: lr.identity,
+            address: lr.address,
+            success: lr.success,
+            latency_ms: lr.latency_ms,
+            error: lr.error,
+            error_code: lr.error_code.map(|code| code.to_string()),
+            attempts: lr.attempts,
+        }
+    }
+}
+
+impl From<DeliveryResult> for RelaySendResponse {
+    fn from(result: DeliveryResult) -> Self {
+        Self {
+            delivered: result.delivered,
+            latency_ms: result.latency_ms,
+            leader_count: result.leader_count,
+            leaders: result.leaders.into_iter().map(Into::into).collect(),
+            retry_count: result.total_retries,
+        }
+    }
+}
+
+impl RelayConfirmResponse {
+    /// Converts a native outcome, formatting errors for unconfirmed sends.
+    fn from_outcome(outcome: ConfirmOutcome, timeout: Duration) -> Self {
+        Self {
+            confirmed: outcome.confirmed(),
+            signature: outcome.signature.to_string(),
+            rounds: outcome.rounds,
+            total_leaders_sent: outcome.total_leaders_sent,
+            latency_ms: outcome.latency.as_millis() as u64,
+            error: outcome.error_message(timeout),
+            error_code: outcome.error_code().map(|code| code.to_string()),
+        }
+    }
+}
+
+/// Builds the relay router over a shared client.
+///
+/// # Arguments
+///
+/// * `client` - Client shared by all requests
+/// * `auth_token` - Bearer token required on the send routes, if any
+pub fn router(client: Arc<FastlaneClient>, auth_token: Option<String>) -> Router {
+    let mut send_routes = Router::new()
+        .route("/send", post(send))
+        .route("/send-until-confirmed", post(send_until_confirmed));
+    if let Some(token) = auth_token {
+        send_routes = send_routes.route_layer(middleware::from_fn_with_state(
+            Arc::<str>::from(token),
+            require_token,
+        ));
+    }
+
+    send_routes
+        .route("/health", get(health))
+        .route("/stats", get(stats))
+        .route("/metrics", get(metrics))
+        .with_state(client)
+}
+
+/// Rejects requests without the expected bearer token.
+async fn require_token(
+    State(token): State<Arc<str>>,
+    request: Request,
+    next: Next,
+) -> Result<Response, RelayError> {
+    let provided = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .unwrap_or_default();
+    if !constant_time_eq(provided.as_bytes(), token.as_bytes()) {
+        return Err(RelayError::Unauthorized);
+    }
+    Ok(next.run(request).await)
+}
+
+/// Compares two byte strings without exiting early on the first mismatch.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Decodes the base64 transaction from a request body.
+fn decode_transaction(request: &RelaySendRequest) -> Result<Vec<u8>, RelayError> {
+    base64::engine::general_purpose::STANDARD
+        .decode(request.transaction.trim())
+        .map_err(|e| RelayError::BadRequest(format!("Invalid base64 transaction: {}", e)))
+}
+
+/// `POST /send`
+async fn send(
+    State(client): State<Arc<FastlaneClient>>,
+    request: Result<Json<RelaySendRequest>, JsonRejection>,
+) -> Result<Json<RelaySendResponse>, RelayError> {
+    let Json(request) = request?;
+    let tx_data = decode_transaction(&request)?;
+
+    let result = client.send_transaction(&tx_data).await?;
+    Ok(Json(result.into()))
+}
+
+/// `POST /send-until-confirmed`
+async fn send_until_confirmed(
+    State(client): State<Arc<FastlaneClient>>,
+    request: Result<Json<RelaySendRequest>, JsonRejection>,
+) -> Result<Json<RelayConfirmResponse>, RelayError> {
+    let Json(request) = request?;
+    let tx_data = decode_transaction(&request)?;
+    let timeout_ms = request.timeout_ms.unwrap_or(DEFAULT_CONFIRM_TIMEOUT_MS);
+    if timeout_ms == 0 {
+        return Err(RelayError::BadRequest(
+            "timeoutMs must be positive".to_string(),
+        ));
+    }
+    let timeout = Duration::from_millis(timeout_ms.min(MAX_CONFIRM_TIMEOUT_MS));
+
+    let outcome = client.send_until_confirmed(&tx_data, timeout).await?;
+    Ok(Json(RelayConfirmResponse::from_outcome(outcome, timeout)))
+}
+
+/// `GET /health`
+async fn health(State(client): State<Arc<FastlaneClient>>) -> Response {
+    let stats = client.stats().await;
+    let (status, ready_state) = if stats.ready {
+        (StatusCode::OK, "ready")
+    } else {
+        (StatusCode::SERVICE_UNAVAILABLE, "initializing")
+    };
+
+    let body = serde_json::json!({
+        "readyState": ready_state,
+        "currentSlot": stats.current_slot,
+        "knownValidators": stats.known_validators,
+    });
+    (status, Json(body)).into_response()
+}
+
+/// `GET /stats`
+async fn stats(State(client): State<Arc<FastlaneClient>>) -> Json<RelayStatsResponse> {
+    let stats = client.stats().await;
+
+    Json(RelayStatsResponse {
+        connection_count: stats.connection_count,
+        current_slot: stats.current_slot,
+        endpoint_count: stats.endpoint_count,
+        ready_state: if stats.ready {
+            "ready".to_string()
+        } else {
+            "initializing".to_string()
+        },
+        uptime_secs: stats.uptime.as_secs(),
+        known_validators: stats.known_validators,
+        identity: stats.identity,
+        max_connections: stats.max_connections,
+        connections: stats
+            .connections
+            .into_iter()
+            .map(|conn| RelayPooledConnection {
+                address: conn.address,
+                open: conn.open,
+                idle_ms: conn.idle.as_millis() as u64,
+                last_used_ms: conn
+                    .last_used
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map(|since| since.as_millis() as u64)
+                    .unwrap_or(0),
+            })
+            .collect(),
+        slot_sources: stats
+            .slot_sources
+            .into_iter()
+            .map(|source| RelaySlotSource {
+                label: source.label,
+                kind: source.kind.to_string(),
+                slot: source.slot,
+                lag: source.lag,
+                last_update_ms: source.since_update.map(|since| since.as_millis() as u64),
+                stale: source.stale,
+            })
+            .collect(),
+        warm_start: stats.warm_start,
+    })
+}
+
+/// `GET /metrics`
+async fn metrics(State(client): State<Arc<FastlaneClient>>) -> Response {
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        client.metrics().await,
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fastlane_client::FastlaneConfig;
+    use crate::test_support::{MockBehavior, MockTpuServer};
+    use crate::tracker::StaticLeaderSource;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    async fn relay(leaders: Vec<(String, String)>) -> Router {
+        relay_with_token(leaders, None).await
+    }
+
+    async fn relay_with_token(leaders: Vec<(String, String)>, token: Option<&str>) -> Router {
+        let mut config = FastlaneConfig::new("http://127.0.0.1:1", "ws://127.0.0.1:1");
+        config.prewarm_connections = false;
+        let source = Arc::new(StaticLeaderSource::new(leaders, 100));
+
+        let client = FastlaneClient::with_leader_source(config, source)
+            .await
+            .unwrap();
+        router(Arc::new(client), token.map(str::to_string))
+    }
+
+    async fn post_json(router: Router, uri: &str, body: &str) -> (StatusCode, serde_json::Value) {
+        post_json_with_token(router, uri, body, None).await
+    }
+
+    async fn post_json_with_token(
+        router: Router,
+        uri: &str,
+        body: &str,
+        token: Option<&str>,
+    ) -> (StatusCode, serde_json::Value) {
+        let mut request = Request::post(uri).header(header::CONTENT_TYPE, "application/json");
+        if let Some(token) = token {
+            request = request.header(header::AUTHORIZATION, format!("Bearer {}", token));
+        }
+        let request = request.body(Body::from(body.to_string())).unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_send_returns_send_result_shape() {
+        let server = MockTpuServer::start(MockBehavior::Accept).await;
+        let router = relay(vec![("pinned-leader".to_string(), server.address())]).await;
+
+        let tx = base64::engine::general_purpose::STANDARD.encode(b"relayed-tx");
+        let (status, body) =
+            post_json(router, "/send", &format!(r#"{{"transaction":"{}"}}"#, tx)).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["delivered"], true);
+        assert_eq!(body["leaderCount"], 1);
+        assert_eq!(body["leaders"][0]["identity"], "pinned-leader");
+        assert!(body["leaders"][0].get("errorCode").is_none());
+        assert_eq!(
+            server
+                .wait_for_transactions(1, Duration::from_secs(2))
+                .await,
+            vec![b"relayed-tx".to_vec()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_errors_map_to_status_codes() {
+        let router = relay(vec![]).await;
+
+        let (status, body) = post_json(router.clone(), "/send", r#"{"transaction":"!!"}"#).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body["error"].as_str().unwrap().contains("base64"));
+
+        let (status, _) = post_json(router.clone(), "/send", r#"{"tx":"AAAA"}"#).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, body) = post_json(router.clone(), "/send", r#"{"transaction":"AAAA"}"#).await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["errorCode"], "NO_LEADERS");
+
+        let (status, body) = post_json(
+            router,
+            "/send-until-confirmed",
+            r#"{"transaction":"AAAA","timeoutMs":0}"#,
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body["error"].as_str().unwrap().contains("timeoutMs"));
+    }
+
+    #[tokio::test]
+    async fn test_send_routes_require_token_when_configured() {
+        let router = relay_with_token(vec![], Some("secret")).await;
+        let body = r#"{"transaction":"AAAA"}"#;
+
+        for token in [None, Some("wrong")] {
+            let (status, _) = post_json_with_token(router.clone(), "/send", body, token).await;
+            assert_eq!(status, StatusCode::UNAUTHORIZED);
+            let (status, _) =
+                post_json_with_token(router.clone(), "/send-until-confirmed", body, token).await;
+            assert_eq!(status, StatusCode::UNAUTHORIZED);
+        }
+
+        let (status, _) = post_json_with_token(router.clone(), "/send", body, Some("secret")).await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+
+        // Health checks stay open
+        let request = Request::get("/health").body(Body::empty()).unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        assert_ne!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+}
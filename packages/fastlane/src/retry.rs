@@ -0,0 +1,291 @@
+//! Per-leader retry policy.
+//!
+//! Decides whether a failed send to a leader is retried, how long to wait
+//! first and whether the retry needs a fresh connection. Delays grow
+//! exponentially with random jitter so many clients retrying the same
+//! validator don't synchronize, and rate limiting backs off further.
+
+use anyhow::{anyhow, Result};
+use rand::Rng;
+use std::time::Duration;
+
+use crate::errors::TpuErrorCode;
+
+/// Default maximum send attempts per leader.
+const MAX_SEND_ATTEMPTS: usize = 3;
+
+/// Default delay before the first retry in milliseconds.
+const RETRY_DELAY_MS: u64 = 50;
+
+/// Default cap on a single retry delay in milliseconds.
+const MAX_RETRY_DELAY_MS: u64 = 400;
+
+/// Default growth factor applied per retry.
+const BACKOFF_MULTIPLIER: f64 = 2.0;
+
+/// Default fraction of each delay that is randomized.
+const JITTER: f64 = 0.2;
+
+/// Default extra backoff factor after the validator rate limited us.
+const RATE_LIMITED_FACTOR: f64 = 4.0;
+
+/// Largest accepted rate limit backoff factor.
+const MAX_RATE_LIMITED_FACTOR: f64 = 100.0;
+
+/// Retry policy with exponential backoff, jitter and per-error-code behavior.
+///
+/// - `RateLimited` waits `rate_limited_factor` times longer.
+/// - `StreamClosed` and `ConnectionFailed` retry on a fresh connection.
+/// - Other retryable codes (e.g. `Timeout`) reuse the cached connection.
+/// - Codes that are not retryable stop immediately.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    /// Maximum send attempts per leader, including the first.
+    pub max_attempts: usize,
+    /// Delay before the first retry.
+    pub base_delay: Duration,
+    /// Cap on a single delay (before the rate limit factor).
+    pub max_delay: Duration,
+    /// Growth factor applied per retry (1.0 keeps the delay fixed).
+    pub multiplier: f64,
+    /// Fraction of each delay that is randomized (0.0 to 1.0).
+    pub jitter: f64,
+    /// Extra backoff factor applied after a `RateLimited` error.
+    pub rate_limited_factor: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::exponential()
+    }
+}
+
+impl RetryPolicy {
+    /// Exponential backoff with jitter (the default).
+    pub fn exponential() -> Self {
+        Self {
+            max_attempts: MAX_SEND_ATTEMPTS,
+            base_delay: Duration::from_millis(RETRY_DELAY_MS),
+            max_delay: Duration::from_millis(MAX_RETRY_DELAY_MS),
+            multiplier: BACKOFF_MULTIPLIER,
+            jitter: JITTER,
+            rate_limited_factor: RATE_LIMITED_FACTOR,
+        }
+    }
+
+    /// Fixed delay between attempts, without jitter.
+    ///
+    /// Per-error-code behavior still applies, including the longer
+    /// backoff after rate limiting.
+    pub fn fixed(max_attempts: usize, delay: Duration) -> Self {
+        Self {
+            max_attempts,
+            base_delay: delay,
+            max_delay: delay,
+            multiplier: 1.0,
+            jitter: 0.0,
+            rate_limited_factor: RATE_LIMITED_FACTOR,
+        }
+    }
+
+    /// A single attempt per leader.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::exponential()
+        }
+    }
+
+    /// Sets the delay before the first retry.
+    ///
+    /// `max_delay` is scaled along with it, keeping the number of retries
+    /// before the cap is reached.
+    pub fn with_base_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = if self.base_delay.is_zero() {
+            self.max_delay.max(delay)
+        } else {
+            delay.mul_f64(self.max_delay.as_secs_f64() / self.base_delay.as_secs_f64())
+        };
+        self.base_delay = delay;
+        self
+    }
+
+    /// Checks that every value is usable.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the first invalid field.
+    pub fn validate(&self) -> Result<()> {
+        if self.max_attempts == 0 {
+            return Err(anyhow!("max_attempts must be at least 1"));
+        }
+        if self.max_delay < self.base_delay {
+            return Err(anyhow!("max_delay must not be below base_delay"));
+        }
+        if !(self.multiplier >= 1.0 && self.multiplier.is_finite()) {
+            return Err(anyhow!("multiplier must be at least 1.0"));
+        }
+        if !(0.0..=1.0).contains(&self.jitter) {
+            return Err(anyhow!("jitter must be between 0.0 and 1.0"));
+        }
+        if !(1.0..=MAX_RATE_LIMITED_FACTOR).contains(&self.rate_limited_factor) {
+            return Err(anyhow!(
+                "rate_limited_factor must be between 1.0 and {}",
+                MAX_RATE_LIMITED_FACTOR
+            ));
+        }
+        Ok(())
+    }
+
+    /// Decides whether to retry after a failed attempt, and after how long.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - Error code of the failed attempt
+    /// * `attempt` - Zero-based index of the failed attempt
+    ///
+    /// # Returns
+    ///
+    /// The delay before the next attempt, or `None` when the error is not
+    /// retryable or no attempts are left.
+    pub fn next_delay(&self, code: TpuErrorCode, attempt: usize) -> Option<Duration> {
+        if !code.is_retryable() || attempt + 1 >= self.max_attempts {
+            return None;
+        }
+        Some(self.delay(code, attempt))
+    }
+
+    /// Returns the jittered delay before retrying a failed attempt.
+    fn delay(&self, code: TpuErrorCode, attempt: usize) -> Duration {
+        let mut delay = self.backoff(code, attempt);
+        if self.jitter > 0.0 {
+            let scale = 1.0 - self.jitter * rand::thread_rng().gen::<f64>();
+            delay = delay.mul_f64(scale);
+        }
+        delay
+    }
+
+    /// Returns the delay before jitter is applied.
+    fn backoff(&self, code: TpuErrorCode, attempt: usize) -> Duration {
+        let exponent = i32::try_from(attempt).unwrap_or(i32::MAX);
+        let backoff = self.base_delay.as_secs_f64() * self.multiplier.powi(exponent);
+        let mut delay = Duration::try_from_secs_f64(backoff.min(self.max_delay.as_secs_f64()))
+            .unwrap_or(self.max_delay);
+        if code == TpuErrorCode::RateLimited {
+            delay = Duration::try_from_secs_f64(delay.as_secs_f64() * self.rate_limited_factor)
+                .unwrap_or(Duration::MAX);
+        }
+        delay
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_grows_and_is_capped() {
+        let policy = RetryPolicy {
+            max_attempts: 10,
+            ..RetryPolicy::exponential()
+        };
+
+        let delays: Vec<u128> = (0..5)
+            .map(|attempt| policy.backoff(TpuErrorCode::Timeout, attempt).as_millis())
+            .collect();
+        assert_eq!(delays, [50, 100, 200, 400, 400]);
+
+        assert_eq!(
+            policy.backoff(TpuErrorCode::RateLimited, 0),
+            Duration::from_millis(200)
+        );
+    }
+
+    #[test]
+    fn test_jitter_stays_within_bounds() {
+        let policy = RetryPolicy::exponential();
+        for _ in 0..100 {
+            let delay = policy.delay(TpuErrorCode::Timeout, 1);
+            assert!(delay <= Duration::from_millis(100));
+            assert!(delay >= Duration::from_millis(80));
+        }
+    }
+
+    #[test]
+    fn test_per_code_behavior() {
+        let policy = RetryPolicy::fixed(3, Duration::from_millis(10));
+
+        assert_eq!(
+            policy.next_delay(TpuErrorCode::Timeout, 0),
+            Some(Duration::from_millis(10))
+        );
+        assert_eq!(
+            policy.next_delay(TpuErrorCode::RateLimited, 0),
+            Some(Duration::from_millis(40))
+        );
+        assert!(!TpuErrorCode::Timeout.requires_reconnect());
+        assert!(TpuErrorCode::StreamClosed.requires_reconnect());
+
+        // Not retryable, or out of attempts
+        assert!(policy.next_delay(TpuErrorCode::InvalidAddress, 0).is_none());
+        assert!(policy.next_delay(TpuErrorCode::Timeout, 2).is_none());
+        assert!(RetryPolicy::none()
+            .next_delay(TpuErrorCode::Timeout, 0)
+            .is_none());
+    }
+
+    #[test]
+    fn test_base_delay_scales_max_delay() {
+        let policy = RetryPolicy::exponential().with_base_delay(Duration::from_millis(500));
+        assert_eq!(policy.base_delay, Duration::from_millis(500));
+        assert_eq!(policy.max_delay, Duration::from_millis(4_000));
+        assert_eq!(
+            policy.backoff(TpuErrorCode::Timeout, 1),
+            Duration::from_millis(1_000)
+        );
+
+        let fixed = RetryPolicy::fixed(3, Duration::from_millis(10))
+            .with_base_delay(Duration::from_millis(30));
+        assert_eq!(fixed.max_delay, Duration::from_millis(30));
+    }
+
+    #[test]
+    fn test_invalid_policy_is_rejected() {
+        assert!(RetryPolicy::default().validate().is_ok());
+        assert!(RetryPolicy::fixed(0, Duration::ZERO).validate().is_err());
+        assert!(RetryPolicy {
+            jitter: 1.5,
+            ..RetryPolicy::default()
+        }
+        .validate()
+        .is_err());
+        assert!(RetryPolicy {
+            multiplier: 0.5,
+            ..RetryPolicy::default()
+        }
+        .validate()
+        .is_err());
+        assert!(RetryPolicy {
+            rate_limited_factor: 1e300,
+            ..RetryPolicy::default()
+        }
+        .validate()
+        .is_err());
+        assert!(RetryPolicy {
+            rate_limited_factor: MAX_RATE_LIMITED_FACTOR,
+            ..RetryPolicy::default()
+        }
+        .validate()
+        .is_ok());
+    }
+
+    #[test]
+    fn test_rate_limited_backoff_saturates() {
+        let policy = RetryPolicy {
+            max_delay: Duration::MAX,
+            multiplier: f64::MAX,
+            ..RetryPolicy::default()
+        };
+        assert_eq!(policy.backoff(TpuErrorCode::RateLimited, 1), Duration::MAX);
+    }
+}
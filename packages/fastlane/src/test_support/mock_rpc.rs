@@ -0,0 +1,131 @@
+//! In-process mock JSON-RPC server.
+//!
+//! Answers HTTP JSON-RPC requests with canned results keyed by method
+//! name, so RPC-driven send logic (expiry checks, confirmation polling)
+//! can be exercised without a live node. Unknown methods get a JSON-RPC
+//! error, which the client treats like a flaky node.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+/// Mock JSON-RPC server bound to a local TCP port.
+pub struct MockRpcServer {
+    /// Local address the server listens on.
+    addr: SocketAddr,
+    /// Number of requests received per method.
+    calls: Arc<Mutex<HashMap<String, usize>>>,
+    /// Accept loop task.
+    task: JoinHandle<()>,
+}
+
+impl MockRpcServer {
+    /// Starts a mock server on `127.0.0.1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `results` - Raw JSON `result` value to answer each method with
+    pub async fn start(results: &[(&str, &str)]) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Failed to bind mock RPC server");
+        let addr = listener.local_addr().unwrap();
+
+        let results: Arc<HashMap<String, String>> = Arc::new(
+            results
+                .iter()
+                .map(|(method, result)| (method.to_string(), result.to_string()))
+                .collect(),
+        );
+        let calls = Arc::new(Mutex::new(HashMap::new()));
+
+        let task = {
+            let calls = calls.clone();
+            tokio::spawn(async move {
+                while let Ok((stream, _)) = listener.accept().await {
+                    tokio::spawn(serve(stream, results.clone(), calls.clone()));
+                }
+            })
+        };
+
+        Self { addr, calls, task }
+    }
+
+    /// HTTP URL of the server.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Number of requests received for `method`.
+    pub fn calls(&self, method: &str) -> usize {
+        self.calls.lock().unwrap().get(method).copied().unwrap_or(0)
+    }
+}
+
+impl Drop for MockRpcServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Answers requests on one keep-alive connection until it closes.
+async fn serve(
+    stream: TcpStream,
+    results: Arc<HashMap<String, String>>,
+    calls: Arc<Mutex<HashMap<String, usize>>>,
+) {
+    let mut stream = BufReader::new(stream);
+    loop {
+        let mut content_length = 0;
+        let mut line = String::new();
+        loop {
+            line.clear();
+            match stream.read_line(&mut line).await {
+                Ok(0) | Err(_) => return,
+                Ok(_) => {}
+            }
+            let header = line.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap_or(0);
+                }
+            }
+        }
+
+        let mut body = vec![0u8; content_length];
+        if stream.read_exact(&mut body).await.is_err() {
+            return;
+        }
+        let method = method_name(&String::from_utf8_lossy(&body)).unwrap_or_default();
+        *calls.lock().unwrap().entry(method.clone()).or_insert(0) += 1;
+
+        let response = match results.get(&method) {
+            Some(result) => format!(r#"{{"jsonrpc":"2.0","id":1,"result":{}}}"#, result),
+            None => {
+                r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32601,"message":"Method not found"}}"#
+                    .to_string()
+            }
+        };
+        let reply = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+            response.len(),
+            response
+        );
+        if stream.get_mut().write_all(reply.as_bytes()).await.is_err() {
+            return;
+        }
+    }
+}
+
+/// Extracts the `method` field from a JSON-RPC request body.
+fn method_name(body: &str) -> Option<String> {
+    let rest = &body[body.find(r#""method":"#)? + r#""method":"#.len()..];
+    let rest = rest.trim_start().strip_prefix('"')?;
+    Some(rest[..rest.find('"')?].to_string())
+}
@@ -0,0 +1,190 @@
+//! In-process mock TPU QUIC server.
+//!
+//! Speaks the `solana-tpu` ALPN and records every transaction received on
+//! a unidirectional stream. Behavior can be configured to reproduce the
+//! failure modes seen from real validators (refused connections, close
+//! codes, stopped streams, stream throttling, stalled handshakes).
+
+use quinn::{
+    crypto::rustls::QuicServerConfig, Endpoint, Incoming, ServerConfig, TransportConfig, VarInt,
+};
+use solana_sdk::signature::Keypair;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+
+/// ALPN protocol identifier for Solana TPU.
+const ALPN_TPU_PROTOCOL_ID: &[u8] = b"solana-tpu";
+
+/// How the mock server responds to incoming connections.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MockBehavior {
+    /// Accept connections and record all transactions.
+    Accept,
+    /// Refuse every connection attempt.
+    Refuse,
+    /// Accept the handshake, then close the connection with an application code.
+    CloseWith(u32),
+    /// Accept connections but stop every incoming stream with this code.
+    StopStreamsWith(u32),
+    /// Accept connections but allow only this many concurrent uni streams,
+    /// reading each stream slowly.
+    Throttle(u32),
+    /// Never respond to connection attempts (handshake stalls).
+    Stall,
+}
+
+/// Mock TPU server bound to a local UDP port.
+pub struct MockTpuServer {
+    /// Server endpoint.
+    endpoint: Endpoint,
+    /// Local address the server listens on.
+    addr: SocketAddr,
+    /// Transactions received, in arrival order.
+    received: Arc<Mutex<Vec<Vec<u8>>>>,
+    /// Number of connections whose handshake completed.
+    connections: Arc<AtomicUsize>,
+    /// Accept loop task.
+    task: JoinHandle<()>,
+}
+
+impl MockTpuServer {
+    /// Starts a mock server on `127.0.0.1` with the given behavior.
+    pub async fn start(behavior: MockBehavior) -> Self {
+        let endpoint = Endpoint::server(server_config(behavior), "127.0.0.1:0".parse().unwrap())
+            .expect("Failed to bind mock TPU server");
+        let addr = endpoint.local_addr().unwrap();
+
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let connections = Arc::new(AtomicUsize::new(0));
+
+        let task = tokio::spawn(accept_loop(
+            endpoint.clone(),
+            behavior,
+            received.clone(),
+            connections.clone(),
+        ));
+
+        Self {
+            endpoint,
+            addr,
+            received,
+            connections,
+            task,
+        }
+    }
+
+    /// Returns the server address as `ip:port`.
+    pub fn address(&self) -> String {
+        self.addr.to_string()
+    }
+
+    /// Returns all transactions received so far.
+    pub fn received(&self) -> Vec<Vec<u8>> {
+        self.received.lock().unwrap().clone()
+    }
+
+    /// Returns the number of completed handshakes.
+    pub fn connection_count(&self) -> usize {
+        self.connections.load(Ordering::Relaxed)
+    }
+
+    /// Waits until at least `count` transactions were received or `timeout` elapses.
+    pub async fn wait_for_transactions(&self, count: usize, timeout: Duration) -> Vec<Vec<u8>> {
+        let start = Instant::now();
+        while self.received.lock().unwrap().len() < count && start.elapsed() < timeout {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        self.received()
+    }
+}
+
+impl Drop for MockTpuServer {
+    fn drop(&mut self) {
+        self.task.abort();
+        self.endpoint.close(0u32.into(), b"shutdown");
+    }
+}
+
+/// Builds a server config with a throwaway certificate and the TPU ALPN.
+fn server_config(behavior: MockBehavior) -> ServerConfig {
+    let (certificate, key) = solana_tls_utils::new_dummy_x509_certificate(&Keypair::new());
+
+    let mut crypto = solana_tls_utils::tls_server_config_builder()
+        .with_single_cert(vec![certificate], key)
+        .expect("Failed to build mock TPU TLS config");
+    crypto.alpn_protocols = vec![ALPN_TPU_PROTOCOL_ID.to_vec()];
+
+    let mut config = ServerConfig::with_crypto(Arc::new(
+        QuicServerConfig::try_from(crypto).expect("Failed to build mock QUIC config"),
+    ));
+
+    if let MockBehavior::Throttle(max_streams) = behavior {
+        let mut transport = TransportConfig::default();
+        transport.max_concurrent_uni_streams(max_streams.into());
+        config.transport_config(Arc::new(transport));
+    }
+
+    config
+}
+
+/// Accepts incoming connections according to the configured behavior.
+async fn accept_loop(
+    endpoint: Endpoint,
+    behavior: MockBehavior,
+    received: Arc<Mutex<Vec<Vec<u8>>>>,
+    connections: Arc<AtomicUsize>,
+) {
+    // Stalled handshakes are kept alive here so they are never answered
+    let mut stalled: Vec<Incoming> = Vec::new();
+
+    while let Some(incoming) = endpoint.accept().await {
+        match behavior {
+            MockBehavior::Refuse => incoming.refuse(),
+            MockBehavior::Stall => stalled.push(incoming),
+            _ => {
+                let received = received.clone();
+                let connections = connections.clone();
+                tokio::spawn(async move {
+                    let _ = handle_connection(incoming, behavior, received, connections).await;
+                });
+            }
+        }
+    }
+}
+
+/// Handles one accepted connection, reading transactions from uni streams.
+async fn handle_connection(
+    incoming: Incoming,
+    behavior: MockBehavior,
+    received: Arc<Mutex<Vec<Vec<u8>>>>,
+    connections: Arc<AtomicUsize>,
+) -> anyhow::Result<()> {
+    let connection = incoming.await?;
+    connections.fetch_add(1, Ordering::Relaxed);
+
+    if let MockBehavior::CloseWith(code) = behavior {
+        connection.close(VarInt::from_u32(code), b"mock close");
+        return Ok(());
+    }
+
+    loop {
+        let mut stream = connection.accept_uni().await?;
+        if let MockBehavior::StopStreamsWith(code) = behavior {
+            let _ = stream.stop(VarInt::from_u32(code));
+            continue;
+        }
+        let received = received.clone();
+
+        tokio::spawn(async move {
+            if let MockBehavior::Throttle(_) = behavior {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+            if let Ok(data) = stream.read_to_end(usize::MAX).await {
+                received.lock().unwrap().push(data);
+            }
+        });
+    }
+}
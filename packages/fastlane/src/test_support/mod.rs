@@ -0,0 +1,10 @@
+//! Test-only support utilities.
+//!
+//! Provides in-process fakes so connection and routing logic can be
+//! exercised offline, without a live cluster or RPC node.
+
+mod mock_rpc;
+mod mock_tpu;
+
+pub use mock_rpc::MockRpcServer;
+pub use mock_tpu::{MockBehavior, MockTpuServer};
@@ -0,0 +1,243 @@
+//! Pluggable leader sources for transaction routing.
+//!
+//! `LeaderTracker` is the live implementation backed by RPC and slot
+//! subscriptions. `StaticLeaderSource` serves a fixed, scriptable rotation
+//! so the client can run without a cluster or be pinned to specific validators.
+
+use async_trait::async_trait;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::RwLock;
+
+use super::leader_tracker::{LeaderInfo, LeaderTracker};
+use super::Slot;
+
+/// Number of consecutive slots assigned to each leader.
+const NUM_CONSECUTIVE_LEADER_SLOTS: u64 = 4;
+
+/// Provides the current slot and upcoming leaders for routing.
+#[async_trait]
+pub trait LeaderSource: Send + Sync + std::fmt::Debug {
+    /// Gets the current estimated slot (0 if unknown).
+    async fn current_slot(&self) -> Slot;
+
+    /// Gets deduplicated leaders for slots `current + start..current + end`.
+    async fn get_future_leaders(&self, start: u64, end: u64) -> Vec<LeaderInfo>;
+
+    /// Gets leaders using the slot-aware strategy, along with the slot
+    /// position within the current leader's window.
+    ///
+    /// Returns the current leader, plus the next one on the last slot of
+    /// the window. Returns no leaders when the slot is unknown.
+    async fn get_slot_aware_leaders(&self) -> (Vec<LeaderInfo>, u8) {
+        let current_slot = self.current_slot().await;
+        if current_slot == 0 {
+            return (vec![], 0);
+        }
+
+        let slot_position = LeaderTracker::get_slot_position(current_slot);
+        let num_leaders = if slot_position == 3 { 2 } else { 1 };
+
+        let leaders = self
+            .get_future_leaders(0, num_leaders as u64 * NUM_CONSECUTIVE_LEADER_SLOTS)
+            .await
+            .into_iter()
+            .take(num_leaders)
+            .collect();
+
+        (leaders, slot_position)
+    }
+
+    /// Gets upcoming leaders with configurable fanout.
+    async fn get_leaders_with_fanout(&self, fanout: u32) -> Vec<LeaderInfo> {
+        self.get_future_leaders(0, fanout as u64 * NUM_CONSECUTIVE_LEADER_SLOTS)
+            .await
+    }
+
+    /// Returns whether the source is ready to provide leader info.
+    async fn is_ready(&self) -> bool {
+        true
+    }
+
+    /// Refreshes the current slot when it appears stale.
+    ///
+    /// Sources without an external slot feed return the current slot.
+    async fn refresh_slot(&self) -> anyhow::Result<Slot> {
+        Ok(self.current_slot().await)
+    }
+}
+
+#[async_trait]
+impl LeaderSource for LeaderTracker {
+    async fn current_slot(&self) -> Slot {
+        LeaderTracker::current_slot(self).await
+    }
+
+    async fn get_future_leaders(&self, start: u64, end: u64) -> Vec<LeaderInfo> {
+        LeaderTracker::get_future_leaders(self, start, end).await
+    }
+
+    async fn get_slot_aware_leaders(&self) -> (Vec<LeaderInfo>, u8) {
+        LeaderTracker::get_slot_aware_leaders(self).await
+    }
+
+    async fn get_leaders_with_fanout(&self, fanout: u32) -> Vec<LeaderInfo> {
+        LeaderTracker::get_leaders_with_fanout(self, fanout).await
+    }
+
+    async fn is_ready(&self) -> bool {
+        LeaderTracker::is_ready(self).await
+    }
+
+    async fn refresh_slot(&self) -> anyhow::Result<Slot> {
+        self.refresh_slot_from_rpc().await
+    }
+}
+
+/// Leader source with a fixed rotation and a manually driven slot.
+///
+/// Leaders rotate every 4 slots in the order given, so with a single leader
+/// all traffic is pinned to that validator. The slot only moves when
+/// `set_slot` or `advance` is called, which makes routing deterministic.
+#[derive(Debug)]
+pub struct StaticLeaderSource {
+    /// Leader rotation as `(identity, tpu_socket)` pairs.
+    leaders: RwLock<Vec<(String, String)>>,
+    /// Current slot.
+    slot: AtomicU64,
+}
+
+impl StaticLeaderSource {
+    /// Creates a source rotating through the given leaders.
+    ///
+    /// # Arguments
+    ///
+    /// * `leaders` - `(identity, tpu_socket)` pairs in rotation order
+    /// * `slot` - Initial slot
+    pub fn new(leaders: Vec<(String, String)>, slot: Slot) -> Self {
+        Self {
+            leaders: RwLock::new(leaders),
+            slot: AtomicU64::new(slot),
+        }
+    }
+
+    /// Replaces the leader rotation.
+    pub fn set_leaders(&self, leaders: Vec<(String, String)>) {
+        *self.leaders.write().unwrap() = leaders;
+    }
+
+    /// Sets the current slot.
+    pub fn set_slot(&self, slot: Slot) {
+        self.slot.store(slot, Ordering::Relaxed);
+    }
+
+    /// Advances the current slot, returning the new slot.
+    pub fn advance(&self, slots: u64) -> Slot {
+        self.slot.fetch_add(slots, Ordering::Relaxed) + slots
+    }
+
+    /// Returns the leader scheduled for a slot.
+    fn leader_for_slot(leaders: &[(String, String)], slot: Slot) -> Option<&(String, String)> {
+        if leaders.is_empty() {
+            return None;
+        }
+        let index = (slot / NUM_CONSECUTIVE_LEADER_SLOTS) % leaders.len() as u64;
+        leaders.get(index as usize)
+    }
+}
+
+#[async_trait]
+impl LeaderSource for StaticLeaderSource {
+    async fn current_slot(&self) -> Slot {
+        self.slot.load(Ordering::Relaxed)
+    }
+
+    async fn get_future_leaders(&self, start: u64, end: u64) -> Vec<LeaderInfo> {
+        let curr_slot = self.slot.load(Ordering::Relaxed);
+        let leaders = self.leaders.read().unwrap();
+
+        let mut result: Vec<LeaderInfo> = Vec::new();
+        for i in start..end {
+            let Some(target_slot) = curr_slot.checked_add(i) else {
+                break;
+            };
+            let Some((identity, tpu_socket)) = Self::leader_for_slot(&leaders, target_slot) else {
+                break;
+            };
+            if result.iter().any(|leader| &leader.identity == identity) {
+                continue;
+            }
+            result.push(LeaderInfo {
+                identity: identity.clone(),
+                tpu_socket: tpu_socket.clone(),
+                slot: curr_slot,
+            });
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(slot: Slot) -> StaticLeaderSource {
+        StaticLeaderSource::new(
+            vec![
+                ("A".to_string(), "127.0.0.1:1001".to_string()),
+                ("B".to_string(), "127.0.0.1:1002".to_string()),
+                ("C".to_string(), "127.0.0.1:1003".to_string()),
+            ],
+            slot,
+        )
+    }
+
+    fn identities(leaders: &[LeaderInfo]) -> Vec<&str> {
+        leaders.iter().map(|l| l.identity.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_static_source_rotates_every_four_slots() {
+        let source = source(4);
+
+        assert_eq!(
+            identities(&source.get_future_leaders(0, 12).await),
+            ["B", "C", "A"]
+        );
+        assert_eq!(
+            identities(&source.get_leaders_with_fanout(2).await),
+            ["B", "C"]
+        );
+
+        source.advance(4);
+        assert_eq!(identities(&source.get_future_leaders(0, 4).await), ["C"]);
+    }
+
+    #[tokio::test]
+    async fn test_static_source_slot_aware_hedges_on_last_slot() {
+        let source = source(5);
+        let (leaders, position) = source.get_slot_aware_leaders().await;
+        assert_eq!(position, 1);
+        assert_eq!(identities(&leaders), ["B"]);
+
+        source.set_slot(7);
+        let (leaders, position) = source.get_slot_aware_leaders().await;
+        assert_eq!(position, 3);
+        assert_eq!(identities(&leaders), ["B", "C"]);
+
+        source.set_slot(0);
+        assert!(source.get_slot_aware_leaders().await.0.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_static_source_pins_single_leader() {
+        let source = StaticLeaderSource::new(vec![], 1);
+        assert!(source.get_leaders_with_fanout(4).await.is_empty());
+
+        source.set_leaders(vec![("A".to_string(), "127.0.0.1:1001".to_string())]);
+        let leaders = source.get_leaders_with_fanout(4).await;
+        assert_eq!(identities(&leaders), ["A"]);
+        assert_eq!(leaders[0].tpu_socket, "127.0.0.1:1001");
+        assert_eq!(leaders[0].slot, 1);
+    }
+}
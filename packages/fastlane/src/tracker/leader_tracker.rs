@@ -4,19 +4,34 @@
 //! to determine where to send transactions at any given moment.
 
 use anyhow::{Context, Result};
+use futures_util::future::join_all;
 use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
 use solana_client::nonblocking::pubsub_client::PubsubClient;
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_client::rpc_response::SlotUpdate;
+use solana_commitment_config::CommitmentConfig;
 use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::RwLock;
 
+use crate::events::{ClientEvent, EventBus};
+use crate::metrics::TrackerMetrics;
+
 use super::schedule_tracker::ScheduleTracker;
+use super::slot_sources::{SlotConsensus, SlotSource, SlotSourceStats};
 use super::slots_tracker::{SlotEvent, SlotsTracker};
+use super::snapshot::TrackerSnapshot;
 use super::Slot;
 
+/// Progress into the second-to-last slot of a leader window from which the
+/// next leader is hedged too, as a transaction sent then may not be
+/// scheduled before the window ends.
+const LATE_SLOT_HEDGE_PROGRESS: f64 = 0.75;
+
 /// Information about a leader validator.
 #[derive(Debug, Clone)]
 pub struct LeaderInfo {
@@ -28,9 +43,33 @@ pub struct LeaderInfo {
     pub slot: Slot,
 }
 
+/// A run of consecutive slots assigned to one leader.
+#[derive(Debug, Clone)]
+pub struct LeaderWindow {
+    /// Validator identity pubkey.
+    pub identity: String,
+    /// First slot of the window.
+    pub start_slot: Slot,
+    /// Last slot of the window (inclusive).
+    pub end_slot: Slot,
+    /// TPU sockets for this validator, if known.
+    pub sockets: Option<TpuSockets>,
+}
+
+/// Epoch boundaries as currently tracked.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EpochBounds {
+    /// Current epoch number.
+    pub epoch: u64,
+    /// First slot of the current epoch.
+    pub first_slot: Slot,
+    /// Number of slots in an epoch.
+    pub slots_in_epoch: Slot,
+}
+
 /// TPU socket addresses for a validator.
 /// Stores both normal and forwards ports for flexible routing.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TpuSockets {
     /// Standard TPU QUIC socket address.
     pub tpu_socket: Option<String>,
@@ -41,7 +80,7 @@ pub struct TpuSockets {
 /// Coordinates leader tracking for TPU transaction routing.
 ///
 /// Responsibilities:
-/// 1. Track current slot via WebSocket subscriptions
+/// 1. Track current slot across WebSocket, gRPC and RPC slot sources
 /// 2. Maintain leader schedule for current and next epochs
 /// 3. Map leader identities to TPU socket addresses
 ///
@@ -50,13 +89,11 @@ pub struct TpuSockets {
 pub struct LeaderTracker {
     /// RPC URL for fetching data.
     rpc_url: String,
-    /// WebSocket URL for subscriptions.
-    ws_url: String,
-    /// Optional Yellowstone gRPC URL for slot subscriptions.
-    grpc_url: Option<String>,
-    /// Optional Yellowstone gRPC x-token for authenticated endpoints.
-    grpc_x_token: Option<String>,
-    /// Real-time slot tracker.
+    /// Slot sources followed concurrently.
+    slot_sources: Vec<SlotSource>,
+    /// Per-source slot state and the consensus across sources.
+    slot_consensus: RwLock<SlotConsensus>,
+    /// Real-time slot tracker, fed the consensus slot.
     pub slots_tracker: RwLock<SlotsTracker>,
     /// Leader schedule tracker.
     schedule_tracker: RwLock<ScheduleTracker>,
@@ -64,49 +101,228 @@ pub struct LeaderTracker {
     leader_sockets: RwLock<HashMap<String, TpuSockets>>,
     /// Whether the tracker is ready.
     ready: RwLock<bool>,
+    /// Slot lag and epoch rotation metrics.
+    metrics: TrackerMetrics,
+    /// Publishes slot, leader change, epoch and socket events.
+    events: EventBus,
+    /// Where schedules and sockets are persisted, if anywhere.
+    snapshot_path: Option<PathBuf>,
+    /// Genesis hash the snapshot is keyed by (set with `snapshot_path`).
+    genesis_hash: Option<String>,
+    /// Whether schedules and sockets were restored from the snapshot.
+    warm_start: bool,
+    /// Whether schedules or sockets changed since the snapshot was written.
+    snapshot_dirty: AtomicBool,
+    /// Whether an epoch rotation is being fetched.
+    rotating: AtomicBool,
 }
 
 impl LeaderTracker {
     /// Creates a new LeaderTracker.
     ///
+    /// Slots are tracked from the gRPC endpoint when one is given, otherwise
+    /// from the WebSocket endpoint. Use `with_slot_sources` to follow
+    /// several sources at once.
+    ///
     /// # Arguments
     ///
     /// * `rpc_url` - RPC endpoint URL
     /// * `ws_url` - WebSocket endpoint URL
+    /// * `grpc_url` - Optional Yellowstone gRPC endpoint URL
+    /// * `grpc_x_token` - Optional x-token for the gRPC endpoint
     pub async fn new(
         rpc_url: String,
         ws_url: String,
         grpc_url: Option<String>,
         grpc_x_token: Option<String>,
+    ) -> Result<Self> {
+        Self::new_with_snapshot(rpc_url, ws_url, grpc_url, grpc_x_token, None).await
+    }
+
+    /// Creates a new LeaderTracker that persists its schedules and sockets.
+    ///
+    /// When the snapshot at `snapshot_path` belongs to the same cluster and
+    /// is still current, it is restored instead of fetching the leader
+    /// schedules, and sockets are known before the first cluster nodes
+    /// refresh. Otherwise this falls back to fetching from RPC like `new`.
+    ///
+    /// # Arguments
+    ///
+    /// * `rpc_url` - RPC endpoint URL
+    /// * `ws_url` - WebSocket endpoint URL
+    /// * `grpc_url` - Optional Yellowstone gRPC endpoint URL
+    /// * `grpc_x_token` - Optional x-token for the gRPC endpoint
+    /// * `snapshot_path` - Optional snapshot file to restore from and write to
+    pub async fn new_with_snapshot(
+        rpc_url: String,
+        ws_url: String,
+        grpc_url: Option<String>,
+        grpc_x_token: Option<String>,
+        snapshot_path: Option<PathBuf>,
     ) -> Result<Self> {
         let rpc_client = RpcClient::new(rpc_url.clone());
 
-        let schedule_tracker = ScheduleTracker::new(&rpc_client)
-            .await
-            .context("Failed to initialize schedule tracker")?;
+        let (schedule_tracker, leader_sockets, genesis_hash, warm_start) = match &snapshot_path {
+            Some(path) => {
+                let (genesis_hash, epoch_info) =
+                    tokio::try_join!(rpc_client.get_genesis_hash(), rpc_client.get_epoch_info())
+                        .context("Failed to fetch genesis hash and epoch info from RPC")?;
+                let genesis_hash = genesis_hash.to_string();
+
+                let restored = TrackerSnapshot::load(path)
+                    .await
+                    .and_then(|snapshot| snapshot.restore(&genesis_hash, &epoch_info));
+                match restored {
+                    Ok((schedule_tracker, leader_sockets)) => {
+                        (schedule_tracker, leader_sockets, Some(genesis_hash), true)
+                    }
+                    Err(_) => {
+                        let schedule_tracker =
+                            ScheduleTracker::with_epoch_info(&rpc_client, &epoch_info)
+                                .await
+                                .context("Failed to initialize schedule tracker")?;
+                        (schedule_tracker, HashMap::new(), Some(genesis_hash), false)
+                    }
+                }
+            }
+            None => {
+                let schedule_tracker = ScheduleTracker::new(&rpc_client)
+                    .await
+                    .context("Failed to initialize schedule tracker")?;
+                (schedule_tracker, HashMap::new(), None, false)
+            }
+        };
+
+        let slot_sources = vec![match grpc_url {
+            Some(url) => SlotSource::Grpc {
+                url,
+                x_token: grpc_x_token,
+            },
+            None => SlotSource::WebSocket { url: ws_url },
+        }];
 
         Ok(Self {
             rpc_url,
-            ws_url,
-            grpc_url,
-            grpc_x_token,
+            slot_consensus: RwLock::new(SlotConsensus::new(&slot_sources)),
+            slot_sources,
             slots_tracker: RwLock::new(SlotsTracker::new()),
             schedule_tracker: RwLock::new(schedule_tracker),
-            leader_sockets: RwLock::new(HashMap::new()),
+            leader_sockets: RwLock::new(leader_sockets),
             ready: RwLock::new(false),
+            metrics: TrackerMetrics::default(),
+            events: EventBus::default(),
+            snapshot_path,
+            genesis_hash,
+            warm_start,
+            snapshot_dirty: AtomicBool::new(!warm_start),
+            rotating: AtomicBool::new(false),
         })
     }
 
+    /// Replaces the slot sources, running all of them concurrently.
+    ///
+    /// An empty list keeps the sources chosen by `new`. Must be called
+    /// before the slot listener starts.
+    pub fn with_slot_sources(mut self, slot_sources: Vec<SlotSource>) -> Self {
+        if !slot_sources.is_empty() {
+            self.slot_consensus = RwLock::new(SlotConsensus::new(&slot_sources));
+            self.slot_sources = slot_sources;
+        }
+        self
+    }
+
+    /// Returns the slot sources being followed.
+    pub fn slot_sources(&self) -> &[SlotSource] {
+        &self.slot_sources
+    }
+
+    /// Returns per-source slot, lag against the consensus and staleness.
+    pub async fn slot_source_stats(&self) -> Vec<SlotSourceStats> {
+        self.slot_consensus.read().await.stats()
+    }
+
+    /// Returns whether schedules and sockets were restored from a snapshot
+    /// rather than fetched from RPC.
+    pub fn warm_start(&self) -> bool {
+        self.warm_start
+    }
+
+    /// Returns the snapshot file, if snapshots are enabled.
+    pub fn snapshot_path(&self) -> Option<&Path> {
+        self.snapshot_path.as_deref()
+    }
+
+    /// Writes the current schedules and sockets to the snapshot file.
+    ///
+    /// # Returns
+    ///
+    /// `false` if snapshots are not enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the snapshot cannot be written.
+    pub async fn save_snapshot(&self) -> Result<bool> {
+        let (path, genesis_hash) = match (&self.snapshot_path, &self.genesis_hash) {
+            (Some(path), Some(genesis_hash)) => (path, genesis_hash),
+            _ => return Ok(false),
+        };
+
+        let snapshot = {
+            let schedule_tracker = self.schedule_tracker.read().await;
+            let leader_sockets = self.leader_sockets.read().await;
+            TrackerSnapshot::capture(genesis_hash, &schedule_tracker, &leader_sockets)
+        };
+        snapshot.save(path).await?;
+        Ok(true)
+    }
+
+    /// Starts a background task that writes the snapshot whenever schedules
+    /// or sockets changed since the last write, checking every `interval`.
+    ///
+    /// Returns immediately if snapshots are not enabled.
+    pub async fn run_snapshot_writer(self: Arc<Self>, interval: Duration) {
+        if self.snapshot_path.is_none() {
+            return;
+        }
+        loop {
+            tokio::time::sleep(interval).await;
+            if self.snapshot_dirty.swap(false, Ordering::Relaxed)
+                && self.save_snapshot().await.is_err()
+            {
+                self.snapshot_dirty.store(true, Ordering::Relaxed);
+            }
+        }
+    }
+
     /// Returns whether the tracker is ready to provide leader info.
     pub async fn is_ready(&self) -> bool {
         *self.ready.read().await
     }
 
+    /// Returns the slot lag and epoch rotation metrics.
+    pub fn metrics(&self) -> &TrackerMetrics {
+        &self.metrics
+    }
+
+    /// Returns the bus slot, leader change, epoch and socket events are
+    /// published on.
+    pub fn events(&self) -> &EventBus {
+        &self.events
+    }
+
     /// Gets the current estimated slot.
+    ///
+    /// Extrapolated from the wall clock when slot updates lag, so routing
+    /// stays correct through short feed gaps.
     pub async fn current_slot(&self) -> Slot {
         self.slots_tracker.read().await.current_slot()
     }
 
+    /// Gets the fractional progress into the current slot (0.0 to 1.0).
+    pub async fn slot_progress(&self) -> f64 {
+        self.slots_tracker.read().await.slot_progress()
+    }
+
     /// Refreshes the current slot from RPC when WebSocket is stale.
     /// 
     /// This is a fallback mechanism when the WebSocket subscription lags
@@ -119,12 +335,38 @@ impl LeaderTracker {
             .context("Failed to fetch slot from RPC")?;
         
         // Update the slots tracker with this fresh value
-        let mut tracker = self.slots_tracker.write().await;
-        tracker.record(SlotEvent::Start(slot));
+        let (previous, curr_slot) = {
+            let mut tracker = self.slots_tracker.write().await;
+            let previous = tracker.observed_slot();
+            self.record_slot_lag(slot, tracker.current_slot());
+            (previous, tracker.record(SlotEvent::Start(slot)))
+        };
+        self.emit_slot_events(previous, curr_slot).await;
         
         Ok(slot)
     }
 
+    /// Compares the tracked slot against RPC and records the lag.
+    ///
+    /// Does not update the tracked slot.
+    pub async fn sample_slot_lag(&self) -> Result<i64> {
+        let rpc_client = RpcClient::new(self.rpc_url.clone());
+        let rpc_slot = rpc_client
+            .get_slot()
+            .await
+            .context("Failed to fetch slot from RPC")?;
+
+        let tracked_slot = self.current_slot().await;
+        Ok(self.record_slot_lag(rpc_slot, tracked_slot))
+    }
+
+    /// Records how far the tracked slot is behind RPC (negative if ahead).
+    fn record_slot_lag(&self, rpc_slot: Slot, tracked_slot: Slot) -> i64 {
+        let lag = rpc_slot as i64 - tracked_slot as i64;
+        self.metrics.slot_lag.set(lag);
+        lag
+    }
+
     /// Get slot position within leader's 4-slot window (0-3).
     /// 
     /// Solana leaders get 4 consecutive slots (NUM_CONSECUTIVE_LEADER_SLOTS = 4).
@@ -135,16 +377,31 @@ impl LeaderTracker {
         (slot % 4) as u8
     }
 
+    /// Returns whether to also send to the next leader.
+    ///
+    /// Hedges through the last slot of the window, and from late in the
+    /// slot before it (`LATE_SLOT_HEDGE_PROGRESS`).
+    ///
+    /// # Arguments
+    ///
+    /// * `slot_position` - Position within the leader window (0-3)
+    /// * `slot_progress` - Fractional progress into the current slot
+    pub fn should_hedge(slot_position: u8, slot_progress: f64) -> bool {
+        slot_position == 3 || (slot_position == 2 && slot_progress >= LATE_SLOT_HEDGE_PROGRESS)
+    }
+
     /// Get leaders using slot-aware strategy to minimize tx leakage.
     /// 
     /// Strategy:
     /// - Slots 0-2 of leader window: returns current leader only (fanout = 1)
-    /// - Slot 3 of leader window: returns current + next leader (fanout = 2)
+    /// - Slot 3 of leader window, or late in slot 2: returns current + next
+    ///   leader (fanout = 2)
     /// 
     /// This achieves the same landing rate as high fanout but with minimal
     /// transaction leakage (fewer validators see the transaction).
     pub async fn get_slot_aware_leaders(&self) -> (Vec<LeaderInfo>, u8) {
-        let current_slot = self.current_slot().await;
+        let (current_slot, slot_progress) =
+            self.slots_tracker.read().await.current_slot_and_progress();
         
         // If slot is 0, we can't determine position - caller should fallback
         if current_slot == 0 {
@@ -153,9 +410,13 @@ impl LeaderTracker {
         
         let slot_position = Self::get_slot_position(current_slot);
         
-        // Last slot of leader's window (position 3) - include next leader as hedge
+        // End of leader's window - include next leader as hedge
         // Otherwise, just send to current leader
-        let num_leaders = if slot_position == 3 { 2 } else { 1 };
+        let num_leaders = if Self::should_hedge(slot_position, slot_progress) {
+            2
+        } else {
+            1
+        };
         
         // Look ahead enough slots to find the required number of unique leaders
         // Each leader has 4 slots, so for 2 leaders we need to look at 8 slots
@@ -176,7 +437,8 @@ impl LeaderTracker {
     /// Gets upcoming leaders for transaction routing.
     ///
     /// Prefers TPU forwards port (recommended by validators), falling back
-    /// to normal TPU port if forwards is not available.
+    /// to normal TPU port if forwards is not available. Lookahead continues
+    /// into the next epoch using its prefetched schedule.
     ///
     /// # Arguments
     ///
@@ -198,13 +460,6 @@ impl LeaderTracker {
             return vec![];
         }
 
-        // Validate we're in the current epoch
-        if curr_slot < schedule_tracker.current_epoch_slot_start()
-            || curr_slot >= schedule_tracker.next_epoch_slot_start()
-        {
-            return vec![];
-        }
-
         let mut leaders = Vec::new();
         let mut seen = HashSet::new();
 
@@ -214,41 +469,27 @@ impl LeaderTracker {
                 None => break, // Overflow protection
             };
 
-            // Skip if out of current epoch range
-            if target_slot >= schedule_tracker.next_epoch_slot_start() {
-                break;
-            }
-
-            // Convert absolute slot to epoch-relative index
-            let slot_index = match schedule_tracker.slot_to_index(target_slot) {
-                Some(idx) => idx,
-                None => continue,
-            };
-
-            // Get leader for this slot
-            if let Some(leader_pubkey) = schedule_tracker.get_leader_for_slot_index(slot_index) {
+            // Get leader for this slot (falls through to next epoch's schedule)
+            if let Some(leader_pubkey) = schedule_tracker.get_leader_for_slot(target_slot) {
                 // Deduplicate - only add each leader once
                 if !seen.insert(leader_pubkey.to_string()) {
                     continue;
                 }
 
-                match leader_sockets.get(leader_pubkey) {
-                    Some(sockets) => {
-                        // Prefer forwards port, fall back to normal TPU port
-                        let socket = sockets
-                            .tpu_forwards_socket
-                            .as_ref()
-                            .or(sockets.tpu_socket.as_ref());
-
-                        if let Some(s) = socket {
-                            leaders.push(LeaderInfo {
-                                identity: leader_pubkey.to_string(),
-                                tpu_socket: s.clone(),
-                                slot: curr_slot,
-                            });
-                        }
+                if let Some(sockets) = leader_sockets.get(leader_pubkey) {
+                    // Prefer forwards port, fall back to normal TPU port
+                    let socket = sockets
+                        .tpu_forwards_socket
+                        .as_ref()
+                        .or(sockets.tpu_socket.as_ref());
+
+                    if let Some(s) = socket {
+                        leaders.push(LeaderInfo {
+                            identity: leader_pubkey.to_string(),
+                            tpu_socket: s.clone(),
+                            slot: curr_slot,
+                        });
                     }
-                    None => {}
                 }
             }
         }
@@ -256,6 +497,49 @@ impl LeaderTracker {
         leaders
     }
 
+    /// Gets upcoming leader windows with their slot ranges and sockets.
+    ///
+    /// Unlike `get_future_leaders`, leaders are not deduplicated and are
+    /// included even when their TPU sockets are unknown. The first window is
+    /// the current leader's, starting at its first slot.
+    ///
+    /// # Arguments
+    ///
+    /// * `lookahead` - Number of slots to look ahead from the current slot,
+    ///   capped at one epoch
+    pub async fn get_leader_windows(&self, lookahead: u64) -> Vec<LeaderWindow> {
+        let curr_slot = self.current_slot().await;
+        if curr_slot == 0 {
+            return vec![];
+        }
+
+        let schedule_tracker = self.schedule_tracker.read().await;
+        let leader_sockets = self.leader_sockets.read().await;
+
+        let lookahead = lookahead.min(schedule_tracker.slots_in_epoch());
+        schedule_tracker
+            .leader_windows(curr_slot, curr_slot.saturating_add(lookahead))
+            .into_iter()
+            .map(|(identity, start_slot, end_slot)| LeaderWindow {
+                sockets: leader_sockets.get(&identity).cloned(),
+                identity,
+                start_slot,
+                end_slot,
+            })
+            .collect()
+    }
+
+    /// Returns the current epoch number and boundaries.
+    pub async fn epoch_bounds(&self) -> EpochBounds {
+        let schedule_tracker = self.schedule_tracker.read().await;
+
+        EpochBounds {
+            epoch: schedule_tracker.epoch(),
+            first_slot: schedule_tracker.current_epoch_slot_start(),
+            slots_in_epoch: schedule_tracker.slots_in_epoch(),
+        }
+    }
+
     /// Gets upcoming leaders for transaction routing.
     ///
     /// This is the main method for transaction routing.
@@ -295,6 +579,7 @@ impl LeaderTracker {
 
         let mut sockets = self.leader_sockets.write().await;
         let mut seen = HashSet::new();
+        let (mut added, mut changed) = (0, 0);
 
         for node in nodes {
             let pubkey = node.pubkey.to_string();
@@ -313,30 +598,52 @@ impl LeaderTracker {
                     tpu_forwards_socket,
                 };
 
-                let needs_update = sockets
-                    .get(&pubkey)
-                    .map(|existing| existing != &new_entry)
-                    .unwrap_or(true);
-
-                if needs_update {
-                    sockets.insert(pubkey, new_entry);
+                match sockets.get(&pubkey) {
+                    Some(existing) if existing == &new_entry => {}
+                    Some(_) => {
+                        changed += 1;
+                        sockets.insert(pubkey, new_entry);
+                    }
+                    None => {
+                        added += 1;
+                        sockets.insert(pubkey, new_entry);
+                    }
                 }
             }
         }
 
         // Remove validators no longer present in the cluster nodes response.
+        let before = sockets.len();
         sockets.retain(|pubkey, _| seen.contains(pubkey));
+        let removed = before - sockets.len();
+
+        if added + changed + removed > 0 {
+            self.snapshot_dirty.store(true, Ordering::Relaxed);
+            self.events.emit(ClientEvent::SocketsUpdated {
+                added,
+                changed,
+                removed,
+                known: sockets.len(),
+            });
+        }
 
         Ok(())
     }
 
-    /// Starts the slot updates listener with automatic reconnection.
+    /// Starts the slot updates listeners with automatic reconnection.
     ///
-    /// This should be spawned as a background task. If the WebSocket
-    /// connection drops, it will automatically reconnect after a short delay.
+    /// This should be spawned as a background task. Every slot source runs
+    /// concurrently, and each one reconnects after a short delay when its
+    /// connection drops without affecting the others.
     pub async fn run_slot_listener(self: Arc<Self>) -> Result<()> {
+        join_all((0..self.slot_sources.len()).map(|source| self.run_source_listener(source))).await;
+        Ok(())
+    }
+
+    /// Runs a single slot source, reconnecting whenever it ends.
+    async fn run_source_listener(&self, source: usize) {
         loop {
-            match self.run_slot_listener_inner().await {
+            match self.run_slot_listener_inner(source).await {
                 Ok(_) => {}
                 Err(_) => {
                     tokio::time::sleep(Duration::from_secs(1)).await;
@@ -345,19 +652,24 @@ impl LeaderTracker {
         }
     }
 
-    /// Inner slot listener that handles the configured connection.
+    /// Inner slot listener that handles one source's connection.
     /// Returns when the connection ends (either normally or due to error).
-    async fn run_slot_listener_inner(&self) -> Result<()> {
-        if let Some(grpc_url) = self.grpc_url.as_ref() {
-            return self.run_grpc_slot_listener_inner(grpc_url).await;
+    async fn run_slot_listener_inner(&self, source: usize) -> Result<()> {
+        match &self.slot_sources[source] {
+            SlotSource::WebSocket { url } => self.run_wss_slot_listener_inner(source, url).await,
+            SlotSource::Grpc { url, x_token } => {
+                self.run_grpc_slot_listener_inner(source, url, x_token.clone())
+                    .await
+            }
+            SlotSource::RpcPoll { url, interval } => {
+                self.run_rpc_slot_poller_inner(source, url, *interval).await
+            }
         }
-
-        self.run_wss_slot_listener_inner().await
     }
 
     /// Inner slot listener that handles the WebSocket connection.
-    async fn run_wss_slot_listener_inner(&self) -> Result<()> {
-        let ws_client = PubsubClient::new(&self.ws_url)
+    async fn run_wss_slot_listener_inner(&self, source: usize, ws_url: &str) -> Result<()> {
+        let ws_client = PubsubClient::new(ws_url)
             .await
             .context("Failed to connect to WebSocket")?;
 
@@ -373,7 +685,7 @@ impl LeaderTracker {
         }
 
         while let Some(slot_event) = slot_notifications.next().await {
-            let _ = self.handle_slot_event(slot_event).await;
+            let _ = self.handle_slot_event(source, slot_event).await;
         }
 
         // Stream ended - will trigger reconnect in the outer loop
@@ -381,7 +693,12 @@ impl LeaderTracker {
     }
 
     /// Inner slot listener that handles the Yellowstone gRPC connection.
-    async fn run_grpc_slot_listener_inner(&self, grpc_url: &str) -> Result<()> {
+    async fn run_grpc_slot_listener_inner(
+        &self,
+        source: usize,
+        grpc_url: &str,
+        x_token: Option<String>,
+    ) -> Result<()> {
         use yellowstone_grpc_client::{ClientTlsConfig, GeyserGrpcBuilder};
         use yellowstone_grpc_proto::geyser::{
             SubscribeRequest, SubscribeRequestFilterSlots,
@@ -390,7 +707,6 @@ impl LeaderTracker {
 
         let mut builder = GeyserGrpcBuilder::from_shared(grpc_url.to_string())
             .context("Failed to build gRPC client")?;
-        let x_token = self.grpc_x_token.clone();
         builder = builder.x_token(x_token).context("Failed to set gRPC x-token")?;
 
         let mut client = builder
@@ -416,42 +732,49 @@ impl LeaderTracker {
             .await
             .context("Failed to subscribe to gRPC slot updates")?;
 
-        let mut ready_set = false;
         while let Some(result) = stream.next().await {
             let update = result.context("gRPC slot stream error")?;
             if let Some(UpdateOneof::Slot(slot_update)) = update.update_oneof {
-                let slot = slot_update.slot;
-
-                // Record the slot update (monotonic source; bypass outlier filtering)
-                let curr_slot = {
-                    let mut tracker = self.slots_tracker.write().await;
-                    tracker.record_monotonic(slot)
-                };
-
-                // Mark as ready once we start receiving updates
-                if !ready_set {
-                    let mut ready = self.ready.write().await;
-                    *ready = true;
-                    ready_set = true;
-                }
-
-                // Check if we need to rotate to next epoch (keep schedule fresh across epoch boundaries)
-                let needs_rotation = {
-                    let schedule_tracker = self.schedule_tracker.read().await;
-                    curr_slot >= schedule_tracker.next_epoch_slot_start()
-                };
-
-                if needs_rotation {
-                    self.rotate_epoch(curr_slot).await?;
-                }
+                // Monotonic source; bypass outlier filtering
+                let consensus = self
+                    .slot_consensus
+                    .write()
+                    .await
+                    .record_monotonic(source, slot_update.slot);
+                self.apply_consensus_slot(consensus).await?;
             }
         }
 
         Ok(())
     }
 
-    /// Handles a single slot update event.
-    async fn handle_slot_event(&self, slot_update: SlotUpdate) -> Result<()> {
+    /// Inner slot listener that polls `getSlot` over RPC.
+    /// Returns an error on the first failed poll.
+    async fn run_rpc_slot_poller_inner(
+        &self,
+        source: usize,
+        rpc_url: &str,
+        interval: Duration,
+    ) -> Result<()> {
+        let rpc_client =
+            RpcClient::new_with_commitment(rpc_url.to_string(), CommitmentConfig::processed());
+        loop {
+            let slot = rpc_client
+                .get_slot()
+                .await
+                .context("Failed to poll slot from RPC")?;
+            let consensus = self
+                .slot_consensus
+                .write()
+                .await
+                .record_monotonic(source, slot);
+            self.apply_consensus_slot(consensus).await?;
+            tokio::time::sleep(interval).await;
+        }
+    }
+
+    /// Handles a single slot update event from a WebSocket source.
+    async fn handle_slot_event(&self, source: usize, slot_update: SlotUpdate) -> Result<()> {
         // Convert to our SlotEvent type
         let event = match slot_update {
             SlotUpdate::FirstShredReceived { slot, .. } => SlotEvent::Start(slot),
@@ -459,42 +782,154 @@ impl LeaderTracker {
             _ => return Ok(()), // Ignore other event types
         };
 
-        // Record the slot event
-        let curr_slot = {
+        let consensus = self.slot_consensus.write().await.record(source, event);
+        self.apply_consensus_slot(consensus).await
+    }
+
+    /// Advances the tracked slot to the consensus across sources, marks the
+    /// tracker ready and rotates the schedule at epoch boundaries.
+    async fn apply_consensus_slot(&self, consensus: Slot) -> Result<()> {
+        if consensus == 0 {
+            return Ok(());
+        }
+
+        let (previous, curr_slot) = {
             let mut slot_tracker = self.slots_tracker.write().await;
-            slot_tracker.record(event)
+            let previous = slot_tracker.observed_slot();
+            (previous, slot_tracker.record_monotonic(consensus))
         };
+        self.emit_slot_events(previous, curr_slot).await;
 
-        // Check if we need to rotate to next epoch
-        let needs_rotation = {
-            let schedule_tracker = self.schedule_tracker.read().await;
-            curr_slot >= schedule_tracker.next_epoch_slot_start()
-        };
+        if !*self.ready.read().await {
+            *self.ready.write().await = true;
+        }
 
-        if needs_rotation {
-            self.rotate_epoch(curr_slot).await?;
+        // Rotate to the next epoch at the boundary, unless another update
+        // is already fetching the rotation
+        if !self.rotating.swap(true, Ordering::AcqRel) {
+            let result = self.rotate_epoch(curr_slot).await;
+            self.rotating.store(false, Ordering::Release);
+            result?;
         }
 
         Ok(())
     }
 
     /// Rotates the schedule to the next epoch.
+    ///
+    /// Schedules are fetched without holding the schedule lock, so routing
+    /// continues meanwhile; the tracker only changes once they are fetched.
     async fn rotate_epoch(&self, curr_slot: Slot) -> Result<()> {
+        let (epoch_slot_start, fetch_current, slots_in_epoch) = {
+            let schedule_tracker = self.schedule_tracker.read().await;
+            match schedule_tracker.rotation_due(curr_slot) {
+                Some((start, fetch_current)) => {
+                    (start, fetch_current, schedule_tracker.slots_in_epoch())
+                }
+                None => return Ok(()),
+            }
+        };
+
         let rpc_client = RpcClient::new(self.rpc_url.clone());
-        let mut schedule_tracker = self.schedule_tracker.write().await;
+        let rotation = ScheduleTracker::fetch_rotation(
+            &rpc_client,
+            epoch_slot_start,
+            slots_in_epoch,
+            fetch_current,
+        )
+        .await
+        .context("Epoch rotation failed")?;
 
-        schedule_tracker.maybe_rotate(curr_slot, &rpc_client).await
-            .context("Epoch rotation failed")?;
+        let mut schedule_tracker = self.schedule_tracker.write().await;
+        if schedule_tracker.apply_rotation(rotation) {
+            self.snapshot_dirty.store(true, Ordering::Relaxed);
+            self.metrics.epoch_rotations.inc();
+            self.events.emit(ClientEvent::EpochRotated {
+                bounds: EpochBounds {
+                    epoch: schedule_tracker.epoch(),
+                    first_slot: schedule_tracker.current_epoch_slot_start(),
+                    slots_in_epoch: schedule_tracker.slots_in_epoch(),
+                },
+                slot: curr_slot,
+            });
+        }
 
         Ok(())
     }
 
+    /// Publishes a slot event, and a leader change event when the new slot
+    /// belongs to a different leader than the previous one.
+    ///
+    /// Does nothing unless the slot advanced and someone is subscribed.
+    async fn emit_slot_events(&self, previous: Slot, curr_slot: Slot) {
+        if curr_slot <= previous || !self.events.has_subscribers() {
+            return;
+        }
+        self.events.emit(ClientEvent::Slot { slot: curr_slot });
+
+        let schedule_tracker = self.schedule_tracker.read().await;
+        let previous_leader = if previous == 0 {
+            None
+        } else {
+            schedule_tracker.get_leader_for_slot(previous)
+        };
+
+        if let Some(leader) = schedule_tracker.get_leader_for_slot(curr_slot) {
+            if previous_leader != Some(leader) {
+                self.events.emit(ClientEvent::LeaderChange {
+                    slot: curr_slot,
+                    leader: leader.to_string(),
+                    previous: previous_leader.map(str::to_string),
+                });
+            }
+        }
+    }
+
+    /// Fetches the next epoch's schedule if it is still missing.
+    ///
+    /// The RPC call is made without holding the schedule lock, so routing
+    /// is never blocked on it.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a missing schedule was fetched and installed.
+    pub async fn refresh_next_schedule(&self) -> Result<bool> {
+        let next_epoch_slot_start = match self.schedule_tracker.read().await.missing_next_schedule() {
+            Some(slot) => slot,
+            None => return Ok(false),
+        };
+
+        let rpc_client = RpcClient::new(self.rpc_url.clone());
+        let schedule = ScheduleTracker::fetch_schedule(&rpc_client, next_epoch_slot_start)
+            .await
+            .context("Failed to fetch next epoch schedule")?;
+
+        let mut schedule_tracker = self.schedule_tracker.write().await;
+        let installed = schedule_tracker.set_next_schedule(next_epoch_slot_start, schedule);
+        if installed {
+            self.snapshot_dirty.store(true, Ordering::Relaxed);
+        }
+        Ok(installed)
+    }
+
+    /// Starts a background task that retries fetching a missing next epoch schedule.
+    ///
+    /// Should be spawned as a background task.
+    pub async fn run_schedule_refresher(self: Arc<Self>, interval: Duration) {
+        loop {
+            let _ = self.refresh_next_schedule().await;
+            tokio::time::sleep(interval).await;
+        }
+    }
+
     /// Starts a background task to periodically update leader sockets.
     ///
+    /// Also samples slot lag against RPC on each tick.
     /// Should be spawned as a background task.
     pub async fn run_socket_updater(self: Arc<Self>, interval: Duration) {
         loop {
             let _ = self.update_leader_sockets().await;
+            let _ = self.sample_slot_lag().await;
             tokio::time::sleep(interval).await;
         }
     }
@@ -504,8 +939,14 @@ impl std::fmt::Debug for LeaderTracker {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("LeaderTracker")
             .field("rpc_url", &self.rpc_url)
-            .field("ws_url", &self.ws_url)
-            .field("grpc_url", &self.grpc_url)
+            .field(
+                "slot_sources",
+                &self
+                    .slot_sources
+                    .iter()
+                    .map(SlotSource::kind)
+                    .collect::<Vec<_>>(),
+            )
             .finish()
     }
 }
@@ -533,6 +974,14 @@ mod tests {
         assert_eq!(LeaderTracker::get_slot_position(8), 0);
     }
 
+    #[test]
+    fn test_hedges_at_end_of_window() {
+        assert!(!LeaderTracker::should_hedge(0, 0.9));
+        assert!(!LeaderTracker::should_hedge(2, 0.5));
+        assert!(LeaderTracker::should_hedge(2, LATE_SLOT_HEDGE_PROGRESS));
+        assert!(LeaderTracker::should_hedge(3, 0.0));
+    }
+
     #[test]
     fn test_get_slot_position_large_slots() {
         // Test with realistic slot numbers (mainnet is in the hundreds of millions)
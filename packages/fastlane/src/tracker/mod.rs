@@ -1,27 +1,47 @@
 //! Leader tracking module for TPU transaction routing.
 //!
 //! This module provides real-time tracking of:
-//! - Current slot via WebSocket subscriptions
+//! - Current slot via WebSocket, gRPC and RPC slot sources, agreed across
+//!   sources so one lagging or faulty provider doesn't move routing
 //! - Leader schedule per epoch
 //! - Validator TPU socket addresses
 //!
+//! Schedules and sockets can be persisted to disk so later starts skip the
+//! schedule and cluster nodes fetches.
+//!
 //! The components work together to determine which validators should
-//! receive transactions at any given moment.
+//! receive transactions at any given moment. Routing consumers depend on
+//! the `LeaderSource` trait, so a static source can stand in for the tracker.
 
+mod leader_source;
 mod leader_tracker;
 mod schedule_tracker;
+mod slot_sources;
 mod slots_tracker;
+mod snapshot;
+
+use std::time::Duration;
 
-pub use leader_tracker::{LeaderInfo, LeaderTracker};
+pub use leader_source::{LeaderSource, StaticLeaderSource};
+pub use leader_tracker::{EpochBounds, LeaderInfo, LeaderTracker, LeaderWindow, TpuSockets};
 pub use schedule_tracker::ScheduleTracker;
+pub use slot_sources::{
+    SlotConsensus, SlotSource, SlotSourceStats, DEFAULT_RPC_POLL_INTERVAL,
+    SLOT_SOURCE_STALE_AFTER,
+};
 pub use slots_tracker::{SlotEvent, SlotsTracker};
+pub use snapshot::{TrackerSnapshot, DEFAULT_SNAPSHOT_INTERVAL, SNAPSHOT_VERSION};
 
 /// Type alias for slot numbers.
 pub type Slot = u64;
 
 /// Maximum number of slots to skip before considering a slot invalid.
+/// Also bounds how far the current slot is extrapolated past the last event.
 pub const MAX_SLOT_SKIP_DISTANCE: u64 = 48;
 
+/// Target slot duration, used until slot durations have been observed.
+pub const DEFAULT_SLOT_DURATION: Duration = Duration::from_millis(400);
+
 /// Capacity for tracking recent leader slots.
 pub const RECENT_LEADER_SLOTS_CAPACITY: usize = 48;
 
@@ -5,10 +5,25 @@
 
 use anyhow::{ensure, Context, Result};
 use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::epoch_info::EpochInfo;
 use std::collections::HashMap;
 
 use super::Slot;
 
+/// Schedules fetched for rotating into the next epoch.
+///
+/// Fetched without touching the tracker, then installed with
+/// `ScheduleTracker::apply_rotation`.
+#[derive(Debug)]
+pub struct EpochRotation {
+    /// First slot of the epoch being rotated into.
+    epoch_slot_start: Slot,
+    /// Its schedule, unless it was already prefetched.
+    curr_schedule: Option<HashMap<usize, String>>,
+    /// Schedule of the epoch after it (empty if not available yet).
+    next_schedule: HashMap<usize, String>,
+}
+
 /// Tracks leader schedules for current and upcoming epochs.
 ///
 /// Maintains schedules for two epochs:
@@ -19,6 +34,8 @@ use super::Slot;
 /// rotated and a new next epoch schedule is fetched.
 #[derive(Debug)]
 pub struct ScheduleTracker {
+    /// Current epoch number.
+    epoch: u64,
     /// First slot of the current epoch.
     curr_epoch_slot_start: Slot,
     /// First slot of the next epoch.
@@ -50,6 +67,17 @@ impl ScheduleTracker {
             .await
             .context("Failed to fetch epoch info from RPC")?;
 
+        Self::with_epoch_info(rpc_client, &epoch_info).await
+    }
+
+    /// Creates a new ScheduleTracker for already fetched epoch info,
+    /// fetching the current and next epoch schedules.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the epoch info is invalid or the current epoch
+    /// schedule cannot be fetched.
+    pub async fn with_epoch_info(rpc_client: &RpcClient, epoch_info: &EpochInfo) -> Result<Self> {
         // Validate epoch info
         ensure!(
             epoch_info.slots_in_epoch > 0,
@@ -78,6 +106,7 @@ impl ScheduleTracker {
             .unwrap_or_default(); // Next epoch schedule may not be available yet
 
         Ok(Self {
+            epoch: epoch_info.epoch,
             curr_epoch_slot_start,
             next_epoch_slot_start,
             curr_schedule,
@@ -86,6 +115,24 @@ impl ScheduleTracker {
         })
     }
 
+    /// Creates a tracker from previously fetched schedules.
+    pub(super) fn from_parts(
+        epoch: u64,
+        curr_epoch_slot_start: Slot,
+        slots_in_epoch: Slot,
+        curr_schedule: HashMap<usize, String>,
+        next_schedule: HashMap<usize, String>,
+    ) -> Self {
+        Self {
+            epoch,
+            curr_epoch_slot_start,
+            next_epoch_slot_start: curr_epoch_slot_start + slots_in_epoch,
+            curr_schedule,
+            next_schedule,
+            slots_in_epoch,
+        }
+    }
+
     /// Fetches the leader schedule for a given epoch.
     ///
     /// # Arguments
@@ -138,6 +185,117 @@ impl ScheduleTracker {
         self.curr_schedule.get(&slot_index).map(|s| s.as_str())
     }
 
+    /// Gets the leader for an absolute slot in the current or next epoch.
+    ///
+    /// Slots past the epoch boundary are resolved from the prefetched next
+    /// epoch schedule, so lookahead keeps working in the last slots of an epoch.
+    ///
+    /// # Returns
+    ///
+    /// The validator pubkey for this slot, or None if the slot is outside
+    /// both epochs or its schedule hasn't been fetched.
+    pub fn get_leader_for_slot(&self, slot: Slot) -> Option<&str> {
+        if let Some(slot_index) = self.slot_to_index(slot) {
+            return self.get_leader_for_slot_index(slot_index);
+        }
+
+        let next_index = slot.checked_sub(self.next_epoch_slot_start)?;
+        if next_index >= self.slots_in_epoch {
+            return None; // Beyond the next epoch
+        }
+
+        self.next_schedule
+            .get(&(next_index as usize))
+            .map(|s| s.as_str())
+    }
+
+    /// Returns the first slot of the next epoch if its schedule is still missing.
+    ///
+    /// The next schedule may not be available from RPC when first requested
+    /// (e.g. early in an epoch), so it is retried until fetched.
+    pub fn missing_next_schedule(&self) -> Option<Slot> {
+        if self.next_schedule.is_empty() {
+            Some(self.next_epoch_slot_start)
+        } else {
+            None
+        }
+    }
+
+    /// Installs a fetched next epoch schedule.
+    ///
+    /// # Returns
+    ///
+    /// `false` if the schedule no longer applies (the tracker rotated since
+    /// `epoch_slot_start` was requested).
+    pub fn set_next_schedule(
+        &mut self,
+        epoch_slot_start: Slot,
+        schedule: HashMap<usize, String>,
+    ) -> bool {
+        if epoch_slot_start != self.next_epoch_slot_start {
+            return false;
+        }
+
+        self.next_schedule = schedule;
+        true
+    }
+
+    /// Groups the schedule into consecutive leader windows.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - First absolute slot to include
+    /// * `end` - Absolute slot to stop before (exclusive)
+    ///
+    /// # Returns
+    ///
+    /// `(leader pubkey, first slot, last slot)` for each run of consecutive
+    /// slots with the same leader, in slot order. The first window keeps its
+    /// real first slot even when that is before `start`. Windows continue
+    /// into the next epoch and stop where the known schedules end; slots
+    /// missing from both schedules are skipped.
+    pub fn leader_windows(&self, start: Slot, end: Slot) -> Vec<(String, Slot, Slot)> {
+        let mut windows: Vec<(String, Slot, Slot)> = Vec::new();
+        let end = end.min(self.next_epoch_slot_start + self.slots_in_epoch);
+
+        for slot in start..end {
+            let leader = match self.get_leader_for_slot(slot) {
+                Some(leader) => leader,
+                None => continue,
+            };
+
+            match windows.last_mut() {
+                Some((identity, _, last)) if identity == leader && *last + 1 == slot => {
+                    *last = slot;
+                }
+                _ => windows.push((leader.to_string(), slot, slot)),
+            }
+        }
+
+        if let Some((identity, first, _)) = windows.first_mut() {
+            while *first > 0 && self.get_leader_for_slot(*first - 1) == Some(identity.as_str()) {
+                *first -= 1;
+            }
+        }
+
+        windows
+    }
+
+    /// Returns the current epoch's schedule (slot index -> validator pubkey).
+    pub(super) fn curr_schedule(&self) -> &HashMap<usize, String> {
+        &self.curr_schedule
+    }
+
+    /// Returns the next epoch's schedule (empty if not fetched yet).
+    pub(super) fn next_schedule(&self) -> &HashMap<usize, String> {
+        &self.next_schedule
+    }
+
+    /// Returns the current epoch number.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
     /// Returns the first slot of the current epoch.
     pub fn current_epoch_slot_start(&self) -> Slot {
         self.curr_epoch_slot_start
@@ -171,9 +329,83 @@ impl ScheduleTracker {
         Some(index as usize)
     }
 
+    /// Returns the first slot of the next epoch once `current_slot` has
+    /// reached it, along with whether its schedule still has to be fetched.
+    pub fn rotation_due(&self, current_slot: Slot) -> Option<(Slot, bool)> {
+        (current_slot >= self.next_epoch_slot_start)
+            .then_some((self.next_epoch_slot_start, self.next_schedule.is_empty()))
+    }
+
+    /// Fetches the schedules needed to rotate into an epoch.
+    ///
+    /// # Arguments
+    ///
+    /// * `rpc_client` - The RPC client to use
+    /// * `epoch_slot_start` - First slot of the epoch being rotated into
+    /// * `slots_in_epoch` - Number of slots in an epoch
+    /// * `fetch_current` - Whether that epoch's schedule was never prefetched
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the epoch's own schedule is needed and cannot be
+    /// fetched. The schedule after it may not be available yet and is
+    /// left empty then.
+    pub async fn fetch_rotation(
+        rpc_client: &RpcClient,
+        epoch_slot_start: Slot,
+        slots_in_epoch: Slot,
+        fetch_current: bool,
+    ) -> Result<EpochRotation> {
+        let curr_schedule = if fetch_current {
+            Some(
+                Self::fetch_schedule(rpc_client, epoch_slot_start)
+                    .await
+                    .context("Failed to fetch current epoch schedule after rotation")?,
+            )
+        } else {
+            None
+        };
+
+        let next_schedule = Self::fetch_schedule(rpc_client, epoch_slot_start + slots_in_epoch)
+            .await
+            .unwrap_or_default(); // May not be available yet
+
+        Ok(EpochRotation {
+            epoch_slot_start,
+            curr_schedule,
+            next_schedule,
+        })
+    }
+
+    /// Rotates to the next epoch using fetched schedules.
+    ///
+    /// # Returns
+    ///
+    /// `true` if rotation occurred, `false` if the tracker is no longer at
+    /// the epoch the rotation was fetched for (nothing changes then).
+    pub fn apply_rotation(&mut self, rotation: EpochRotation) -> bool {
+        if rotation.epoch_slot_start != self.next_epoch_slot_start {
+            return false;
+        }
+        let curr_schedule = match rotation.curr_schedule {
+            Some(schedule) => schedule,
+            None if !self.next_schedule.is_empty() => std::mem::take(&mut self.next_schedule),
+            None => return false,
+        };
+
+        self.epoch += 1;
+        self.curr_epoch_slot_start = self.next_epoch_slot_start;
+        self.next_epoch_slot_start += self.slots_in_epoch;
+        self.curr_schedule = curr_schedule;
+        self.next_schedule = rotation.next_schedule;
+        true
+    }
+
     /// Rotates to the next epoch and fetches the new next_schedule.
     ///
     /// Should be called when the current slot crosses into the next epoch.
+    /// The tracker is left unchanged when a needed schedule cannot be
+    /// fetched, so a later call retries.
     ///
     /// # Returns
     ///
@@ -183,21 +415,14 @@ impl ScheduleTracker {
         current_slot: Slot,
         rpc_client: &RpcClient,
     ) -> Result<bool> {
-        if current_slot < self.next_epoch_slot_start {
+        let Some((epoch_slot_start, fetch_current)) = self.rotation_due(current_slot) else {
             return Ok(false); // Still in current epoch
-        }
-
-        // Rotate to next epoch
-        self.curr_epoch_slot_start = self.next_epoch_slot_start;
-        self.next_epoch_slot_start += self.slots_in_epoch;
-        self.curr_schedule = std::mem::take(&mut self.next_schedule);
-
-        // Fetch new next epoch schedule
-        self.next_schedule = Self::fetch_schedule(rpc_client, self.next_epoch_slot_start)
-            .await
-            .unwrap_or_default(); // May not be available yet
+        };
 
-        Ok(true)
+        let rotation =
+            Self::fetch_rotation(rpc_client, epoch_slot_start, self.slots_in_epoch, fetch_current)
+                .await?;
+        Ok(self.apply_rotation(rotation))
     }
 }
 
@@ -208,6 +433,7 @@ mod tests {
     #[test]
     fn test_slot_to_index() {
         let tracker = ScheduleTracker {
+            epoch: 0,
             curr_epoch_slot_start: 1000,
             next_epoch_slot_start: 1432,
             curr_schedule: HashMap::new(),
@@ -221,6 +447,146 @@ mod tests {
         assert_eq!(tracker.slot_to_index(999), None); // Before epoch
         assert_eq!(tracker.slot_to_index(1432), None); // After epoch
     }
+
+    #[test]
+    fn test_leader_windows() {
+        let curr_schedule = HashMap::from([
+            (0, "A".to_string()),
+            (1, "A".to_string()),
+            (2, "B".to_string()),
+            (3, "B".to_string()),
+            (5, "B".to_string()), // Slot 4 missing from schedule
+            (6, "A".to_string()),
+        ]);
+        let tracker = ScheduleTracker {
+            epoch: 5,
+            curr_epoch_slot_start: 1000,
+            next_epoch_slot_start: 1007,
+            curr_schedule,
+            next_schedule: HashMap::new(),
+            slots_in_epoch: 7,
+        };
+
+        assert_eq!(
+            tracker.leader_windows(1000, 1010),
+            vec![
+                ("A".to_string(), 1000, 1001),
+                ("B".to_string(), 1002, 1003),
+                ("B".to_string(), 1005, 1005),
+                ("A".to_string(), 1006, 1006),
+            ]
+        );
+        // The first window keeps its real start
+        assert_eq!(
+            tracker.leader_windows(1001, 1003),
+            vec![("A".to_string(), 1000, 1001), ("B".to_string(), 1002, 1002)]
+        );
+        assert!(tracker.leader_windows(990, 1000).is_empty());
+
+        // Lookahead past the known schedules stops where they end
+        assert_eq!(tracker.leader_windows(1006, u64::MAX).len(), 1);
+    }
+
+    fn boundary_tracker() -> ScheduleTracker {
+        ScheduleTracker {
+            epoch: 5,
+            curr_epoch_slot_start: 1000,
+            next_epoch_slot_start: 1004,
+            curr_schedule: HashMap::from([
+                (0, "A".to_string()),
+                (1, "A".to_string()),
+                (2, "B".to_string()),
+                (3, "B".to_string()),
+            ]),
+            next_schedule: HashMap::from([
+                (0, "B".to_string()),
+                (1, "C".to_string()),
+                (2, "C".to_string()),
+                (3, "C".to_string()),
+            ]),
+            slots_in_epoch: 4,
+        }
+    }
+
+    #[test]
+    fn test_get_leader_for_slot_crosses_epoch_boundary() {
+        let tracker = boundary_tracker();
+
+        assert_eq!(tracker.get_leader_for_slot(999), None);
+        assert_eq!(tracker.get_leader_for_slot(1000), Some("A"));
+        assert_eq!(tracker.get_leader_for_slot(1003), Some("B"));
+        assert_eq!(tracker.get_leader_for_slot(1004), Some("B")); // Next epoch
+        assert_eq!(tracker.get_leader_for_slot(1007), Some("C"));
+        assert_eq!(tracker.get_leader_for_slot(1008), None); // Beyond next epoch
+    }
+
+    #[test]
+    fn test_leader_windows_cross_epoch_boundary() {
+        let tracker = boundary_tracker();
+
+        // B's window spans the boundary and is merged
+        assert_eq!(
+            tracker.leader_windows(1002, 1010),
+            vec![("B".to_string(), 1002, 1004), ("C".to_string(), 1005, 1007)]
+        );
+    }
+
+    #[test]
+    fn test_apply_rotation() {
+        let mut tracker = boundary_tracker();
+        assert_eq!(tracker.rotation_due(1003), None);
+        assert_eq!(tracker.rotation_due(1004), Some((1004, false)));
+
+        // A rotation fetched for another epoch is ignored
+        let stale = EpochRotation {
+            epoch_slot_start: 1000,
+            curr_schedule: None,
+            next_schedule: HashMap::new(),
+        };
+        assert!(!tracker.apply_rotation(stale));
+        assert_eq!(tracker.epoch(), 5);
+
+        let rotation = EpochRotation {
+            epoch_slot_start: 1004,
+            curr_schedule: None,
+            next_schedule: HashMap::from([(0, "D".to_string())]),
+        };
+        assert!(tracker.apply_rotation(rotation));
+        assert_eq!(tracker.epoch(), 6);
+        assert_eq!(tracker.get_leader_for_slot(1005), Some("C"));
+        assert_eq!(tracker.get_leader_for_slot(1008), Some("D"));
+    }
+
+    #[tokio::test]
+    async fn test_failed_rotation_leaves_tracker_unchanged() {
+        let mut tracker = boundary_tracker();
+        tracker.next_schedule.clear();
+        let rpc_client = RpcClient::new("http://127.0.0.1:1".to_string());
+
+        assert!(tracker.maybe_rotate(1004, &rpc_client).await.is_err());
+        assert_eq!(tracker.epoch(), 5);
+        assert_eq!(tracker.get_leader_for_slot(1003), Some("B"));
+
+        // Still due, so the next slot update retries
+        assert_eq!(tracker.rotation_due(1005), Some((1004, true)));
+    }
+
+    #[test]
+    fn test_set_next_schedule() {
+        let mut tracker = boundary_tracker();
+        tracker.next_schedule.clear();
+        assert_eq!(tracker.missing_next_schedule(), Some(1004));
+        assert_eq!(tracker.get_leader_for_slot(1004), None);
+
+        // Stale epoch start is rejected
+        let schedule = HashMap::from([(0, "D".to_string())]);
+        assert!(!tracker.set_next_schedule(2000, schedule.clone()));
+        assert_eq!(tracker.missing_next_schedule(), Some(1004));
+
+        assert!(tracker.set_next_schedule(1004, schedule));
+        assert_eq!(tracker.missing_next_schedule(), None);
+        assert_eq!(tracker.get_leader_for_slot(1004), Some("D"));
+    }
 }
 
 
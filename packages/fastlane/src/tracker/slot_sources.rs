@@ -0,0 +1,352 @@
+//! Slot sources and cross-source slot consensus.
+//!
+//! The tracker can follow several slot feeds at once: WebSocket
+//! `slotsUpdates` subscriptions, Yellowstone gRPC slot streams and RPC
+//! `getSlot` polling. Each source keeps its own `SlotsTracker`, and the
+//! combined slot is agreed across the sources that are still updating, so a
+//! single source that lags, stalls or reports far-future slots does not move
+//! routing.
+
+use anyhow::{anyhow, Result};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use super::slots_tracker::{SlotEvent, SlotsTracker};
+use super::Slot;
+
+/// Default interval between RPC `getSlot` polls.
+pub const DEFAULT_RPC_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How long a source may go without updates before it is left out of consensus.
+pub const SLOT_SOURCE_STALE_AFTER: Duration = Duration::from_secs(3);
+
+/// A feed of slot updates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SlotSource {
+    /// WebSocket `slotsUpdates` subscription.
+    WebSocket {
+        /// WebSocket endpoint URL.
+        url: String,
+    },
+    /// Yellowstone gRPC slot stream.
+    Grpc {
+        /// gRPC endpoint URL.
+        url: String,
+        /// Optional x-token for authenticated endpoints.
+        x_token: Option<String>,
+    },
+    /// RPC `getSlot` polling.
+    RpcPoll {
+        /// RPC endpoint URL.
+        url: String,
+        /// Interval between polls.
+        interval: Duration,
+    },
+}
+
+impl SlotSource {
+    /// Returns the source kind: "ws", "grpc" or "rpc".
+    pub fn kind(&self) -> &'static str {
+        match self {
+            SlotSource::WebSocket { .. } => "ws",
+            SlotSource::Grpc { .. } => "grpc",
+            SlotSource::RpcPoll { .. } => "rpc",
+        }
+    }
+
+    /// Returns the endpoint URL.
+    pub fn url(&self) -> &str {
+        match self {
+            SlotSource::WebSocket { url }
+            | SlotSource::Grpc { url, .. }
+            | SlotSource::RpcPoll { url, .. } => url,
+        }
+    }
+
+    /// Returns the label the source at `index` is reported under, e.g. "ws-0".
+    ///
+    /// URLs are left out since they often embed API keys.
+    pub fn label(&self, index: usize) -> String {
+        format!("{}-{}", self.kind(), index)
+    }
+}
+
+impl FromStr for SlotSource {
+    type Err = anyhow::Error;
+
+    /// Parses `kind=url`, where kind is "ws", "grpc" or "rpc".
+    ///
+    /// gRPC sources are parsed without an x-token and RPC sources poll at
+    /// `DEFAULT_RPC_POLL_INTERVAL`.
+    fn from_str(s: &str) -> Result<Self> {
+        let (kind, url) = s
+            .split_once('=')
+            .ok_or_else(|| anyhow!("Slot source \"{}\" is not kind=url", s))?;
+        let url = url.trim().to_string();
+        if url.is_empty() {
+            return Err(anyhow!("Slot source \"{}\" has no URL", s));
+        }
+
+        match kind.trim() {
+            "ws" => Ok(SlotSource::WebSocket { url }),
+            "grpc" => Ok(SlotSource::Grpc { url, x_token: None }),
+            "rpc" => Ok(SlotSource::RpcPoll {
+                url,
+                interval: DEFAULT_RPC_POLL_INTERVAL,
+            }),
+            other => Err(anyhow!(
+                "Unknown slot source kind \"{}\" (expected ws, grpc or rpc)",
+                other
+            )),
+        }
+    }
+}
+
+/// Health of a single slot source.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlotSourceStats {
+    /// Source label, e.g. "ws-0".
+    pub label: String,
+    /// Source kind: "ws", "grpc" or "rpc".
+    pub kind: &'static str,
+    /// Last slot observed from this source (0 if none yet).
+    pub slot: Slot,
+    /// Slots this source is behind the consensus slot (negative if ahead).
+    pub lag: i64,
+    /// Time since the source last updated, if it ever did.
+    pub since_update: Option<Duration>,
+    /// Whether the source stopped updating and is left out of consensus.
+    pub stale: bool,
+}
+
+/// Per-source tracking state.
+#[derive(Debug)]
+struct SourceState {
+    /// Source label.
+    label: String,
+    /// Source kind.
+    kind: &'static str,
+    /// Slot estimate from this source's updates alone.
+    tracker: SlotsTracker,
+    /// When this source last updated.
+    updated_at: Option<Instant>,
+}
+
+impl SourceState {
+    /// Returns whether the source updated within `SLOT_SOURCE_STALE_AFTER` of `now`.
+    fn is_fresh(&self, now: Instant) -> bool {
+        self.updated_at
+            .is_some_and(|at| now.saturating_duration_since(at) <= SLOT_SOURCE_STALE_AFTER)
+    }
+}
+
+/// Combines slot updates from several sources into one slot estimate.
+///
+/// Each source's slot is extrapolated to the same instant, so sources that
+/// update at different rates compare fairly. The consensus is the
+/// second-highest slot across fresh sources (the lower one with two): a
+/// single source running ahead never moves it, and with three or more
+/// sources a single lagging one doesn't hold it back either. Stale sources
+/// are left out unless every source is stale.
+#[derive(Debug)]
+pub struct SlotConsensus {
+    /// State per configured source, in configuration order.
+    sources: Vec<SourceState>,
+}
+
+impl SlotConsensus {
+    /// Creates consensus state for the given sources.
+    pub fn new(sources: &[SlotSource]) -> Self {
+        Self {
+            sources: sources
+                .iter()
+                .enumerate()
+                .map(|(index, source)| SourceState {
+                    label: source.label(index),
+                    kind: source.kind(),
+                    tracker: SlotsTracker::new(),
+                    updated_at: None,
+                })
+                .collect(),
+        }
+    }
+
+    /// Records a slot event from the source at `source`.
+    ///
+    /// # Returns
+    ///
+    /// The consensus slot after the update.
+    pub fn record(&mut self, source: usize, event: SlotEvent) -> Slot {
+        self.record_at(source, event, Instant::now())
+    }
+
+    /// Records a slot event that arrived at `now`.
+    fn record_at(&mut self, source: usize, event: SlotEvent, now: Instant) -> Slot {
+        if let Some(state) = self.sources.get_mut(source) {
+            state.tracker.record_at(event, now);
+            state.updated_at = Some(now);
+        }
+        self.consensus_at(now)
+    }
+
+    /// Records a slot from a monotonic source (gRPC or RPC polling).
+    ///
+    /// # Returns
+    ///
+    /// The consensus slot after the update.
+    pub fn record_monotonic(&mut self, source: usize, slot: Slot) -> Slot {
+        self.record_monotonic_at(source, slot, Instant::now())
+    }
+
+    /// Records a monotonic slot that arrived at `now`.
+    fn record_monotonic_at(&mut self, source: usize, slot: Slot, now: Instant) -> Slot {
+        if let Some(state) = self.sources.get_mut(source) {
+            state.tracker.record_monotonic_at(slot, now);
+            state.updated_at = Some(now);
+        }
+        self.consensus_at(now)
+    }
+
+    /// Returns the current consensus slot (0 if no source has updated).
+    pub fn consensus(&self) -> Slot {
+        self.consensus_at(Instant::now())
+    }
+
+    /// Computes the consensus slot at `now`.
+    fn consensus_at(&self, now: Instant) -> Slot {
+        let observed = || {
+            self.sources
+                .iter()
+                .filter(|state| state.tracker.observed_slot() > 0)
+        };
+
+        let mut slots: Vec<Slot> = observed()
+            .filter(|state| state.is_fresh(now))
+            .map(|state| state.tracker.extrapolate(now).0)
+            .collect();
+        if slots.is_empty() {
+            slots = observed()
+                .map(|state| state.tracker.extrapolate(now).0)
+                .collect();
+        }
+        if slots.is_empty() {
+            return 0;
+        }
+
+        slots.sort_unstable();
+        slots[slots.len().saturating_sub(2)]
+    }
+
+    /// Returns per-source health, in configuration order.
+    pub fn stats(&self) -> Vec<SlotSourceStats> {
+        self.stats_at(Instant::now())
+    }
+
+    /// Computes per-source health at `now`.
+    fn stats_at(&self, now: Instant) -> Vec<SlotSourceStats> {
+        let consensus = self.consensus_at(now);
+        self.sources
+            .iter()
+            .map(|state| {
+                let slot = state.tracker.observed_slot();
+                let lag = if slot == 0 {
+                    0
+                } else {
+                    consensus as i64 - state.tracker.extrapolate(now).0 as i64
+                };
+                SlotSourceStats {
+                    label: state.label.clone(),
+                    kind: state.kind,
+                    slot,
+                    lag,
+                    since_update: state.updated_at.map(|at| now.saturating_duration_since(at)),
+                    stale: !state.is_fresh(now),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sources(count: usize) -> Vec<SlotSource> {
+        (0..count)
+            .map(|i| SlotSource::RpcPoll {
+                url: format!("http://rpc-{}", i),
+                interval: DEFAULT_RPC_POLL_INTERVAL,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_consensus_tolerates_lagging_and_lying_sources() {
+        let now = Instant::now();
+        let mut consensus = SlotConsensus::new(&sources(3));
+        consensus.record_monotonic_at(0, 1_000, now);
+        consensus.record_monotonic_at(1, 990, now);
+        assert_eq!(consensus.consensus_at(now), 990);
+
+        // A source far ahead is ignored, and the lagging one no longer holds
+        // the consensus back
+        assert_eq!(consensus.record_monotonic_at(2, 5_000, now), 1_000);
+
+        let stats = consensus.stats_at(now);
+        let lags: Vec<i64> = stats.iter().map(|stats| stats.lag).collect();
+        assert_eq!(lags, [0, 10, -4_000]);
+        assert_eq!(stats[1].label, "rpc-1");
+        assert!(stats.iter().all(|stats| !stats.stale));
+    }
+
+    #[test]
+    fn test_source_slightly_ahead_does_not_win() {
+        let now = Instant::now();
+        let mut consensus = SlotConsensus::new(&sources(3));
+        consensus.record_monotonic_at(0, 1_000, now);
+        consensus.record_monotonic_at(1, 1_001, now);
+        assert_eq!(consensus.record_monotonic_at(2, 1_048, now), 1_001);
+
+        // Honest sources catching up move the consensus as usual
+        assert_eq!(consensus.record_monotonic_at(0, 1_004, now), 1_004);
+    }
+
+    #[test]
+    fn test_stale_sources_leave_consensus() {
+        let start = Instant::now();
+        let mut consensus = SlotConsensus::new(&sources(2));
+        assert_eq!(consensus.consensus_at(start), 0);
+
+        consensus.record_monotonic_at(0, 100, start);
+        consensus.record_at(1, SlotEvent::Start(90), start);
+
+        // Source 0 goes down; source 1 keeps updating
+        let later = start + SLOT_SOURCE_STALE_AFTER + Duration::from_secs(1);
+        consensus.record_at(1, SlotEvent::Start(95), later);
+        assert_eq!(consensus.consensus_at(later), 95);
+
+        let stats = consensus.stats_at(later);
+        assert!(stats[0].stale);
+        assert!(!stats[1].stale);
+        assert_eq!(stats[1].since_update, Some(Duration::ZERO));
+        assert_eq!(stats[0].since_update, Some(later - start));
+    }
+
+    #[test]
+    fn test_parse_slot_sources() {
+        assert_eq!(
+            "ws=wss://a.example".parse::<SlotSource>().unwrap(),
+            SlotSource::WebSocket {
+                url: "wss://a.example".to_string()
+            }
+        );
+        let grpc: SlotSource = "grpc=https://b.example:443".parse().unwrap();
+        assert_eq!(grpc.kind(), "grpc");
+        assert_eq!(grpc.url(), "https://b.example:443");
+        assert_eq!(grpc.label(2), "grpc-2");
+
+        assert!("https://c.example".parse::<SlotSource>().is_err());
+        assert!("udp=c.example".parse::<SlotSource>().is_err());
+        assert!("rpc=".parse::<SlotSource>().is_err());
+    }
+}
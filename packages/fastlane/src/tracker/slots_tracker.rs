@@ -2,10 +2,22 @@
 //!
 //! Tracks slot progression and estimates the current slot based on
 //! slot update events. Filters out outliers from malicious validators
-//! that may broadcast far-future slots.
+//! that may broadcast far-future slots. Between events, the current slot
+//! is extrapolated from the wall clock and the observed slot duration.
 
-use super::{Slot, MAX_SLOT_SKIP_DISTANCE, RECENT_LEADER_SLOTS_CAPACITY};
+use super::{Slot, DEFAULT_SLOT_DURATION, MAX_SLOT_SKIP_DISTANCE, RECENT_LEADER_SLOTS_CAPACITY};
 use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// Shortest slot duration sample accepted (events arriving in bursts).
+const MIN_SLOT_DURATION_SAMPLE: Duration = Duration::from_millis(100);
+
+/// Longest slot duration sample accepted (feed gaps, skipped slots).
+const MAX_SLOT_DURATION_SAMPLE: Duration = Duration::from_millis(1600);
+
+/// Weight of each new sample in the slot duration moving average.
+const SLOT_DURATION_SMOOTHING: f64 = 0.125;
 
 /// Represents a slot event (start or end of a slot).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -33,13 +45,23 @@ impl SlotEvent {
 /// Tracks recent slot events and estimates the current slot.
 ///
 /// Uses a median-based algorithm to filter out outlier slots from
-/// malicious or misconfigured validators.
+/// malicious or misconfigured validators. Arrival times are recorded so
+/// the current slot keeps advancing through short feed gaps, at most
+/// `MAX_SLOT_SKIP_DISTANCE` slots past the last observed one. The current
+/// slot never goes backwards, even when a new event lands behind an
+/// extrapolated estimate.
 #[derive(Debug)]
 pub struct SlotsTracker {
     /// Recent slot events for estimation.
     recent_events: VecDeque<SlotEvent>,
-    /// Current estimated slot.
+    /// Slot estimated from events alone.
     current_slot: Slot,
+    /// When `current_slot` was first observed.
+    observed_at: Option<Instant>,
+    /// Moving average of the observed slot duration.
+    slot_duration: Duration,
+    /// Highest slot `current_slot` has returned.
+    last_returned: AtomicU64,
 }
 
 impl SlotsTracker {
@@ -48,14 +70,90 @@ impl SlotsTracker {
         Self {
             recent_events: VecDeque::with_capacity(RECENT_LEADER_SLOTS_CAPACITY),
             current_slot: 0,
+            observed_at: None,
+            slot_duration: DEFAULT_SLOT_DURATION,
+            last_returned: AtomicU64::new(0),
         }
     }
 
-    /// Returns the current estimated slot.
+    /// Returns the current slot, extrapolated past the last observed slot
+    /// by the time elapsed since it arrived.
     pub fn current_slot(&self) -> Slot {
+        self.current_slot_and_progress().0
+    }
+
+    /// Returns the fractional progress into the current slot (0.0 to 1.0).
+    ///
+    /// Stays at 1.0 once extrapolation reaches its bound.
+    pub fn slot_progress(&self) -> f64 {
+        self.current_slot_and_progress().1
+    }
+
+    /// Returns the current slot and the progress into it.
+    ///
+    /// The slot is clamped to the highest one returned before; progress is
+    /// 0.0 while the estimate is behind it.
+    pub fn current_slot_and_progress(&self) -> (Slot, f64) {
+        let (slot, progress) = self.extrapolate(Instant::now());
+        let last = self.last_returned.fetch_max(slot, Ordering::Relaxed);
+        if last > slot {
+            (last, 0.0)
+        } else {
+            (slot, progress)
+        }
+    }
+
+    /// Returns the slot estimated from events alone, without extrapolation.
+    pub fn observed_slot(&self) -> Slot {
         self.current_slot
     }
 
+    /// Returns the moving average of the observed slot duration.
+    pub fn slot_duration(&self) -> Duration {
+        self.slot_duration
+    }
+
+    /// Extrapolates the current slot and the progress into it at `now`.
+    pub(super) fn extrapolate(&self, now: Instant) -> (Slot, f64) {
+        let observed_at = match self.observed_at {
+            Some(observed_at) if self.current_slot > 0 => observed_at,
+            _ => return (self.current_slot, 0.0),
+        };
+
+        let elapsed = now.saturating_duration_since(observed_at);
+        let slots = elapsed.as_secs_f64() / self.slot_duration.as_secs_f64();
+        let skipped = slots.floor() as u64;
+
+        if skipped >= MAX_SLOT_SKIP_DISTANCE {
+            return (self.current_slot + MAX_SLOT_SKIP_DISTANCE, 1.0);
+        }
+        (self.current_slot + skipped, slots.fract())
+    }
+
+    /// Moves the observed slot, re-anchoring extrapolation at `now`.
+    ///
+    /// Forward moves also update the slot duration average, unless they
+    /// jump further than `MAX_SLOT_SKIP_DISTANCE` (a resync or a bad source).
+    fn observe(&mut self, slot: Slot, now: Instant) {
+        if slot == self.current_slot && self.observed_at.is_some() {
+            return;
+        }
+
+        if let Some(observed_at) = self.observed_at {
+            let distance = slot.saturating_sub(self.current_slot);
+            if distance > 0 && distance <= MAX_SLOT_SKIP_DISTANCE && self.current_slot > 0 {
+                let slots = distance as u32;
+                let sample = (now.saturating_duration_since(observed_at) / slots)
+                    .clamp(MIN_SLOT_DURATION_SAMPLE, MAX_SLOT_DURATION_SAMPLE);
+                self.slot_duration = self.slot_duration.mul_f64(1.0 - SLOT_DURATION_SMOOTHING)
+                    + sample.mul_f64(SLOT_DURATION_SMOOTHING);
+            }
+        }
+
+        self.current_slot = slot;
+        self.observed_at = Some(now);
+    }
+
     /// Records a slot event and returns the new current slot estimate.
     ///
     /// # Arguments
@@ -66,6 +164,11 @@ impl SlotsTracker {
     ///
     /// The new estimated current slot after processing the event.
     pub fn record(&mut self, event: SlotEvent) -> Slot {
+        self.record_at(event, Instant::now())
+    }
+
+    /// Records a slot event that arrived at `now`.
+    pub(super) fn record_at(&mut self, event: SlotEvent, now: Instant) -> Slot {
         self.recent_events.push_back(event);
 
         // Trim to capacity
@@ -74,7 +177,8 @@ impl SlotsTracker {
             self.recent_events.drain(..excess);
         }
 
-        self.current_slot = self.estimate_current_slot();
+        let estimate = self.estimate_current_slot();
+        self.observe(estimate, now);
         self.current_slot
     }
 
@@ -92,11 +196,16 @@ impl SlotsTracker {
     ///
     /// Ignores out-of-order or duplicate slots and bypasses outlier filtering.
     pub fn record_monotonic(&mut self, slot: Slot) -> Slot {
+        self.record_monotonic_at(slot, Instant::now())
+    }
+
+    /// Records a monotonic slot update that arrived at `now`.
+    pub(super) fn record_monotonic_at(&mut self, slot: Slot, now: Instant) -> Slot {
         if slot <= self.current_slot {
             return self.current_slot;
         }
 
-        self.current_slot = slot;
+        self.observe(slot, now);
         self.recent_events.clear();
         self.recent_events.push_back(SlotEvent::Start(slot));
         self.current_slot
@@ -194,5 +303,86 @@ mod tests {
         let tracker = tracker_from_slots(vec![1, 2, 100]);
         assert_eq!(tracker.current_slot(), 3);
     }
+
+    #[test]
+    fn test_extrapolates_through_feed_gaps() {
+        let start = Instant::now();
+        let mut tracker = SlotsTracker::new();
+        assert_eq!(tracker.extrapolate(start), (0, 0.0));
+
+        // Slots arriving every 500ms pull the average up from 400ms
+        for i in 0..4u32 {
+            tracker.record_monotonic_at(100 + i as u64, start + Duration::from_millis(500) * i);
+        }
+        assert!(tracker.slot_duration() > DEFAULT_SLOT_DURATION);
+        assert!(tracker.slot_duration() < Duration::from_millis(500));
+
+        let last = start + Duration::from_millis(1500);
+        let duration = tracker.slot_duration();
+        assert_eq!(tracker.observed_slot(), 103);
+        assert_eq!(tracker.extrapolate(last), (103, 0.0));
+
+        let (slot, progress) = tracker.extrapolate(last + duration.mul_f64(2.5));
+        assert_eq!(slot, 105);
+        assert!((progress - 0.5).abs() < 1e-6);
+
+        // Bounded by MAX_SLOT_SKIP_DISTANCE
+        let (slot, progress) = tracker.extrapolate(last + Duration::from_secs(3600));
+        assert_eq!(slot, 103 + MAX_SLOT_SKIP_DISTANCE);
+        assert_eq!(progress, 1.0);
+
+        // A new event re-anchors extrapolation
+        tracker.record_monotonic_at(104, last + Duration::from_secs(3600));
+        assert_eq!(
+            tracker.extrapolate(last + Duration::from_secs(3600)),
+            (104, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_current_slot_never_goes_backwards() {
+        let mut tracker = SlotsTracker::new();
+        tracker.record_monotonic_at(100, Instant::now() - DEFAULT_SLOT_DURATION * 5);
+        let extrapolated = tracker.current_slot();
+        assert!(extrapolated >= 104);
+
+        // A late event behind the extrapolated slot doesn't move it back
+        tracker.record_start(101);
+        assert_eq!(tracker.observed_slot(), 101);
+        assert_eq!(tracker.current_slot_and_progress(), (extrapolated, 0.0));
+    }
+
+    #[test]
+    fn test_duration_samples_are_clamped() {
+        let start = Instant::now();
+        let mut tracker = SlotsTracker::new();
+        tracker.record_at(SlotEvent::Start(10), start);
+        tracker.record_at(SlotEvent::Start(11), start);
+        assert_eq!(
+            tracker.slot_duration(),
+            DEFAULT_SLOT_DURATION.mul_f64(1.0 - SLOT_DURATION_SMOOTHING)
+                + MIN_SLOT_DURATION_SAMPLE.mul_f64(SLOT_DURATION_SMOOTHING)
+        );
+        assert_eq!(tracker.observed_slot(), 11);
+    }
+
+    #[test]
+    fn test_large_jumps_skip_duration_sample() {
+        let start = Instant::now();
+        let mut tracker = SlotsTracker::new();
+        tracker.record_monotonic_at(100, start);
+
+        // A multiple of 2^32 would truncate to a zero slot count
+        let jump = 100 + (1 << 32);
+        tracker.record_monotonic_at(jump, start + DEFAULT_SLOT_DURATION);
+        assert_eq!(tracker.observed_slot(), jump);
+        assert_eq!(tracker.slot_duration(), DEFAULT_SLOT_DURATION);
+
+        tracker.record_monotonic_at(
+            jump + MAX_SLOT_SKIP_DISTANCE + 1,
+            start + DEFAULT_SLOT_DURATION * 2,
+        );
+        assert_eq!(tracker.slot_duration(), DEFAULT_SLOT_DURATION);
+    }
 }
 
@@ -0,0 +1,331 @@
+//! On-disk snapshot of leader schedules and validator sockets.
+//!
+//! Fetching two leader schedules and the cluster nodes makes a cold start
+//! slow, and costly on rate-limited RPCs. The tracker can persist what it
+//! fetched and restore it on the next start instead, keyed by the cluster's
+//! genesis hash and checked against the current epoch. Restored data is
+//! refreshed from RPC in the background as usual.
+
+use anyhow::{bail, ensure, Context, Result};
+use bincode::Options;
+use serde::{Deserialize, Serialize};
+use solana_sdk::epoch_info::EpochInfo;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use super::leader_tracker::TpuSockets;
+use super::schedule_tracker::ScheduleTracker;
+use super::Slot;
+
+/// Snapshot format version; snapshots with another version are ignored.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// Default interval between checks for changes to persist.
+pub const DEFAULT_SNAPSHOT_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Largest snapshot file accepted, well above a mainnet snapshot.
+const MAX_SNAPSHOT_SIZE: u64 = 64 * 1024 * 1024;
+
+/// Marks a slot without a known leader in an encoded schedule.
+const NO_LEADER: u32 = u32::MAX;
+
+/// Returns the bincode options snapshots are encoded and decoded with.
+fn snapshot_options() -> impl Options {
+    bincode::options()
+        .with_limit(MAX_SNAPSHOT_SIZE)
+        .with_fixint_encoding()
+        .reject_trailing_bytes()
+}
+
+/// Leader schedules, epoch bounds and TPU sockets as persisted on disk.
+///
+/// Schedules are stored as one index into `identities` per slot of the
+/// epoch, which keeps the file a few megabytes on mainnet.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrackerSnapshot {
+    /// Format version.
+    version: u32,
+    /// Genesis hash of the cluster the data belongs to (base58).
+    genesis_hash: String,
+    /// Epoch of `curr_schedule`.
+    epoch: u64,
+    /// First slot of `epoch`.
+    curr_epoch_slot_start: Slot,
+    /// Number of slots in an epoch.
+    slots_in_epoch: Slot,
+    /// Validator identities referenced by the schedules.
+    identities: Vec<String>,
+    /// Leader per slot index of the current epoch.
+    curr_schedule: Vec<u32>,
+    /// Leader per slot index of the next epoch (empty if not fetched).
+    next_schedule: Vec<u32>,
+    /// TPU sockets per validator identity.
+    sockets: Vec<(String, TpuSockets)>,
+}
+
+impl TrackerSnapshot {
+    /// Captures the schedules and sockets currently held by a tracker.
+    pub(super) fn capture(
+        genesis_hash: &str,
+        schedule_tracker: &ScheduleTracker,
+        leader_sockets: &HashMap<String, TpuSockets>,
+    ) -> Self {
+        let mut identities = Vec::new();
+        let mut indices = HashMap::new();
+        let slots_in_epoch = schedule_tracker.slots_in_epoch();
+
+        let mut encode = |schedule: &HashMap<usize, String>| -> Vec<u32> {
+            if schedule.is_empty() {
+                return Vec::new();
+            }
+            let mut encoded = vec![NO_LEADER; slots_in_epoch as usize];
+            for (slot_index, identity) in schedule {
+                let index = *indices.entry(identity.clone()).or_insert_with(|| {
+                    identities.push(identity.clone());
+                    (identities.len() - 1) as u32
+                });
+                if let Some(slot) = encoded.get_mut(*slot_index) {
+                    *slot = index;
+                }
+            }
+            encoded
+        };
+        let curr_schedule = encode(schedule_tracker.curr_schedule());
+        let next_schedule = encode(schedule_tracker.next_schedule());
+
+        Self {
+            version: SNAPSHOT_VERSION,
+            genesis_hash: genesis_hash.to_string(),
+            epoch: schedule_tracker.epoch(),
+            curr_epoch_slot_start: schedule_tracker.current_epoch_slot_start(),
+            slots_in_epoch,
+            identities,
+            curr_schedule,
+            next_schedule,
+            sockets: leader_sockets
+                .iter()
+                .map(|(identity, sockets)| (identity.clone(), sockets.clone()))
+                .collect(),
+        }
+    }
+
+    /// Returns the epoch of the current schedule.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// Returns the genesis hash of the cluster the snapshot belongs to.
+    pub fn genesis_hash(&self) -> &str {
+        &self.genesis_hash
+    }
+
+    /// Reads a snapshot from `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, is larger than
+    /// `MAX_SNAPSHOT_SIZE` or cannot be decoded.
+    pub async fn load(path: &Path) -> Result<Self> {
+        let bytes = tokio::fs::read(path)
+            .await
+            .with_context(|| format!("Failed to read snapshot {}", path.display()))?;
+        snapshot_options()
+            .deserialize(&bytes)
+            .context("Failed to decode snapshot")
+    }
+
+    /// Writes the snapshot to `path`, replacing any existing file atomically.
+    ///
+    /// The data is written to a temporary file next to `path` first, named
+    /// uniquely so that several writers sharing a path don't clobber it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub async fn save(&self, path: &Path) -> Result<()> {
+        let bytes = snapshot_options()
+            .serialize(self)
+            .context("Failed to encode snapshot")?;
+        let mut tmp_name = path.as_os_str().to_owned();
+        tmp_name.push(format!(
+            ".{}.{:08x}.tmp",
+            std::process::id(),
+            rand::random::<u32>()
+        ));
+        let tmp_path = PathBuf::from(tmp_name);
+
+        if let Err(e) = tokio::fs::write(&tmp_path, bytes).await {
+            let _ = tokio::fs::remove_file(&tmp_path).await;
+            return Err(e)
+                .with_context(|| format!("Failed to write snapshot {}", tmp_path.display()));
+        }
+        if let Err(e) = tokio::fs::rename(&tmp_path, path).await {
+            let _ = tokio::fs::remove_file(&tmp_path).await;
+            return Err(e)
+                .with_context(|| format!("Failed to replace snapshot {}", path.display()));
+        }
+        Ok(())
+    }
+
+    /// Rebuilds the schedule tracker and sockets if the snapshot still
+    /// applies to the cluster.
+    ///
+    /// A snapshot from the previous epoch is still used when it holds that
+    /// epoch's next schedule; the schedule after it is fetched later.
+    ///
+    /// # Arguments
+    ///
+    /// * `genesis_hash` - Genesis hash of the cluster (base58)
+    /// * `epoch_info` - Current epoch info from RPC
+    ///
+    /// # Errors
+    ///
+    /// Returns an error describing why the snapshot no longer applies.
+    pub(super) fn restore(
+        self,
+        genesis_hash: &str,
+        epoch_info: &EpochInfo,
+    ) -> Result<(ScheduleTracker, HashMap<String, TpuSockets>)> {
+        ensure!(
+            self.version == SNAPSHOT_VERSION,
+            "Snapshot version {} is not {}",
+            self.version,
+            SNAPSHOT_VERSION
+        );
+        ensure!(
+            self.genesis_hash == genesis_hash,
+            "Snapshot is for cluster {}, not {}",
+            self.genesis_hash,
+            genesis_hash
+        );
+        ensure!(
+            self.slots_in_epoch == epoch_info.slots_in_epoch,
+            "Snapshot has {} slots per epoch, cluster has {}",
+            self.slots_in_epoch,
+            epoch_info.slots_in_epoch
+        );
+
+        let epoch_slot_start = epoch_info.absolute_slot - epoch_info.slot_index;
+        let (curr_schedule, next_schedule) =
+            if self.epoch == epoch_info.epoch && self.curr_epoch_slot_start == epoch_slot_start {
+                (self.curr_schedule, self.next_schedule)
+            } else if self.epoch + 1 == epoch_info.epoch
+                && self.curr_epoch_slot_start + self.slots_in_epoch == epoch_slot_start
+                && !self.next_schedule.is_empty()
+            {
+                (self.next_schedule, Vec::new())
+            } else {
+                bail!(
+                    "Snapshot is for epoch {}, cluster is at epoch {}",
+                    self.epoch,
+                    epoch_info.epoch
+                );
+            };
+
+        let decode = |schedule: Vec<u32>| -> HashMap<usize, String> {
+            schedule
+                .into_iter()
+                .enumerate()
+                .filter_map(|(slot_index, index)| {
+                    let identity = self.identities.get(index as usize)?;
+                    Some((slot_index, identity.clone()))
+                })
+                .collect()
+        };
+        let curr_schedule = decode(curr_schedule);
+        ensure!(
+            !curr_schedule.is_empty(),
+            "Snapshot has no current schedule"
+        );
+
+        let schedule_tracker = ScheduleTracker::from_parts(
+            epoch_info.epoch,
+            epoch_slot_start,
+            self.slots_in_epoch,
+            curr_schedule,
+            decode(next_schedule),
+        );
+        Ok((schedule_tracker, self.sockets.into_iter().collect()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn epoch_info(epoch: u64, absolute_slot: Slot) -> EpochInfo {
+        EpochInfo {
+            epoch,
+            slot_index: absolute_slot % 8,
+            slots_in_epoch: 8,
+            absolute_slot,
+            block_height: 0,
+            transaction_count: None,
+        }
+    }
+
+    fn snapshot() -> TrackerSnapshot {
+        let schedule = |leader: &str| {
+            (0..8)
+                .map(|i| (i, format!("{}{}", leader, i / 4)))
+                .collect()
+        };
+        let tracker = ScheduleTracker::from_parts(2, 16, 8, schedule("a"), schedule("b"));
+        let sockets = HashMap::from([(
+            "a0".to_string(),
+            TpuSockets {
+                tpu_socket: Some("127.0.0.1:8009".to_string()),
+                tpu_forwards_socket: None,
+            },
+        )]);
+        TrackerSnapshot::capture("genesis", &tracker, &sockets)
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_round_trips_through_disk() {
+        let path = std::env::temp_dir().join(format!("fastlane-snapshot-{}", std::process::id()));
+        let snapshot = snapshot();
+        snapshot.save(&path).await.unwrap();
+        let loaded = TrackerSnapshot::load(&path).await.unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(loaded, snapshot);
+        assert_eq!(loaded.identities.len(), 4);
+
+        let (tracker, sockets) = loaded.restore("genesis", &epoch_info(2, 20)).unwrap();
+        assert_eq!(tracker.epoch(), 2);
+        assert_eq!(tracker.get_leader_for_slot(20), Some("a1"));
+        assert_eq!(tracker.get_leader_for_slot(24), Some("b0"));
+        assert!(tracker.missing_next_schedule().is_none());
+        assert_eq!(sockets["a0"].tpu_socket.as_deref(), Some("127.0.0.1:8009"));
+    }
+
+    #[tokio::test]
+    async fn test_corrupt_or_oversized_snapshot_is_rejected() {
+        let path =
+            std::env::temp_dir().join(format!("fastlane-snapshot-bad-{}", std::process::id()));
+
+        // A length prefix claiming far more data than the limit allows
+        let mut bytes = SNAPSHOT_VERSION.to_le_bytes().to_vec();
+        bytes.extend_from_slice(&u64::MAX.to_le_bytes());
+        std::fs::write(&path, &bytes).unwrap();
+        assert!(TrackerSnapshot::load(&path).await.is_err());
+
+        std::fs::write(&path, b"not a snapshot").unwrap();
+        assert!(TrackerSnapshot::load(&path).await.is_err());
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_restore_checks_cluster_and_epoch() {
+        assert!(snapshot().restore("other", &epoch_info(2, 20)).is_err());
+        assert!(snapshot().restore("genesis", &epoch_info(4, 33)).is_err());
+        assert!(snapshot().restore("genesis", &epoch_info(1, 9)).is_err());
+
+        // One epoch later the next schedule becomes the current one
+        let (tracker, _) = snapshot().restore("genesis", &epoch_info(3, 25)).unwrap();
+        assert_eq!(tracker.current_epoch_slot_start(), 24);
+        assert_eq!(tracker.get_leader_for_slot(25), Some("b0"));
+        assert_eq!(tracker.missing_next_schedule(), Some(32));
+    }
+}
@@ -0,0 +1,478 @@
+//! Wire-format helpers for serialized transactions.
+//!
+//! Reads just the fields the client needs (e.g. the recent blockhash)
+//! directly from the serialized bytes, without a full deserialization.
+//! Optional pre-flight checks fully parse and verify a transaction before
+//! it is sent.
+//!
+//! Layout: `[shortvec num_signatures][signatures (64 bytes each)][message]`,
+//! where a versioned message is prefixed with a byte that has the high bit set.
+//! The message continues with its header, account keys, recent blockhash and
+//! instructions (`[program index][shortvec accounts][shortvec data]`).
+
+use anyhow::{bail, Context, Result};
+use bincode::Options;
+use solana_sdk::hash::Hash;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::VersionedTransaction;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+
+use crate::errors::PreflightError;
+
+/// Maximum serialized transaction size; every transaction must fit one packet.
+pub const PACKET_DATA_SIZE: usize = 1232;
+
+/// Size of an ed25519 signature in bytes.
+const SIGNATURE_LEN: usize = 64;
+
+/// Size of a pubkey or hash in bytes.
+const PUBKEY_LEN: usize = 32;
+
+/// Size of the message header (required signatures, readonly signed, readonly unsigned).
+const MESSAGE_HEADER_LEN: usize = 3;
+
+/// High bit of the first message byte marks a versioned message.
+const MESSAGE_VERSION_PREFIX: u8 = 0x80;
+
+/// System program ID (all zeros).
+const SYSTEM_PROGRAM_ID: Pubkey = Pubkey::new_from_array([0; PUBKEY_LEN]);
+
+/// Bincode tag of the system program's `AdvanceNonceAccount` instruction.
+const ADVANCE_NONCE_ACCOUNT_TAG: u32 = 4;
+
+/// State tag of an initialized nonce account.
+const NONCE_STATE_INITIALIZED: u32 = 1;
+
+/// Offset of the durable nonce in a nonce account
+/// (version tag, state tag, authority pubkey).
+const NONCE_HASH_OFFSET: usize = 4 + 4 + PUBKEY_LEN;
+
+/// Decodes a compact-u16 (shortvec) length prefix.
+///
+/// # Returns
+///
+/// The decoded length and the number of bytes consumed.
+pub fn decode_shortvec_len(data: &[u8]) -> Result<(usize, usize)> {
+    let mut len = 0usize;
+
+    for (i, byte) in data.iter().take(3).enumerate() {
+        len |= ((byte & 0x7f) as usize) << (i * 7);
+        if byte & 0x80 == 0 {
+            return Ok((len, i + 1));
+        }
+    }
+
+    bail!("Invalid shortvec length prefix")
+}
+
+/// Returns the byte offset where the message starts.
+fn message_offset(tx_data: &[u8]) -> Result<usize> {
+    let (num_signatures, prefix_len) =
+        decode_shortvec_len(tx_data).context("Failed to read signature count")?;
+
+    let offset = prefix_len + num_signatures * SIGNATURE_LEN;
+    if offset >= tx_data.len() {
+        bail!("Transaction too short to contain a message");
+    }
+
+    Ok(offset)
+}
+
+/// Byte offsets of the message fields the client reads.
+struct MessageLayout {
+    /// Offset of the first static account key.
+    keys_offset: usize,
+    /// Number of static account keys.
+    num_keys: usize,
+    /// Offset of the recent blockhash.
+    blockhash_offset: usize,
+}
+
+impl MessageLayout {
+    /// Locates the account keys and recent blockhash.
+    ///
+    /// Supports both legacy and versioned (v0) messages.
+    fn parse(tx_data: &[u8]) -> Result<Self> {
+        let mut offset = message_offset(tx_data)?;
+
+        // Skip the version prefix for versioned messages
+        if tx_data[offset] & MESSAGE_VERSION_PREFIX != 0 {
+            offset += 1;
+        }
+
+        offset += MESSAGE_HEADER_LEN;
+        let (num_keys, prefix_len) = read_shortvec_len(tx_data, offset)
+            .context("Transaction too short to contain account keys")?;
+        let keys_offset = offset + prefix_len;
+
+        Ok(Self {
+            keys_offset,
+            num_keys,
+            blockhash_offset: keys_offset + num_keys * PUBKEY_LEN,
+        })
+    }
+
+    /// Returns a static account key, or `None` if the index is out of range
+    /// (e.g. an address loaded from a lookup table).
+    fn account_key(&self, tx_data: &[u8], index: usize) -> Option<Pubkey> {
+        if index >= self.num_keys {
+            return None;
+        }
+        let start = self.keys_offset + index * PUBKEY_LEN;
+        let key: [u8; PUBKEY_LEN] = tx_data.get(start..start + PUBKEY_LEN)?.try_into().ok()?;
+        Some(Pubkey::new_from_array(key))
+    }
+}
+
+/// Decodes a shortvec length at `offset`.
+fn read_shortvec_len(data: &[u8], offset: usize) -> Result<(usize, usize)> {
+    data.get(offset..)
+        .map(decode_shortvec_len)
+        .transpose()?
+        .context("Unexpected end of transaction")
+}
+
+/// Extracts the recent blockhash (or durable nonce) from a serialized transaction.
+///
+/// Supports both legacy and versioned (v0) messages.
+pub fn extract_recent_blockhash(tx_data: &[u8]) -> Result<Hash> {
+    let offset = MessageLayout::parse(tx_data)?.blockhash_offset;
+
+    let blockhash: [u8; PUBKEY_LEN] = tx_data
+        .get(offset..offset + PUBKEY_LEN)
+        .context("Transaction too short to contain recent blockhash")?
+        .try_into()
+        .context("Failed to extract recent blockhash bytes")?;
+
+    Ok(Hash::new_from_array(blockhash))
+}
+
+/// Extracts the nonce account of a durable-nonce transaction.
+///
+/// A transaction uses a durable nonce when its first instruction is the
+/// system program's `AdvanceNonceAccount`; the nonce account is that
+/// instruction's first account, and the recent blockhash holds the nonce.
+///
+/// # Returns
+///
+/// The nonce account, or `None` for transactions using a recent blockhash.
+pub fn extract_nonce_account(tx_data: &[u8]) -> Result<Option<Pubkey>> {
+    let layout = MessageLayout::parse(tx_data)?;
+    let mut offset = layout.blockhash_offset + PUBKEY_LEN;
+
+    let (num_instructions, prefix_len) = read_shortvec_len(tx_data, offset)
+        .context("Transaction too short to contain instructions")?;
+    if num_instructions == 0 {
+        return Ok(None);
+    }
+    offset += prefix_len;
+
+    // First instruction: program index, account indexes, data
+    let program_index = *tx_data
+        .get(offset)
+        .context("Transaction too short to contain an instruction")?;
+    offset += 1;
+
+    let (num_accounts, prefix_len) = read_shortvec_len(tx_data, offset)?;
+    offset += prefix_len;
+    let accounts = tx_data
+        .get(offset..offset + num_accounts)
+        .context("Transaction too short to contain instruction accounts")?;
+    offset += num_accounts;
+
+    let (data_len, prefix_len) = read_shortvec_len(tx_data, offset)?;
+    offset += prefix_len;
+    let data = tx_data
+        .get(offset..offset + data_len)
+        .context("Transaction too short to contain instruction data")?;
+
+    let is_advance_nonce = layout.account_key(tx_data, program_index as usize)
+        == Some(SYSTEM_PROGRAM_ID)
+        && data == ADVANCE_NONCE_ACCOUNT_TAG.to_le_bytes();
+    if !is_advance_nonce {
+        return Ok(None);
+    }
+
+    Ok(accounts
+        .first()
+        .and_then(|index| layout.account_key(tx_data, *index as usize)))
+}
+
+/// Reads the durable nonce stored in a nonce account.
+///
+/// # Returns
+///
+/// The current nonce, or `None` if the account is not an initialized nonce account.
+pub fn parse_durable_nonce(account_data: &[u8]) -> Option<Hash> {
+    let state: [u8; 4] = account_data.get(4..8)?.try_into().ok()?;
+    if u32::from_le_bytes(state) != NONCE_STATE_INITIALIZED {
+        return None;
+    }
+
+    let nonce: [u8; PUBKEY_LEN] = account_data
+        .get(NONCE_HASH_OFFSET..NONCE_HASH_OFFSET + PUBKEY_LEN)?
+        .try_into()
+        .ok()?;
+    Some(Hash::new_from_array(nonce))
+}
+
+/// Validates a serialized transaction before it is sent.
+///
+/// Checks the packet size limit, parses the legacy or v0 message without
+/// trailing bytes, checks the signature count against the header,
+/// sanitizes the message (account indexes, duplicate account keys) and
+/// verifies every ed25519 signature locally.
+///
+/// # Returns
+///
+/// The transaction's first signature.
+///
+/// # Errors
+///
+/// Returns the first check that failed.
+pub fn preflight_check(tx_data: &[u8]) -> std::result::Result<Signature, PreflightError> {
+    if tx_data.len() > PACKET_DATA_SIZE {
+        return Err(PreflightError::Oversized {
+            size: tx_data.len(),
+            limit: PACKET_DATA_SIZE,
+        });
+    }
+
+    let tx: VersionedTransaction = bincode::options()
+        .with_limit(PACKET_DATA_SIZE as u64)
+        .with_fixint_encoding()
+        .reject_trailing_bytes()
+        .deserialize(tx_data)
+        .map_err(|e| PreflightError::Malformed(e.to_string()))?;
+
+    let required = tx.message.header().num_required_signatures as usize;
+    if tx.signatures.len() != required {
+        return Err(PreflightError::SignatureCount {
+            signatures: tx.signatures.len(),
+            required,
+        });
+    }
+
+    tx.sanitize()
+        .map_err(|e| PreflightError::Malformed(e.to_string()))?;
+
+    let keys = tx.message.static_account_keys();
+    if keys.iter().collect::<HashSet<_>>().len() != keys.len() {
+        return Err(PreflightError::Malformed(
+            "Duplicate account keys".to_string(),
+        ));
+    }
+
+    if let Some(index) = tx.verify_with_results().iter().position(|ok| !ok) {
+        return Err(PreflightError::InvalidSignature { index });
+    }
+
+    tx.signatures
+        .first()
+        .copied()
+        .ok_or_else(|| PreflightError::Malformed("Transaction has no signatures".to_string()))
+}
+
+/// Validates every transaction of a batch and flags duplicates.
+///
+/// # Errors
+///
+/// Returns `PreflightError::InBatch` for the first entry that fails a
+/// check or repeats an earlier entry's signature.
+pub fn preflight_check_batch(transactions: &[Vec<u8>]) -> std::result::Result<(), PreflightError> {
+    let mut seen: HashMap<Signature, usize> = HashMap::with_capacity(transactions.len());
+
+    for (index, tx_data) in transactions.iter().enumerate() {
+        let in_batch = |source| PreflightError::InBatch {
+            index,
+            source: Box::new(source),
+        };
+
+        let signature = preflight_check(tx_data).map_err(in_batch)?;
+        match seen.entry(signature) {
+            Entry::Occupied(first) => {
+                return Err(in_batch(PreflightError::Duplicate {
+                    signature: signature.to_string(),
+                    first: *first.get(),
+                }))
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(index);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::TpuErrorCode;
+    use solana_sdk::instruction::{AccountMeta, Instruction};
+    use solana_sdk::message::{v0, Message, VersionedMessage};
+    use solana_sdk::signature::Keypair;
+    use solana_sdk::signer::Signer;
+    use solana_sdk::transaction::Transaction;
+
+    #[test]
+    fn test_decode_shortvec_len() {
+        assert_eq!(decode_shortvec_len(&[0x00]).unwrap(), (0, 1));
+        assert_eq!(decode_shortvec_len(&[0x7f]).unwrap(), (127, 1));
+        assert_eq!(decode_shortvec_len(&[0x80, 0x01]).unwrap(), (128, 2));
+        assert_eq!(decode_shortvec_len(&[0xff, 0xff, 0x03]).unwrap(), (65535, 3));
+        assert!(decode_shortvec_len(&[0x80]).is_err());
+        assert!(decode_shortvec_len(&[]).is_err());
+    }
+
+    #[test]
+    fn test_extract_recent_blockhash_legacy() {
+        let payer = Keypair::new();
+        let blockhash = Hash::new_from_array([7; 32]);
+        let message = Message::new_with_blockhash(&[], Some(&payer.pubkey()), &blockhash);
+        let tx = Transaction::new(&[&payer], message, blockhash);
+        let tx_data = bincode::serialize(&tx).unwrap();
+
+        assert_eq!(extract_recent_blockhash(&tx_data).unwrap(), blockhash);
+    }
+
+    #[test]
+    fn test_extract_recent_blockhash_v0() {
+        let payer = Keypair::new();
+        let blockhash = Hash::new_from_array([9; 32]);
+        let message = v0::Message::try_compile(&payer.pubkey(), &[], &[], blockhash).unwrap();
+        let tx = VersionedTransaction::try_new(VersionedMessage::V0(message), &[&payer]).unwrap();
+        let tx_data = bincode::serialize(&tx).unwrap();
+
+        assert_eq!(extract_recent_blockhash(&tx_data).unwrap(), blockhash);
+    }
+
+    /// Builds a signed legacy transaction whose first instruction is `first`.
+    fn legacy_tx(payer: &Keypair, first: Instruction, blockhash: Hash) -> Vec<u8> {
+        let message = Message::new_with_blockhash(&[first], Some(&payer.pubkey()), &blockhash);
+        bincode::serialize(&Transaction::new(&[payer], message, blockhash)).unwrap()
+    }
+
+    #[test]
+    fn test_extract_nonce_account() {
+        let payer = Keypair::new();
+        let nonce_account = Pubkey::new_unique();
+        let nonce = Hash::new_from_array([3; 32]);
+
+        let advance = Instruction::new_with_bincode(
+            SYSTEM_PROGRAM_ID,
+            &ADVANCE_NONCE_ACCOUNT_TAG,
+            vec![
+                AccountMeta::new(nonce_account, false),
+                AccountMeta::new_readonly(Pubkey::new_unique(), false),
+                AccountMeta::new_readonly(payer.pubkey(), true),
+            ],
+        );
+        let tx_data = legacy_tx(&payer, advance, nonce);
+        assert_eq!(
+            extract_nonce_account(&tx_data).unwrap(),
+            Some(nonce_account)
+        );
+        assert_eq!(extract_recent_blockhash(&tx_data).unwrap(), nonce);
+
+        // A system transfer (tag 2) is not a durable-nonce transaction
+        let transfer = Instruction::new_with_bincode(
+            SYSTEM_PROGRAM_ID,
+            &(2u32, 1u64),
+            vec![
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new(nonce_account, false),
+            ],
+        );
+        let tx_data = legacy_tx(&payer, transfer, nonce);
+        assert_eq!(extract_nonce_account(&tx_data).unwrap(), None);
+    }
+
+    #[test]
+    fn test_parse_durable_nonce() {
+        let nonce = Hash::new_from_array([5; 32]);
+        let mut data = Vec::new();
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&NONCE_STATE_INITIALIZED.to_le_bytes());
+        data.extend_from_slice(&[1; PUBKEY_LEN]);
+        data.extend_from_slice(nonce.as_ref());
+        data.extend_from_slice(&5000u64.to_le_bytes());
+
+        assert_eq!(parse_durable_nonce(&data), Some(nonce));
+        assert_eq!(parse_durable_nonce(&[0; 8]), None);
+        assert_eq!(parse_durable_nonce(&[]), None);
+    }
+
+    #[test]
+    fn test_preflight_accepts_signed_transactions() {
+        let payer = Keypair::new();
+        let blockhash = Hash::new_from_array([7; 32]);
+        let message = Message::new_with_blockhash(&[], Some(&payer.pubkey()), &blockhash);
+        let tx = Transaction::new(&[&payer], message, blockhash);
+        let legacy = bincode::serialize(&tx).unwrap();
+        assert_eq!(preflight_check(&legacy).unwrap(), tx.signatures[0]);
+
+        let message = v0::Message::try_compile(&payer.pubkey(), &[], &[], blockhash).unwrap();
+        let tx = VersionedTransaction::try_new(VersionedMessage::V0(message), &[&payer]).unwrap();
+        let v0 = bincode::serialize(&tx).unwrap();
+        assert!(preflight_check(&v0).is_ok());
+
+        assert!(preflight_check_batch(&[legacy, v0]).is_ok());
+    }
+
+    #[test]
+    fn test_preflight_rejects_bad_transactions() {
+        let payer = Keypair::new();
+        let blockhash = Hash::new_from_array([7; 32]);
+        let message = Message::new_with_blockhash(&[], Some(&payer.pubkey()), &blockhash);
+        let tx = Transaction::new(&[&payer], message, blockhash);
+        let tx_data = bincode::serialize(&tx).unwrap();
+
+        let err = preflight_check(&[0; PACKET_DATA_SIZE + 1]).unwrap_err();
+        assert_eq!(err.code(), TpuErrorCode::Oversized);
+
+        let mut trailing = tx_data.clone();
+        trailing.push(0);
+        assert!(matches!(
+            preflight_check(&trailing),
+            Err(PreflightError::Malformed(_))
+        ));
+        assert!(matches!(
+            preflight_check(b"not a transaction"),
+            Err(PreflightError::Malformed(_))
+        ));
+
+        let mut unsigned = tx.clone();
+        unsigned.signatures.clear();
+        assert_eq!(
+            preflight_check(&bincode::serialize(&unsigned).unwrap()),
+            Err(PreflightError::SignatureCount {
+                signatures: 0,
+                required: 1
+            })
+        );
+
+        let mut tampered = tx_data.clone();
+        tampered[1] ^= 0xff;
+        let err = preflight_check(&tampered).unwrap_err();
+        assert_eq!(err, PreflightError::InvalidSignature { index: 0 });
+        assert_eq!(err.code(), TpuErrorCode::InvalidTransaction);
+
+        let err = preflight_check_batch(&[tx_data.clone(), tx_data]).unwrap_err();
+        assert!(matches!(
+            err,
+            PreflightError::InBatch { index: 1, ref source }
+                if matches!(**source, PreflightError::Duplicate { first: 0, .. })
+        ));
+        assert_eq!(err.code(), TpuErrorCode::DuplicateTransaction);
+    }
+
+    #[test]
+    fn test_extract_recent_blockhash_truncated() {
+        assert!(extract_recent_blockhash(&[]).is_err());
+        assert!(extract_recent_blockhash(&[1; 65]).is_err());
+        assert!(extract_recent_blockhash(&[1; 100]).is_err());
+    }
+}